@@ -0,0 +1,119 @@
+/*! Probes of the code that the wrapper operators compile to.
+
+Each probe is an unmangled function around one operator, so that its assembly
+can be found by name in the output of `rustc --emit asm`. `codegen.sh` compiles
+this file, and checks that every probe is straight-line code of the expected
+instructions, with no calls or branches. Run it with `just codegen`.
+
+`cargo bench --bench codegen` also times some of the probes against the same
+operation on the bare integer, through `black_box`.
+!*/
+
+use std::{
+	hint::black_box,
+	time::Instant,
+};
+
+use surety::{
+	Overflowing,
+	Wrapping,
+};
+
+/// Adds, folding the carry into the sticky flag.
+#[no_mangle]
+#[inline(never)]
+pub fn overflowing_add_u32(lhs: Overflowing<u32>, rhs: u32) -> Overflowing<u32> {
+	lhs + rhs
+}
+
+/// Subtracts, folding the borrow into the sticky flag.
+#[no_mangle]
+#[inline(never)]
+pub fn overflowing_sub_u32(lhs: Overflowing<u32>, rhs: u32) -> Overflowing<u32> {
+	lhs - rhs
+}
+
+/// Adds signed integers, folding the signed overflow into the sticky flag.
+#[no_mangle]
+#[inline(never)]
+pub fn overflowing_add_i32(lhs: Overflowing<i32>, rhs: i32) -> Overflowing<i32> {
+	lhs + rhs
+}
+
+/// Chains four additions, which merge their flags without branching.
+#[no_mangle]
+#[inline(never)]
+pub fn overflowing_chain_u32(
+	a: Overflowing<u32>,
+	b: u32,
+	c: u32,
+	d: u32,
+) -> Overflowing<u32>
+{
+	a + b + c + d
+}
+
+/// Adds, discarding the carry.
+#[no_mangle]
+#[inline(never)]
+pub fn wrapping_add_u32(lhs: Wrapping<u32>, rhs: u32) -> Wrapping<u32> {
+	lhs + rhs
+}
+
+/// Subtracts, discarding the borrow.
+#[no_mangle]
+#[inline(never)]
+pub fn wrapping_sub_u32(lhs: Wrapping<u32>, rhs: u32) -> Wrapping<u32> {
+	lhs - rhs
+}
+
+/// Multiplies, discarding the high half of the product.
+#[no_mangle]
+#[inline(never)]
+pub fn wrapping_mul_u32(lhs: Wrapping<u32>, rhs: u32) -> Wrapping<u32> {
+	lhs * rhs
+}
+
+/// Multiplies and accumulates, as in a filter or a hash.
+#[no_mangle]
+#[inline(never)]
+pub fn wrapping_mul_add_u32(
+	acc: Wrapping<u32>,
+	a: u32,
+	b: u32,
+) -> Wrapping<u32>
+{
+	acc + Wrapping::new(a) * b
+}
+
+/// Times `func` over many iterations, and reports the time per iteration.
+fn time(name: &str, func: impl Fn(u32, u32) -> u32) {
+	const ROUNDS: u32 = 100_000_000;
+	let start = Instant::now();
+	let mut acc = 0u32;
+	for num in 0 .. ROUNDS {
+		acc = func(black_box(acc), black_box(num));
+	}
+	black_box(acc);
+	let nanos = start.elapsed().as_nanos() as f64 / ROUNDS as f64;
+	println!("{:<24} {:>6.3} ns", name, nanos);
+}
+
+fn main() {
+	//  `cargo test --benches` runs this too, without the `--bench` flag and
+	//  without optimizations.
+	if !std::env::args().any(|arg| arg == "--bench") {
+		return;
+	}
+	time("u32::wrapping_mul", |a, b| a.wrapping_mul(b));
+	time("wrapping_mul_u32", |a, b| {
+		wrapping_mul_u32(Wrapping::new(a), b).into_inner()
+	});
+	time("u32::overflowing_add", |a, b| a.overflowing_add(b).0);
+	time("overflowing_add_u32", |a, b| {
+		overflowing_add_u32(Overflowing::new(a), b).into_inner()
+	});
+	time("overflowing_chain_u32", |a, b| {
+		overflowing_chain_u32(Overflowing::new(a), b, b, b).into_inner()
+	});
+}
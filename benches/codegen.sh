@@ -0,0 +1,72 @@
+#!/bin/sh
+# Compiles the probes in `codegen.rs` to assembly, and checks that each one is
+# straight-line code of at most the expected number of instructions.
+#
+# Register moves and the return are not counted, because they depend on the
+# calling convention rather than on the operator.
+
+set -eu
+
+cd "$(dirname "$0")/.."
+cargo rustc --quiet --release --bench codegen -- --emit asm -C codegen-units=1
+asm=$(ls -t target/release/deps/codegen-*.s | head -n 1)
+
+case "$(uname -m)" in
+x86_64)
+	branch='^(call|j[a-z]+)$'
+	flag='^set[bo]$'
+	;;
+aarch64 | arm64)
+	branch='^(bl|blr|br|b|b\..*|cbn?z|tbn?z)$'
+	flag='^cs(et|inc)$'
+	;;
+*)
+	echo "No codegen expectations for $(uname -m)" >&2
+	exit 0
+	;;
+esac
+
+status=0
+
+# Checks that the probe named $1 has at most $2 instructions, none of which is
+# a branch or call, and that one of them matches the pattern $3, if given.
+check() {
+	ops=$(awk -v name="$1" '
+		$0 ~ "^_?" name ":" { found = 1; next }
+		found && /^[ \t]*\.cfi_endproc/ { exit }
+		found && /^[ \t]+[a-z]/ { print $1 }
+	' "$asm" | grep -Ev '^(mov|ret)' || true)
+	count=$(printf '%s\n' "$ops" | grep -c . || true)
+	listing=$(printf '%s ' $ops)
+	if [ "$count" -eq 0 ]; then
+		echo "$1: not found, or has no instructions"
+		status=1
+	elif printf '%s\n' "$ops" | grep -Eq "$branch"; then
+		echo "$1: branches or calls: $listing"
+		status=1
+	elif [ "$count" -gt "$2" ]; then
+		echo "$1: $count instructions, expected at most $2: $listing"
+		status=1
+	elif [ -n "${3-}" ] && ! printf '%s\n' "$ops" | grep -Eq "$3"; then
+		echo "$1: no instruction matches $3: $listing"
+		status=1
+	else
+		echo "$1: ok: $listing"
+	fi
+}
+
+# An operation, reading the carry or overflow flag, and merging it into the
+# sticky flag. AArch64 reads and merges the flag in one `csinc`.
+check overflowing_add_u32 3 "$flag"
+check overflowing_sub_u32 3 "$flag"
+check overflowing_add_i32 3 "$flag"
+check overflowing_chain_u32 9 "$flag"
+
+# One arithmetic instruction, with no flag or call. A multiply-accumulate is
+# one `madd` on AArch64, and `imul` and `lea` on x86-64.
+check wrapping_add_u32 1
+check wrapping_sub_u32 1
+check wrapping_mul_u32 1
+check wrapping_mul_add_u32 2
+
+exit $status
@@ -0,0 +1,330 @@
+//! Implementation of `#[derive(Surety)]`.
+
+use proc_macro2::{
+	Span,
+	TokenStream,
+};
+use quote::{
+	quote,
+	ToTokens,
+};
+use syn::{
+	parse_quote,
+	spanned::Spanned,
+	Data,
+	DeriveInput,
+	Error,
+	Fields,
+	Ident,
+	Index,
+	Lifetime,
+	Member,
+	Type,
+};
+
+/// The arithmetic operator traits, and their methods.
+const ARITH: &[(&str, &str)] = &[
+	("Add", "add"),
+	("Sub", "sub"),
+	("Mul", "mul"),
+	("Div", "div"),
+	("Rem", "rem"),
+];
+
+/// The shift operator traits, and their methods.
+const SHIFTS: &[(&str, &str)] = &[("Shl", "shl"), ("Shr", "shr")];
+
+/// The signed integer types, whose newtypes can be negated.
+const SIGNED: &[&str] = &["i8", "i16", "i32", "i64", "i128", "isize"];
+
+pub fn expand(input: DeriveInput) -> Result<TokenStream, Error> {
+	let policy = policy(&input)?;
+	let wrapper = match policy {
+		Policy::Saturating => quote!(::surety::Saturating),
+		Policy::Wrapping => quote!(::surety::Wrapping),
+	};
+	let (member, ty) = field(&input)?;
+
+	let name = &input.ident;
+	let (impl_gen, ty_gen, where_clause) = input.generics.split_for_impl();
+	let this = quote!(#wrapper::<#ty>::new(self.#member));
+
+	let mut out = TokenStream::new();
+
+	//  `Saturating` only implements the additive and multiplicative operators.
+	let (arith, shifts): (&[_], &[_]) = match policy {
+		Policy::Saturating => (&ARITH[.. 3], &[]),
+		Policy::Wrapping => (ARITH, SHIFTS),
+	};
+
+	for (op, func) in arith {
+		let op = Ident::new(op, Span::call_site());
+		let func = Ident::new(func, Span::call_site());
+		let op_assign = Ident::new(&format!("{}Assign", op), Span::call_site());
+		let func_assign =
+			Ident::new(&format!("{}_assign", func), Span::call_site());
+
+		for (rhs, val) in &[
+			(quote!(Self), quote!(rhs.#member)),
+			(quote!(&Self), quote!(rhs.#member)),
+			(quote!(#ty), quote!(rhs)),
+			(quote!(&#ty), quote!(*rhs)),
+		] {
+			out.extend(quote! {
+				impl #impl_gen ::core::ops::#op<#rhs> for #name #ty_gen
+				#where_clause
+				{
+					type Output = Self;
+
+					#[inline]
+					fn #func(self, rhs: #rhs) -> Self::Output {
+						Self {
+							#member: ::core::ops::#op::#func(#this, #val).into_inner(),
+						}
+					}
+				}
+
+				impl #impl_gen ::core::ops::#op_assign<#rhs> for #name #ty_gen
+				#where_clause
+				{
+					#[inline]
+					fn #func_assign(&mut self, rhs: #rhs) {
+						self.#member = ::core::ops::#op::#func(#this, #val).into_inner();
+					}
+				}
+			});
+		}
+	}
+
+	//  Shifts accept every amount that the wrapper does, through one generic
+	//  parameter added to the newtype's own.
+	let mut shift_generics = input.generics.clone();
+	shift_generics
+		.params
+		.push(parse_quote!(__Amount: ::surety::ShiftAmount));
+	let (shift_gen, _, _) = shift_generics.split_for_impl();
+
+	for (op, func) in shifts {
+		let op = Ident::new(op, Span::call_site());
+		let func = Ident::new(func, Span::call_site());
+		let op_assign = Ident::new(&format!("{}Assign", op), Span::call_site());
+		let func_assign =
+			Ident::new(&format!("{}_assign", func), Span::call_site());
+
+		out.extend(quote! {
+			impl #shift_gen ::core::ops::#op<__Amount> for #name #ty_gen
+			#where_clause
+			{
+				type Output = Self;
+
+				#[inline]
+				fn #func(self, rhs: __Amount) -> Self::Output {
+					Self {
+						#member: ::core::ops::#op::#func(#this, rhs).into_inner(),
+					}
+				}
+			}
+
+			impl #shift_gen ::core::ops::#op_assign<__Amount> for #name #ty_gen
+			#where_clause
+			{
+				#[inline]
+				fn #func_assign(&mut self, rhs: __Amount) {
+					self.#member = ::core::ops::#op::#func(#this, rhs).into_inner();
+				}
+			}
+		});
+	}
+
+	if is_signed(ty) {
+		out.extend(quote! {
+			impl #impl_gen ::core::ops::Neg for #name #ty_gen #where_clause {
+				type Output = Self;
+
+				#[inline]
+				fn neg(self) -> Self::Output {
+					Self {
+						#member: ::core::ops::Neg::neg(#this).into_inner(),
+					}
+				}
+			}
+		});
+	}
+
+	let mut ref_gen = input.generics.clone();
+	let lt = Lifetime::new("'__surety", Span::call_site());
+	ref_gen.params.insert(0, parse_quote!(#lt));
+	let (ref_impl_gen, _, _) = ref_gen.split_for_impl();
+
+	out.extend(quote! {
+		impl #impl_gen ::core::iter::Sum<Self> for #name #ty_gen #where_clause {
+			#[inline]
+			fn sum<I>(iter: I) -> Self
+			where I: ::core::iter::Iterator<Item = Self> {
+				Self {
+					#member: iter
+						.map(|elem| #wrapper::<#ty>::new(elem.#member))
+						.sum::<#wrapper<#ty>>()
+						.into_inner(),
+				}
+			}
+		}
+
+		impl #ref_impl_gen ::core::iter::Sum<&#lt Self> for #name #ty_gen
+		#where_clause
+		{
+			#[inline]
+			fn sum<I>(iter: I) -> Self
+			where I: ::core::iter::Iterator<Item = &#lt Self> {
+				Self {
+					#member: iter
+						.map(|elem| #wrapper::<#ty>::new(elem.#member))
+						.sum::<#wrapper<#ty>>()
+						.into_inner(),
+				}
+			}
+		}
+
+		impl #impl_gen ::core::cmp::PartialEq<#ty> for #name #ty_gen
+		#where_clause
+		{
+			#[inline]
+			fn eq(&self, other: &#ty) -> bool {
+				self.#member.eq(other)
+			}
+		}
+
+		impl #impl_gen ::core::cmp::PartialOrd<#ty> for #name #ty_gen
+		#where_clause
+		{
+			#[inline]
+			fn partial_cmp(
+				&self,
+				other: &#ty,
+			) -> ::core::option::Option<::core::cmp::Ordering> {
+				self.#member.partial_cmp(other)
+			}
+		}
+
+		impl #impl_gen ::core::convert::AsRef<#ty> for #name #ty_gen
+		#where_clause
+		{
+			#[inline]
+			fn as_ref(&self) -> &#ty {
+				&self.#member
+			}
+		}
+
+		impl #impl_gen ::core::convert::AsMut<#ty> for #name #ty_gen
+		#where_clause
+		{
+			#[inline]
+			fn as_mut(&mut self) -> &mut #ty {
+				&mut self.#member
+			}
+		}
+
+		impl #impl_gen ::core::convert::From<#ty> for #name #ty_gen
+		#where_clause
+		{
+			#[inline]
+			fn from(value: #ty) -> Self {
+				Self { #member: value }
+			}
+		}
+
+		impl #impl_gen ::core::convert::From<#wrapper<#ty>> for #name #ty_gen
+		#where_clause
+		{
+			#[inline]
+			fn from(value: #wrapper<#ty>) -> Self {
+				Self { #member: value.into_inner() }
+			}
+		}
+
+		impl #impl_gen ::core::convert::From<#name #ty_gen> for #wrapper<#ty>
+		#where_clause
+		{
+			#[inline]
+			fn from(value: #name #ty_gen) -> Self {
+				Self::new(value.#member)
+			}
+		}
+	});
+
+	Ok(out)
+}
+
+/// The overflow policies that a newtype can select.
+#[derive(Clone, Copy, Eq, PartialEq)]
+enum Policy {
+	Saturating,
+	Wrapping,
+}
+
+/// Finds the overflow policy selected by the `#[surety(...)]` attribute.
+fn policy(input: &DeriveInput) -> Result<Policy, Error> {
+	let mut found = None;
+	for attr in input.attrs.iter().filter(|a| a.path().is_ident("surety")) {
+		let policy: Ident = attr.parse_args()?;
+		let policy = match policy.to_string().as_str() {
+			"saturating" => Policy::Saturating,
+			"wrapping" => Policy::Wrapping,
+			_ => {
+				return Err(Error::new(
+					policy.span(),
+					"expected `saturating` or `wrapping`",
+				));
+			},
+		};
+		if found.replace(policy).is_some() {
+			return Err(Error::new(
+				attr.span(),
+				"only one overflow policy may be selected",
+			));
+		}
+	}
+	found.ok_or_else(|| {
+		Error::new(
+			Span::call_site(),
+			"`#[derive(Surety)]` requires a `#[surety(saturating)]` or \
+			 `#[surety(wrapping)]` attribute",
+		)
+	})
+}
+
+/// Finds the sole field of the newtype.
+fn field(input: &DeriveInput) -> Result<(Member, &Type), Error> {
+	let fields = match &input.data {
+		Data::Struct(data) => &data.fields,
+		_ => {
+			return Err(Error::new(
+				input.ident.span(),
+				"`#[derive(Surety)]` can only be used on structs",
+			));
+		},
+	};
+	let mut iter = fields.iter();
+	match (iter.next(), iter.next()) {
+		(Some(field), None) => {
+			let member = match &field.ident {
+				Some(ident) => Member::Named(ident.clone()),
+				None => Member::Unnamed(Index::from(0)),
+			};
+			Ok((member, &field.ty))
+		},
+		_ => Err(Error::new(
+			match fields {
+				Fields::Unit => input.ident.span(),
+				_ => fields.span(),
+			},
+			"`#[derive(Surety)]` requires a struct with exactly one field",
+		)),
+	}
+}
+
+/// Tests if a type is one of the signed integer primitives.
+fn is_signed(ty: &Type) -> bool {
+	let text = ty.to_token_stream().to_string();
+	SIGNED.iter().any(|s| *s == text)
+}
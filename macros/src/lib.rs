@@ -0,0 +1,300 @@
+/*! Procedural macros for `surety`.
+
+This crate is an implementation detail of `surety`, and its macros are
+re-exported there when the `macros` feature is enabled. You should not depend
+on it directly.
+!*/
+
+extern crate proc_macro;
+
+mod derive;
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{
+	parse::{
+		Parse,
+		ParseStream,
+	},
+	parse_macro_input,
+	parse_quote,
+	spanned::Spanned,
+	visit_mut::{
+		self,
+		VisitMut,
+	},
+	BinOp,
+	DeriveInput,
+	Expr,
+	ExprBinary,
+	ExprLit,
+	ExprUnary,
+	Ident,
+	Item,
+	ItemFn,
+	ReturnType,
+	Stmt,
+	Token,
+	Type,
+	UnOp,
+};
+
+/** Rewrites all integer arithmetic in a function to checked arithmetic.
+
+Every use of the `+`, `-`, `*`, `/`, `%`, `<<`, and `>>` operators (and their
+compound-assignment forms, and unary negation) in the function body is replaced
+with the corresponding checked operation, followed by `?`. The function’s return
+type `T` is changed to `Option<T>`, and it returns `None` as soon as any
+operation overflows.
+
+With an error type, as `#[checked_fn(err = MyError)]`, the return type is
+changed to `Result<T, MyError>` instead, and an overflow returns
+`Err(MyError::from(ArithmeticError))`. The error type must implement
+`From<surety::ArithmeticError>`, which `ArithmeticError` itself does.
+
+The attribute is named `checked_fn` rather than `checked` because attribute and
+function-like macros share one namespace, and `surety::checked!` already
+constructs `Checked` values.
+
+The rewrite is syntactic: every operator in the body must be applied to
+integers. Closures, nested items, and the arguments of macro invocations are not
+rewritten, so they may contain other arithmetic.
+
+# Examples
+
+```rust
+#[surety::checked_fn]
+fn parse_decimal(text: &str) -> u8 {
+    if text.is_empty() {
+        return 0;
+    }
+    let mut out = 0;
+    for byte in text.bytes() {
+        out = out * 10 + (byte - b'0');
+    }
+    out
+}
+
+assert_eq!(parse_decimal(""), Some(0));
+assert_eq!(parse_decimal("255"), Some(255));
+assert_eq!(parse_decimal("256"), None);
+
+use surety::ArithmeticError;
+
+#[surety::checked_fn(err = ArithmeticError)]
+fn area(width: u16, height: u16) -> u16 {
+    width * height
+}
+
+assert_eq!(area(200, 300), Ok(60_000));
+assert_eq!(area(300, 300), Err(ArithmeticError));
+```
+**/
+#[proc_macro_attribute]
+pub fn checked_fn(args: TokenStream, item: TokenStream) -> TokenStream {
+	let mut checker = parse_macro_input!(args as Checker);
+	let mut func = parse_macro_input!(item as ItemFn);
+	checker.visit_block_mut(&mut func.block);
+
+	func.sig.output = match func.sig.output {
+		ReturnType::Default => checker.output(parse_quote!(())),
+		ReturnType::Type(_, ty) => checker.output(*ty),
+	};
+
+	let stmts = &mut func.block.stmts;
+	match stmts.pop() {
+		Some(Stmt::Expr(tail, None)) => {
+			stmts.push(Stmt::Expr(checker.success(tail), None));
+		},
+		Some(Stmt::Expr(Expr::Return(ret), semi)) => {
+			stmts.push(Stmt::Expr(Expr::Return(ret), semi));
+		},
+		last => {
+			stmts.extend(last);
+			stmts.push(Stmt::Expr(checker.success(parse_quote!(())), None));
+		},
+	}
+
+	quote!(#func).into()
+}
+
+/** Implements a wrapper type’s arithmetic on an integer newtype.
+
+This derive applies to structs with exactly one field, which is an integer. The
+struct must also have a `#[surety(saturating)]` or `#[surety(wrapping)]`
+attribute, which selects the overflow policy of its arithmetic.
+
+The generated implementations match the operator surface of the selected
+wrapper: each operator it implements and its compound-assignment form, `Sum`,
+comparison with the bare integer, `AsRef` and `AsMut`, and conversions from the
+integer and both to and from the wrapper. Newtypes around the signed primitives
+also implement `Neg`.
+
+The standard traits, such as `Clone`, `Copy`, and `PartialEq`, are not
+generated, and should be derived alongside this one.
+
+# Examples
+
+```rust
+use surety::Surety;
+
+#[derive(Clone, Copy, Debug, PartialEq, Surety)]
+#[surety(saturating)]
+struct Volume(u8);
+
+#[derive(Clone, Copy, Debug, PartialEq, Surety)]
+#[surety(wrapping)]
+struct Sequence {
+    number: u16,
+}
+
+let mut volume = Volume(250);
+volume += 10;
+assert_eq!(volume, 255);
+
+let next = Sequence { number: 0xFFFF } + 1;
+assert_eq!(next, Sequence { number: 0 });
+```
+**/
+#[proc_macro_derive(Surety, attributes(surety))]
+pub fn surety(item: TokenStream) -> TokenStream {
+	let input = parse_macro_input!(item as DeriveInput);
+	derive::expand(input)
+		.unwrap_or_else(syn::Error::into_compile_error)
+		.into()
+}
+
+/// Rewrites arithmetic and `return` expressions in a function body.
+struct Checker {
+	/// The error type of a `Result`-returning function, or `None` for an
+	/// `Option`-returning one.
+	err: Option<Type>,
+}
+
+impl Checker {
+	/// Produces the rewritten return type.
+	fn output(&self, ty: Type) -> ReturnType {
+		match &self.err {
+			Some(err) => {
+				parse_quote!(-> ::core::result::Result<#ty, #err>)
+			},
+			None => parse_quote!(-> ::core::option::Option<#ty>),
+		}
+	}
+
+	/// Wraps a value as the function’s successful result.
+	fn success(&self, value: Expr) -> Expr {
+		match self.err {
+			Some(_) => parse_quote!(::core::result::Result::Ok(#value)),
+			None => parse_quote!(::core::option::Option::Some(#value)),
+		}
+	}
+
+	/// Returns early from the function if a checked operation failed.
+	fn propagate(&self, op: Expr) -> Expr {
+		match self.err {
+			Some(_) => parse_quote!(#op.ok_or(::surety::ArithmeticError)?),
+			None => parse_quote!(#op?),
+		}
+	}
+
+	/// Produces the checked replacement of a binary operator, if it is
+	/// arithmetic.
+	fn binary(&self, left: &Expr, op: &BinOp, right: &Expr) -> Option<Expr> {
+		let (func, assign) = match op {
+			BinOp::Add(_) => ("add", false),
+			BinOp::Sub(_) => ("sub", false),
+			BinOp::Mul(_) => ("mul", false),
+			BinOp::Div(_) => ("div", false),
+			BinOp::Rem(_) => ("rem", false),
+			BinOp::Shl(_) => ("shl", false),
+			BinOp::Shr(_) => ("shr", false),
+			BinOp::AddAssign(_) => ("add", true),
+			BinOp::SubAssign(_) => ("sub", true),
+			BinOp::MulAssign(_) => ("mul", true),
+			BinOp::DivAssign(_) => ("div", true),
+			BinOp::RemAssign(_) => ("rem", true),
+			BinOp::ShlAssign(_) => ("shl", true),
+			BinOp::ShrAssign(_) => ("shr", true),
+			_ => return None,
+		};
+		let func = syn::Ident::new(func, op.span());
+
+		Some(if assign {
+			let op = self.propagate(parse_quote!(
+				::surety::__private::#func(*__surety_lhs, __surety_rhs)
+			));
+			parse_quote!({
+				let __surety_rhs = #right;
+				let __surety_lhs = &mut #left;
+				*__surety_lhs = #op;
+			})
+		}
+		else {
+			self.propagate(parse_quote!(::surety::__private::#func(#left, #right)))
+		})
+	}
+}
+
+impl Parse for Checker {
+	fn parse(input: ParseStream) -> syn::Result<Self> {
+		if input.is_empty() {
+			return Ok(Self { err: None });
+		}
+		let key: Ident = input.parse()?;
+		if key != "err" {
+			return Err(syn::Error::new(
+				key.span(),
+				"`#[checked_fn]` only takes an `err = Type` argument",
+			));
+		}
+		input.parse::<Token![=]>()?;
+		let err = input.parse()?;
+		Ok(Self { err: Some(err) })
+	}
+}
+
+impl VisitMut for Checker {
+	fn visit_expr_mut(&mut self, expr: &mut Expr) {
+		match expr {
+			//  These have their own control flow, and are left untouched.
+			Expr::Async(_) | Expr::Closure(_) | Expr::Const(_) => return,
+			_ => visit_mut::visit_expr_mut(self, expr),
+		}
+
+		let rewrite: Option<Expr> = match expr {
+			Expr::Binary(ExprBinary {
+				left, op, right, ..
+			}) => self.binary(left, op, right),
+			Expr::Unary(ExprUnary {
+				op: UnOp::Neg(_),
+				expr: inner,
+				..
+			}) => match &**inner {
+				//  Negative literals are not arithmetic.
+				Expr::Lit(ExprLit { .. }) => None,
+				inner => Some(
+					self.propagate(parse_quote!(::surety::__private::neg(#inner))),
+				),
+			},
+			Expr::Return(ret) => {
+				let inner = ret
+					.expr
+					.take()
+					.map(|inner| *inner)
+					.unwrap_or_else(|| parse_quote!(()));
+				ret.expr = Some(Box::new(self.success(inner)));
+				None
+			},
+			_ => None,
+		};
+
+		if let Some(rewrite) = rewrite {
+			*expr = rewrite;
+		}
+	}
+
+	fn visit_item_mut(&mut self, _: &mut Item) {
+		//  Nested items are separate functions, and are left untouched.
+	}
+}
@@ -0,0 +1,50 @@
+//! Support functions for the code generated by the procedural macros.
+//!
+//! These are not part of the public API, and may change at any time.
+
+use core::convert::TryInto;
+
+use funty::{
+	IsInteger,
+	IsSigned,
+};
+
+#[inline]
+pub fn add<T: IsInteger>(lhs: T, rhs: T) -> Option<T> {
+	lhs.checked_add(rhs)
+}
+
+#[inline]
+pub fn sub<T: IsInteger>(lhs: T, rhs: T) -> Option<T> {
+	lhs.checked_sub(rhs)
+}
+
+#[inline]
+pub fn mul<T: IsInteger>(lhs: T, rhs: T) -> Option<T> {
+	lhs.checked_mul(rhs)
+}
+
+#[inline]
+pub fn div<T: IsInteger>(lhs: T, rhs: T) -> Option<T> {
+	lhs.checked_div(rhs)
+}
+
+#[inline]
+pub fn rem<T: IsInteger>(lhs: T, rhs: T) -> Option<T> {
+	lhs.checked_rem(rhs)
+}
+
+#[inline]
+pub fn shl<T: IsInteger, U: TryInto<u32>>(lhs: T, rhs: U) -> Option<T> {
+	lhs.checked_shl(rhs.try_into().ok()?)
+}
+
+#[inline]
+pub fn shr<T: IsInteger, U: TryInto<u32>>(lhs: T, rhs: U) -> Option<T> {
+	lhs.checked_shr(rhs.try_into().ok()?)
+}
+
+#[inline]
+pub fn neg<T: IsSigned>(val: T) -> Option<T> {
+	val.checked_neg()
+}
@@ -0,0 +1,165 @@
+use core::iter::FromIterator;
+
+use funty::IsInteger as _;
+
+use crate::{
+	Checked,
+	Overflowing,
+	Saturating,
+	Widen,
+	Wrapping,
+};
+
+/** Sums integers in their wider type, and applies an overflow policy once at
+the end.
+
+Adding two `N`-bit numbers produces at most an `N+1`-bit sum, so accumulating
+into a `2N`-bit register can absorb a long run of additions before it is at any
+risk of overflow. This type performs each addition in the wide type, without
+inspecting the narrow range, and defers the choice of overflow behavior to the
+`finish_*` methods.
+
+The accumulator is exact: should the wide register itself wrap around, the
+number of times it has done so is recorded, and the `finish_*` methods account
+for it. This is the only work done on each addition beyond the addition itself.
+
+The wrap count is an `i64`. Each addition moves the sum by less than the square
+root of the wide type’s range, so the wide register wraps at most once in every
+`2^N` additions, and the count cannot overflow before `2^(63+N)` additions,
+which is at least `2^71`.
+
+# Examples
+
+```rust
+use surety::Accumulator;
+
+let acc: Accumulator<u8> = [200, 100, 50].iter().collect();
+assert!(acc.finish_checked().is_none());
+assert_eq!(acc.finish_saturating(), 255);
+assert_eq!(acc.finish_wrapping(), 94);
+
+let ovf = acc.finish_overflowing();
+assert_eq!(ovf, 94);
+assert!(ovf.has_overflowed());
+```
+**/
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Accumulator<T: Widen> {
+	/// The running sum, wrapped at the boundary of the wide type.
+	sum: T::Wide,
+	/// The net number of times `sum` has wrapped upwards (positive) or
+	/// downwards (negative). This is `i64` on every target, so that it cannot
+	/// itself overflow in any feasible run.
+	wraps: i64,
+}
+
+impl<T: Widen> Accumulator<T> {
+	/// Creates an empty accumulator, whose sum is zero.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Adds a value to the running sum.
+	pub fn push(&mut self, value: T) {
+		let (sum, ovf) = self.sum.overflowing_add(value.widen());
+		self.sum = sum;
+		if ovf {
+			if value < T::default() {
+				self.wraps -= 1;
+			}
+			else {
+				self.wraps += 1;
+			}
+		}
+	}
+
+	/// Adds the running sum of another accumulator to this one.
+	///
+	/// This allows a long summation to be split into pieces, such as across
+	/// threads, and recombined without any loss of precision.
+	pub fn merge(&mut self, other: Self) {
+		let (sum, ovf) = self.sum.overflowing_add(other.sum);
+		self.sum = sum;
+		self.wraps += other.wraps;
+		if ovf {
+			if other.sum < T::Wide::default() {
+				self.wraps -= 1;
+			}
+			else {
+				self.wraps += 1;
+			}
+		}
+	}
+
+	/// Gets the running sum in the wide type.
+	///
+	/// This is only `None` if the sum has exceeded even the wide type.
+	pub fn wide_sum(&self) -> Checked<T::Wide> {
+		let sum = if self.wraps == 0 { Some(self.sum) } else { None };
+		sum.into()
+	}
+
+	/// Produces the sum as a checked integer, which is `None` if the sum does
+	/// not fit in `T`.
+	pub fn finish_checked(self) -> Checked<T> {
+		self.wide_sum().and_then(T::narrow)
+	}
+
+	/// Produces the sum as a saturating integer, clamped to the range of `T`.
+	///
+	/// Because the accumulator is exact, this is the true sum clamped once at
+	/// the end, rather than clamped after each individual addition.
+	pub fn finish_saturating(self) -> Saturating<T> {
+		let value = if self.wraps > 0 || self.sum > T::max_value().widen() {
+			T::max_value()
+		}
+		else if self.wraps < 0 || self.sum < T::min_value().widen() {
+			T::min_value()
+		}
+		else {
+			T::truncate(self.sum)
+		};
+		value.into()
+	}
+
+	/// Produces the sum as a wrapping integer, discarding all bits that do not
+	/// fit in `T`.
+	pub fn finish_wrapping(self) -> Wrapping<T> {
+		T::truncate(self.sum).into()
+	}
+
+	/// Produces the sum as a wrapping integer, marked as overflowed if the sum
+	/// does not fit in `T`.
+	pub fn finish_overflowing(self) -> Overflowing<T> {
+		let has_overflowed = self.finish_checked().is_none();
+		(T::truncate(self.sum), has_overflowed).into()
+	}
+}
+
+impl<T: Widen> Extend<T> for Accumulator<T> {
+	fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+		for value in iter {
+			self.push(value);
+		}
+	}
+}
+
+impl<'a, T: 'a + Widen> Extend<&'a T> for Accumulator<T> {
+	fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+		self.extend(iter.into_iter().copied());
+	}
+}
+
+impl<T: Widen> FromIterator<T> for Accumulator<T> {
+	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+		let mut out = Self::new();
+		out.extend(iter);
+		out
+	}
+}
+
+impl<'a, T: 'a + Widen> FromIterator<&'a T> for Accumulator<T> {
+	fn from_iter<I: IntoIterator<Item = &'a T>>(iter: I) -> Self {
+		iter.into_iter().copied().collect()
+	}
+}
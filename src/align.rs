@@ -0,0 +1,153 @@
+use funty::IsUnsigned;
+
+use crate::{
+	Checked,
+	Wrapping,
+};
+
+/** Rounds unsigned integers to a power-of-two alignment.
+
+Rounding up is the error-prone direction: near the top of the range it wraps
+around to zero, which allocators and page-table code then treat as a valid,
+very low, address. `checked_align_up` refuses instead.
+
+# Panics
+
+`wrapping_align_up`, `align_down`, and `is_aligned_to` panic if `align` is not a
+power of two. `checked_align_up` poisons its result instead.
+
+# Examples
+
+```rust
+use surety::Align as _;
+
+assert_eq!(5000usize.checked_align_up(4096), Some(8192));
+assert!((usize::MAX - 100).checked_align_up(4096).is_none());
+assert_eq!((usize::MAX - 100).wrapping_align_up(4096), 0);
+assert_eq!(5000usize.align_down(4096), 4096);
+assert!(8192usize.is_aligned_to(4096));
+assert!(5000usize.checked_align_up(3).is_none());
+```
+**/
+pub trait Align: IsUnsigned {
+	/// Rounds up to a multiple of `align`, poisoning the result if it overflows
+	/// or if `align` is not a power of two.
+	fn checked_align_up(self, align: Self) -> Checked<Self>;
+
+	/// Rounds up to a multiple of `align`, wrapping around to zero if it
+	/// overflows.
+	fn wrapping_align_up(self, align: Self) -> Wrapping<Self>;
+
+	/// Rounds down to a multiple of `align`. This never overflows.
+	fn align_down(self, align: Self) -> Self;
+
+	/// Tests whether the integer is a multiple of `align`.
+	fn is_aligned_to(self, align: Self) -> bool;
+}
+
+impl<T: IsUnsigned> Align for T {
+	fn checked_align_up(self, align: Self) -> Checked<Self> {
+		if !align.is_power_of_two() {
+			return Checked { value: None };
+		}
+		let mask = mask(align);
+		self.checked_add(!mask).map(|up| up & mask).into()
+	}
+
+	#[track_caller]
+	fn wrapping_align_up(self, align: Self) -> Wrapping<Self> {
+		let mask = mask(align);
+		(self.wrapping_add(!mask) & mask).into()
+	}
+
+	#[track_caller]
+	fn align_down(self, align: Self) -> Self {
+		self & mask(align)
+	}
+
+	#[track_caller]
+	fn is_aligned_to(self, align: Self) -> bool {
+		self & !mask(align) == Self::ZERO
+	}
+}
+
+impl<T: IsUnsigned> Checked<T> {
+	/// Rounds up to a multiple of `align`. The result is poisoned if it
+	/// overflows, or if `align` is not a power of two.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Checked;
+	///
+	/// let end = Checked::new(0x1234usize);
+	/// assert_eq!(end.align_up(0x1000), Some(0x2000));
+	/// assert!(end.align_up(3).is_none());
+	/// ```
+	pub fn align_up(self, align: T) -> Self {
+		self.and_then(|val| val.checked_align_up(align).value)
+	}
+
+	/// Rounds down to a multiple of `align`. The result is poisoned if `align`
+	/// is not a power of two.
+	pub fn align_down(self, align: T) -> Self {
+		if !align.is_power_of_two() {
+			return Self { value: None };
+		}
+		self.map(|val| val.align_down(align))
+	}
+
+	/// Tests whether the integer is a multiple of `align`, or produces `None`
+	/// if it is poisoned or `align` is not a power of two.
+	pub fn is_aligned(&self, align: T) -> Option<bool> {
+		if !align.is_power_of_two() {
+			return None;
+		}
+		self.value.map(|val| val.is_aligned_to(align))
+	}
+}
+
+impl<T: IsUnsigned> Wrapping<T> {
+	/// Rounds up to a multiple of `align`, wrapping around to zero if it
+	/// overflows.
+	///
+	/// # Panics
+	///
+	/// This panics if `align` is not a power of two.
+	#[track_caller]
+	pub fn align_up(self, align: T) -> Self {
+		self.value.wrapping_align_up(align)
+	}
+
+	/// Rounds down to a multiple of `align`.
+	///
+	/// # Panics
+	///
+	/// This panics if `align` is not a power of two.
+	#[track_caller]
+	pub fn align_down(self, align: T) -> Self {
+		self.value.align_down(align).into()
+	}
+
+	/// Tests whether the integer is a multiple of `align`.
+	///
+	/// # Panics
+	///
+	/// This panics if `align` is not a power of two.
+	#[track_caller]
+	pub fn is_aligned(&self, align: T) -> bool {
+		self.value.is_aligned_to(align)
+	}
+}
+
+/// Computes the mask which clears the bits below `align`.
+#[track_caller]
+fn mask<T: IsUnsigned>(align: T) -> T {
+	assert!(
+		align.is_power_of_two(),
+		"alignment must be a power of two, not {}",
+		align,
+	);
+	//  For a power of two, `-align` is `!(align - 1)`.
+	align.wrapping_neg()
+}
@@ -0,0 +1,535 @@
+/*! Atomic integers with fixed overflow behavior.
+
+The types in this module wrap the `core::sync::atomic` integers, and expose
+their read-modify-write operations in terms of the overflow policies in this
+crate. They are suitable for counters shared across threads or between a
+program and its interrupt handlers.
+
+# Examples
+
+```rust
+use core::sync::atomic::Ordering;
+use surety::atomic::{AtomicChecked, AtomicSaturating, AtomicWrapping};
+
+let counter = AtomicWrapping::new(250u8);
+assert_eq!(counter.fetch_add(10, Ordering::Relaxed), 250);
+assert_eq!(counter.load(Ordering::Relaxed), 4);
+
+let hits = AtomicSaturating::new(250u8);
+hits.fetch_saturating_add(10, Ordering::AcqRel, Ordering::Acquire);
+assert_eq!(hits.load(Ordering::Relaxed), 255);
+
+let refs = AtomicChecked::new(1u8);
+assert_eq!(refs.fetch_checked_sub(1, Ordering::AcqRel, Ordering::Acquire), Some(1));
+assert!(refs.fetch_checked_sub(1, Ordering::AcqRel, Ordering::Acquire).is_none());
+assert_eq!(refs.load(Ordering::Relaxed), 0);
+```
+!*/
+
+use core::{
+	fmt::{
+		self,
+		Debug,
+		Formatter,
+	},
+	sync::atomic::{
+		self,
+		Ordering,
+	},
+};
+
+use funty::IsInteger;
+
+use crate::{
+	Saturating,
+	Wrapping,
+};
+
+/** Integers which have a corresponding atomic type.
+
+This trait is implemented for the unsigned integers whose atomics are present on
+the target. It routes the operations of the atomic types in this module to the
+fundamental atomics.
+**/
+pub trait AtomicInteger: IsInteger {
+	/// The fundamental atomic type for this integer.
+	type Atom: Send + Sync;
+
+	/// Creates a new atomic with an initial value.
+	fn new_atom(value: Self) -> Self::Atom;
+
+	/// Loads the current value.
+	fn atom_load(atom: &Self::Atom, order: Ordering) -> Self;
+
+	/// Stores a new value.
+	fn atom_store(atom: &Self::Atom, value: Self, order: Ordering);
+
+	/// Stores a new value, returning the previous value.
+	fn atom_swap(atom: &Self::Atom, value: Self, order: Ordering) -> Self;
+
+	/// Adds to the current value, wrapping on overflow and returning the
+	/// previous value.
+	fn atom_fetch_add(atom: &Self::Atom, value: Self, order: Ordering) -> Self;
+
+	/// Subtracts from the current value, wrapping on overflow and returning
+	/// the previous value.
+	fn atom_fetch_sub(atom: &Self::Atom, value: Self, order: Ordering) -> Self;
+
+	/// Fetches the value and applies a function to it that optionally returns
+	/// a new value, looping until the new value is stored or the function
+	/// declines to produce one.
+	fn atom_fetch_update(
+		atom: &Self::Atom,
+		set_order: Ordering,
+		fetch_order: Ordering,
+		func: impl FnMut(Self) -> Option<Self>,
+	) -> Result<Self, Self>;
+
+	/// Mutably borrows the value of an atomic that is not shared.
+	fn atom_get_mut(atom: &mut Self::Atom) -> &mut Self;
+
+	/// Unwraps an atomic into its value.
+	fn atom_into_inner(atom: Self::Atom) -> Self;
+}
+
+/** An atomic integer whose read-modify-write operations wrap on overflow.
+
+This is the behavior of the fundamental atomics, and of the processor’s own
+atomic instructions, so it has no cost over using them directly.
+**/
+#[repr(transparent)]
+pub struct AtomicWrapping<T: AtomicInteger> {
+	inner: T::Atom,
+}
+
+impl<T: AtomicInteger> AtomicWrapping<T> {
+	/// Creates a new atomic with an initial value.
+	pub fn new(value: impl Into<Wrapping<T>>) -> Self {
+		Self {
+			inner: T::new_atom(value.into().value),
+		}
+	}
+
+	/// Loads the current value.
+	pub fn load(&self, order: Ordering) -> Wrapping<T> {
+		T::atom_load(&self.inner, order).into()
+	}
+
+	/// Stores a new value.
+	pub fn store(&self, value: impl Into<Wrapping<T>>, order: Ordering) {
+		T::atom_store(&self.inner, value.into().value, order)
+	}
+
+	/// Stores a new value, returning the previous value.
+	pub fn swap(
+		&self,
+		value: impl Into<Wrapping<T>>,
+		order: Ordering,
+	) -> Wrapping<T>
+	{
+		T::atom_swap(&self.inner, value.into().value, order).into()
+	}
+
+	/// Adds to the current value, wrapping around at the boundary of the
+	/// type, and returns the previous value.
+	pub fn fetch_add(
+		&self,
+		value: impl Into<Wrapping<T>>,
+		order: Ordering,
+	) -> Wrapping<T>
+	{
+		T::atom_fetch_add(&self.inner, value.into().value, order).into()
+	}
+
+	/// Subtracts from the current value, wrapping around at the boundary of
+	/// the type, and returns the previous value.
+	pub fn fetch_sub(
+		&self,
+		value: impl Into<Wrapping<T>>,
+		order: Ordering,
+	) -> Wrapping<T>
+	{
+		T::atom_fetch_sub(&self.inner, value.into().value, order).into()
+	}
+
+	/// Fetches the value and applies a function to it that optionally returns
+	/// a new value.
+	///
+	/// This returns `Ok` of the previous value if the function produced a new
+	/// value, and `Err` of the current value if it did not.
+	///
+	/// # Original
+	///
+	/// [`AtomicUsize::fetch_update`](https://doc.rust-lang.org/core/sync/atomic/struct.AtomicUsize.html#method.fetch_update)
+	pub fn fetch_update(
+		&self,
+		set_order: Ordering,
+		fetch_order: Ordering,
+		mut func: impl FnMut(Wrapping<T>) -> Option<Wrapping<T>>,
+	) -> Result<Wrapping<T>, Wrapping<T>>
+	{
+		T::atom_fetch_update(&self.inner, set_order, fetch_order, |val| {
+			func(val.into()).map(|new| new.value)
+		})
+		.map(Wrapping::from)
+		.map_err(Wrapping::from)
+	}
+
+	/// Mutably borrows the value, which is statically known to not be shared.
+	pub fn get_mut(&mut self) -> &mut T {
+		T::atom_get_mut(&mut self.inner)
+	}
+
+	/// Unwraps the atomic into its value.
+	pub fn into_inner(self) -> Wrapping<T> {
+		T::atom_into_inner(self.inner).into()
+	}
+}
+
+impl<T: AtomicInteger> Debug for AtomicWrapping<T> {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_tuple("AtomicWrapping")
+			.field(&self.load(Ordering::Relaxed).value)
+			.finish()
+	}
+}
+
+impl<T: AtomicInteger> Default for AtomicWrapping<T> {
+	fn default() -> Self {
+		Self::new(T::default())
+	}
+}
+
+impl<T: AtomicInteger> From<T> for AtomicWrapping<T> {
+	fn from(value: T) -> Self {
+		Self::new(value)
+	}
+}
+
+/** An atomic integer whose read-modify-write operations refuse to overflow.
+
+The stored value is never poisoned. Instead, an operation which would overflow
+leaves the stored value untouched, and reports its failure to the caller. This
+is suitable for reference counts and other bookkeeping that must never wrap, and
+must not be silently clamped either.
+
+The checked operations are implemented as compare-exchange loops.
+**/
+#[repr(transparent)]
+pub struct AtomicChecked<T: AtomicInteger> {
+	inner: T::Atom,
+}
+
+impl<T: AtomicInteger> AtomicChecked<T> {
+	/// Creates a new atomic with an initial value.
+	pub fn new(value: T) -> Self {
+		Self {
+			inner: T::new_atom(value),
+		}
+	}
+
+	/// Loads the current value.
+	pub fn load(&self, order: Ordering) -> T {
+		T::atom_load(&self.inner, order)
+	}
+
+	/// Stores a new value.
+	pub fn store(&self, value: T, order: Ordering) {
+		T::atom_store(&self.inner, value, order)
+	}
+
+	/// Stores a new value, returning the previous value.
+	pub fn swap(&self, value: T, order: Ordering) -> T {
+		T::atom_swap(&self.inner, value, order)
+	}
+
+	/// Adds to the current value, unless the sum would overflow.
+	///
+	/// This returns the previous value if the sum was stored, and `None` if
+	/// the sum overflowed and the stored value was left unchanged.
+	///
+	/// `set_order` and `fetch_order` have the same meaning as in
+	/// [`fetch_update`](Self::fetch_update).
+	pub fn fetch_checked_add(
+		&self,
+		value: T,
+		set_order: Ordering,
+		fetch_order: Ordering,
+	) -> Option<T>
+	{
+		self.fetch_update(set_order, fetch_order, |old| old.checked_add(value))
+			.ok()
+	}
+
+	/// Subtracts from the current value, unless the difference would
+	/// overflow.
+	///
+	/// This returns the previous value if the difference was stored, and
+	/// `None` if the difference overflowed and the stored value was left
+	/// unchanged.
+	///
+	/// `set_order` and `fetch_order` have the same meaning as in
+	/// [`fetch_update`](Self::fetch_update).
+	pub fn fetch_checked_sub(
+		&self,
+		value: T,
+		set_order: Ordering,
+		fetch_order: Ordering,
+	) -> Option<T>
+	{
+		self.fetch_update(set_order, fetch_order, |old| old.checked_sub(value))
+			.ok()
+	}
+
+	/// Fetches the value and applies a function to it that optionally returns
+	/// a new value.
+	///
+	/// This returns `Ok` of the previous value if the function produced a new
+	/// value, and `Err` of the current value if it did not.
+	///
+	/// # Original
+	///
+	/// [`AtomicUsize::fetch_update`](https://doc.rust-lang.org/core/sync/atomic/struct.AtomicUsize.html#method.fetch_update)
+	pub fn fetch_update(
+		&self,
+		set_order: Ordering,
+		fetch_order: Ordering,
+		func: impl FnMut(T) -> Option<T>,
+	) -> Result<T, T>
+	{
+		T::atom_fetch_update(&self.inner, set_order, fetch_order, func)
+	}
+
+	/// Mutably borrows the value, which is statically known to not be shared.
+	pub fn get_mut(&mut self) -> &mut T {
+		T::atom_get_mut(&mut self.inner)
+	}
+
+	/// Unwraps the atomic into its value.
+	pub fn into_inner(self) -> T {
+		T::atom_into_inner(self.inner)
+	}
+}
+
+impl<T: AtomicInteger> Debug for AtomicChecked<T> {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_tuple("AtomicChecked")
+			.field(&self.load(Ordering::Relaxed))
+			.finish()
+	}
+}
+
+impl<T: AtomicInteger> Default for AtomicChecked<T> {
+	fn default() -> Self {
+		Self::new(T::default())
+	}
+}
+
+impl<T: AtomicInteger> From<T> for AtomicChecked<T> {
+	fn from(value: T) -> Self {
+		Self::new(value)
+	}
+}
+
+/** An atomic integer whose read-modify-write operations saturate at the
+boundaries of the type.
+
+The fundamental atomics have no saturating instructions, so the saturating
+operations are implemented as compare-exchange loops. They are more expensive
+than the wrapping operations under contention, but a counter built on them can
+never roll over from its maximum back to zero.
+**/
+#[repr(transparent)]
+pub struct AtomicSaturating<T: AtomicInteger> {
+	inner: T::Atom,
+}
+
+impl<T: AtomicInteger> AtomicSaturating<T> {
+	/// Creates a new atomic with an initial value.
+	pub fn new(value: impl Into<Saturating<T>>) -> Self {
+		Self {
+			inner: T::new_atom(value.into().value),
+		}
+	}
+
+	/// Loads the current value.
+	pub fn load(&self, order: Ordering) -> Saturating<T> {
+		T::atom_load(&self.inner, order).into()
+	}
+
+	/// Stores a new value.
+	pub fn store(&self, value: impl Into<Saturating<T>>, order: Ordering) {
+		T::atom_store(&self.inner, value.into().value, order)
+	}
+
+	/// Stores a new value, returning the previous value.
+	pub fn swap(
+		&self,
+		value: impl Into<Saturating<T>>,
+		order: Ordering,
+	) -> Saturating<T>
+	{
+		T::atom_swap(&self.inner, value.into().value, order).into()
+	}
+
+	/// Adds to the current value, saturating at the maximum value of the type,
+	/// and returns the previous value.
+	///
+	/// `set_order` and `fetch_order` have the same meaning as in
+	/// [`fetch_update`](Self::fetch_update).
+	pub fn fetch_saturating_add(
+		&self,
+		value: impl Into<Saturating<T>>,
+		set_order: Ordering,
+		fetch_order: Ordering,
+	) -> Saturating<T>
+	{
+		let value = value.into();
+		self.fetch_update(set_order, fetch_order, |old| Some(old + value))
+			.unwrap_or_else(|old| old)
+	}
+
+	/// Subtracts from the current value, saturating at the minimum value of
+	/// the type, and returns the previous value.
+	///
+	/// `set_order` and `fetch_order` have the same meaning as in
+	/// [`fetch_update`](Self::fetch_update).
+	pub fn fetch_saturating_sub(
+		&self,
+		value: impl Into<Saturating<T>>,
+		set_order: Ordering,
+		fetch_order: Ordering,
+	) -> Saturating<T>
+	{
+		let value = value.into();
+		self.fetch_update(set_order, fetch_order, |old| Some(old - value))
+			.unwrap_or_else(|old| old)
+	}
+
+	/// Fetches the value and applies a function to it that optionally returns
+	/// a new value.
+	///
+	/// This returns `Ok` of the previous value if the function produced a new
+	/// value, and `Err` of the current value if it did not.
+	///
+	/// # Original
+	///
+	/// [`AtomicUsize::fetch_update`](https://doc.rust-lang.org/core/sync/atomic/struct.AtomicUsize.html#method.fetch_update)
+	pub fn fetch_update(
+		&self,
+		set_order: Ordering,
+		fetch_order: Ordering,
+		mut func: impl FnMut(Saturating<T>) -> Option<Saturating<T>>,
+	) -> Result<Saturating<T>, Saturating<T>>
+	{
+		T::atom_fetch_update(&self.inner, set_order, fetch_order, |val| {
+			func(val.into()).map(|new| new.value)
+		})
+		.map(Saturating::from)
+		.map_err(Saturating::from)
+	}
+
+	/// Mutably borrows the value, which is statically known to not be shared.
+	pub fn get_mut(&mut self) -> &mut T {
+		T::atom_get_mut(&mut self.inner)
+	}
+
+	/// Unwraps the atomic into its value.
+	pub fn into_inner(self) -> Saturating<T> {
+		T::atom_into_inner(self.inner).into()
+	}
+}
+
+impl<T: AtomicInteger> Debug for AtomicSaturating<T> {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.debug_tuple("AtomicSaturating")
+			.field(&self.load(Ordering::Relaxed).value)
+			.finish()
+	}
+}
+
+impl<T: AtomicInteger> Default for AtomicSaturating<T> {
+	fn default() -> Self {
+		Self::new(T::default())
+	}
+}
+
+impl<T: AtomicInteger> From<T> for AtomicSaturating<T> {
+	fn from(value: T) -> Self {
+		Self::new(value)
+	}
+}
+
+macro_rules! atomic {
+	($($width:literal => $t:ty => $atom:ident),* $(,)?) => { $(
+		#[cfg(target_has_atomic = $width)]
+		impl AtomicInteger for $t {
+			type Atom = atomic::$atom;
+
+			fn new_atom(value: Self) -> Self::Atom {
+				atomic::$atom::new(value)
+			}
+
+			fn atom_load(atom: &Self::Atom, order: Ordering) -> Self {
+				atom.load(order)
+			}
+
+			fn atom_store(atom: &Self::Atom, value: Self, order: Ordering) {
+				atom.store(value, order)
+			}
+
+			fn atom_swap(
+				atom: &Self::Atom,
+				value: Self,
+				order: Ordering,
+			) -> Self
+			{
+				atom.swap(value, order)
+			}
+
+			fn atom_fetch_add(
+				atom: &Self::Atom,
+				value: Self,
+				order: Ordering,
+			) -> Self
+			{
+				atom.fetch_add(value, order)
+			}
+
+			fn atom_fetch_sub(
+				atom: &Self::Atom,
+				value: Self,
+				order: Ordering,
+			) -> Self
+			{
+				atom.fetch_sub(value, order)
+			}
+
+			fn atom_fetch_update(
+				atom: &Self::Atom,
+				set_order: Ordering,
+				fetch_order: Ordering,
+				func: impl FnMut(Self) -> Option<Self>,
+			) -> Result<Self, Self>
+			{
+				atom.fetch_update(set_order, fetch_order, func)
+			}
+
+			fn atom_get_mut(atom: &mut Self::Atom) -> &mut Self {
+				atom.get_mut()
+			}
+
+			fn atom_into_inner(atom: Self::Atom) -> Self {
+				atom.into_inner()
+			}
+		}
+	)* };
+}
+
+atomic!(
+	"8" => u8 => AtomicU8,
+	"16" => u16 => AtomicU16,
+	"32" => u32 => AtomicU32,
+	"64" => u64 => AtomicU64,
+	"ptr" => usize => AtomicUsize,
+);
@@ -0,0 +1,175 @@
+//! Cast interoperation with `az`.
+//!
+//! This module is only present when the `az` feature is enabled.
+//!
+//! `Overflowing`, `Saturating`, and `Wrapping` implement each of the `az` cast
+//! traits for every destination that their contained integer does, so that
+//! `.checked_as()`, `.saturating_as()`, `.wrapping_as()`, and the rest of the
+//! `az` extension methods convert the integer out of the wrapper. `Checked`
+//! implements only `CheckedCast`, whose `None` also covers a poisoned value.
+//!
+//! In the other direction, every fundamental integer and float implements
+//! `Cast` into each wrapper by applying that wrapper’s policy, so `.az()` into a
+//! `Checked` poisons a value that is out of range, into a `Saturating` clamps
+//! it, into a `Wrapping` wraps it, and into an `Overflowing` wraps it and sets
+//! the flag.
+//!
+//! ```rust
+//! use az::{Az as _, CheckedAs as _, SaturatingAs as _};
+//! use surety::{Checked, Overflowing, Saturating, Wrapping};
+//!
+//! assert_eq!(Saturating::new(300i32).saturating_as::<u8>(), 255);
+//! assert_eq!(Wrapping::new(-1i16).checked_as::<u16>(), None);
+//! assert_eq!(Checked::new(200u32).checked_as::<u8>(), Some(200));
+//!
+//! assert_eq!(300i32.az::<Saturating<u8>>(), 255);
+//! assert_eq!(300i32.az::<Wrapping<u8>>(), 44);
+//! assert!(300i32.az::<Checked<u8>>().is_none());
+//! assert!(300i32.az::<Overflowing<u8>>().has_overflowed());
+//! assert_eq!(2.75f64.az::<Saturating<i8>>(), 2);
+//! ```
+
+use ::az::{
+	Cast,
+	CheckedCast,
+	OverflowingCast,
+	SaturatingCast,
+	StrictCast,
+	UnwrappedCast,
+	WrappingCast,
+};
+use funty::IsInteger;
+
+use crate::{
+	Checked,
+	Overflowing,
+	Saturating,
+	Wrapping,
+};
+
+macro_rules! from_wrapper {
+	($($w:ident),+ $(,)?) => { $(
+		impl<T, Dst> Cast<Dst> for $w<T>
+		where
+			T: IsInteger + Cast<Dst>,
+		{
+			fn cast(self) -> Dst {
+				self.value.cast()
+			}
+		}
+
+		impl<T, Dst> CheckedCast<Dst> for $w<T>
+		where
+			T: IsInteger + CheckedCast<Dst>,
+		{
+			fn checked_cast(self) -> Option<Dst> {
+				self.value.checked_cast()
+			}
+		}
+
+		impl<T, Dst> StrictCast<Dst> for $w<T>
+		where
+			T: IsInteger + StrictCast<Dst>,
+		{
+			fn strict_cast(self) -> Dst {
+				self.value.strict_cast()
+			}
+		}
+
+		impl<T, Dst> SaturatingCast<Dst> for $w<T>
+		where
+			T: IsInteger + SaturatingCast<Dst>,
+		{
+			fn saturating_cast(self) -> Dst {
+				self.value.saturating_cast()
+			}
+		}
+
+		impl<T, Dst> WrappingCast<Dst> for $w<T>
+		where
+			T: IsInteger + WrappingCast<Dst>,
+		{
+			fn wrapping_cast(self) -> Dst {
+				self.value.wrapping_cast()
+			}
+		}
+
+		impl<T, Dst> OverflowingCast<Dst> for $w<T>
+		where
+			T: IsInteger + OverflowingCast<Dst>,
+		{
+			fn overflowing_cast(self) -> (Dst, bool) {
+				self.value.overflowing_cast()
+			}
+		}
+
+		impl<T, Dst> UnwrappedCast<Dst> for $w<T>
+		where
+			T: IsInteger + UnwrappedCast<Dst>,
+		{
+			#[track_caller]
+			fn unwrapped_cast(self) -> Dst {
+				self.value.unwrapped_cast()
+			}
+		}
+	)+ };
+}
+
+from_wrapper!(Overflowing, Saturating, Wrapping);
+
+impl<T, Dst> CheckedCast<Dst> for Checked<T>
+where
+	T: IsInteger + CheckedCast<Dst>,
+{
+	fn checked_cast(self) -> Option<Dst> {
+		self.value.and_then(CheckedCast::checked_cast)
+	}
+}
+
+macro_rules! into_wrapper {
+	($($src:ty),+ $(,)?) => { $(
+		impl<T> Cast<Checked<T>> for $src
+		where
+			T: IsInteger,
+			$src: CheckedCast<T>,
+		{
+			fn cast(self) -> Checked<T> {
+				self.checked_cast().into()
+			}
+		}
+
+		impl<T> Cast<Overflowing<T>> for $src
+		where
+			T: IsInteger,
+			$src: OverflowingCast<T>,
+		{
+			fn cast(self) -> Overflowing<T> {
+				self.overflowing_cast().into()
+			}
+		}
+
+		impl<T> Cast<Saturating<T>> for $src
+		where
+			T: IsInteger,
+			$src: SaturatingCast<T>,
+		{
+			fn cast(self) -> Saturating<T> {
+				Saturating::new(self.saturating_cast())
+			}
+		}
+
+		impl<T> Cast<Wrapping<T>> for $src
+		where
+			T: IsInteger,
+			$src: WrappingCast<T>,
+		{
+			fn cast(self) -> Wrapping<T> {
+				Wrapping::new(self.wrapping_cast())
+			}
+		}
+	)+ };
+}
+
+into_wrapper!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64);
+#[cfg(not(feature = "no-128"))]
+into_wrapper!(i128, u128);
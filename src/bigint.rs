@@ -0,0 +1,108 @@
+//! Conversions to and from the arbitrary-precision integers of `num-bigint`.
+//!
+//! This module is only present when the `num-bigint` feature is enabled.
+//!
+//! `Saturating` and `Wrapping` convert into `BigInt`, and into `BigUint` when
+//! they hold an unsigned integer, with `From`, because the conversion cannot
+//! lose information. Returning to a fixed width is explicit, through the
+//! `from_bigint` and `from_biguint` constructors of `Checked` and `Saturating`,
+//! which poison or clamp a value that does not fit.
+
+use core::convert::TryFrom;
+
+use funty::IsInteger;
+use num_bigint::{
+	BigInt,
+	BigUint,
+	Sign,
+};
+
+use crate::{
+	Checked,
+	Saturating,
+	Wrapping,
+};
+
+macro_rules! into_big {
+	($($w:ident),+ $(,)?) => { $(
+		impl<T> From<$w<T>> for BigInt
+		where T: IsInteger + Into<BigInt> {
+			fn from(num: $w<T>) -> Self {
+				num.into_inner().into()
+			}
+		}
+
+		impl<T> From<$w<T>> for BigUint
+		where T: IsInteger + Into<BigUint> {
+			fn from(num: $w<T>) -> Self {
+				num.into_inner().into()
+			}
+		}
+	)+ };
+}
+
+into_big!(Saturating, Wrapping);
+
+impl<T: IsInteger> Checked<T> {
+	/// Narrows an arbitrary-precision integer, poisoning it if it does not fit
+	/// in `T`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use num_bigint::BigInt;
+	/// use surety::{Checked, Ensure};
+	///
+	/// let big = BigInt::from(200u8.wrapping()) * 400;
+	/// assert!(Checked::<u16>::from_bigint(&big).is_none());
+	/// assert_eq!(Checked::<u32>::from_bigint(&big), Some(80_000));
+	/// ```
+	pub fn from_bigint(big: &BigInt) -> Self
+	where for<'a> T: TryFrom<&'a BigInt> {
+		T::try_from(big).ok().into()
+	}
+
+	/// Narrows an arbitrary-precision natural number, poisoning it if it does
+	/// not fit in `T`.
+	pub fn from_biguint(big: &BigUint) -> Self
+	where for<'a> T: TryFrom<&'a BigUint> {
+		T::try_from(big).ok().into()
+	}
+}
+
+impl<T: IsInteger> Saturating<T> {
+	/// Narrows an arbitrary-precision integer, clamping it to the range of `T`
+	/// if it does not fit.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use num_bigint::BigInt;
+	/// use surety::{Ensure, Saturating};
+	///
+	/// let big = BigInt::from(-100i8.saturating()) * 1000;
+	/// assert_eq!(Saturating::<i16>::from_bigint(&big), i16::MIN);
+	/// assert_eq!(Saturating::<u8>::from_bigint(&big), 0);
+	/// assert_eq!(Saturating::<i32>::from_bigint(&big), -100_000);
+	/// ```
+	pub fn from_bigint(big: &BigInt) -> Self
+	where for<'a> T: TryFrom<&'a BigInt> {
+		T::try_from(big)
+			.unwrap_or_else(|_| {
+				if big.sign() == Sign::Minus {
+					T::MIN
+				}
+				else {
+					T::MAX
+				}
+			})
+			.into()
+	}
+
+	/// Narrows an arbitrary-precision natural number, clamping it to the
+	/// maximum of `T` if it does not fit.
+	pub fn from_biguint(big: &BigUint) -> Self
+	where for<'a> T: TryFrom<&'a BigUint> {
+		T::try_from(big).unwrap_or(T::MAX).into()
+	}
+}
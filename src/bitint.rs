@@ -0,0 +1,909 @@
+//! Integers of any width up to that of a fundamental integer.
+//!
+//! `UInt<T, BITS>` and `Int<T, BITS>` hold a `BITS`-bit number in the
+//! fundamental `T`, and implement the same integer interface as the
+//! fundamentals, overflowing at the declared width rather than at the width of
+//! `T`. Each arithmetic method is computed in `T`, and the result is then
+//! narrowed to `BITS` bits, if necessary, with the overflow flag set when that
+//! changed it.
+
+use core::{
+	cmp::Ordering,
+	convert::{
+		TryFrom,
+		TryInto,
+	},
+	fmt::{
+		self,
+		Binary,
+		Debug,
+		Display,
+		Formatter,
+		LowerHex,
+		Octal,
+		UpperHex,
+	},
+	iter::{
+		Product,
+		Sum,
+	},
+	num::{
+		ParseIntError,
+		TryFromIntError,
+	},
+	ops::{
+		Add,
+		AddAssign,
+		BitAnd,
+		BitAndAssign,
+		BitOr,
+		BitOrAssign,
+		BitXor,
+		BitXorAssign,
+		Div,
+		DivAssign,
+		Mul,
+		MulAssign,
+		Neg,
+		Not,
+		Rem,
+		RemAssign,
+		Shl,
+		ShlAssign,
+		Shr,
+		ShrAssign,
+		Sub,
+		SubAssign,
+	},
+	str::FromStr,
+};
+
+use funty::{
+	IsInteger,
+	IsNumber,
+	IsSigned,
+	IsUnsigned,
+};
+
+use crate::int256::{
+	checked,
+	debug,
+	errors,
+};
+
+/** An unsigned integer of `BITS` bits, stored in the unsigned fundamental `T`.
+
+This type implements the same integer interface as the fundamental integers,
+so it can be used in any of the wrapper types, which then overflow at the
+declared width: a `Wrapping<UInt<u16, 12>>` wraps at 4096, and a
+`Saturating<UInt<u16, 12>>` clamps at 4095. Its operators behave as the
+fundamentals’ do: overflow panics when debug assertions are enabled, and wraps
+otherwise.
+
+`BITS` must be at least 1 and at most the width of `T`, which is checked when
+the type is used. The bits of `T` above `BITS` are always clear. The byte
+conversions, and `swap_bytes`, treat the number as having the width of `T`.
+
+The arithmetic methods are provided by the `funty::IsInteger` and
+`funty::IsUnsigned` traits.
+
+# Examples
+
+```rust
+use surety::{Ensure, UInt};
+
+#[allow(non_camel_case_types)]
+type u12 = UInt<u16, 12>;
+
+let max = u12::MAX;
+assert_eq!(max.get(), 4095);
+
+let one = u12::new(1).unwrap();
+assert!((max.checked() + one).is_none());
+assert_eq!(max.wrapping() + one, u12::ZERO);
+assert_eq!(max.saturating() + one, u12::MAX);
+assert!(u12::new(4096).is_none());
+```
+**/
+#[derive(Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct UInt<T, const BITS: u32> {
+	/// The number, with every bit above `BITS` clear.
+	value: T,
+}
+
+/** A signed integer of `BITS` bits, stored in the signed fundamental `T`.
+
+This type implements the same integer interface as the fundamental integers,
+so it can be used in any of the wrapper types, which then overflow at the
+declared width: a `Checked<Int<i32, 24>>` is poisoned outside
+`-8_388_608 ..= 8_388_607`. Its operators behave as the fundamentals’ do:
+overflow panics when debug assertions are enabled, and wraps otherwise.
+
+`BITS` must be at least 1 and at most the width of `T`, which is checked when
+the type is used. The number is held in two’s complement, sign-extended through
+the bits of `T` above `BITS`. The byte conversions, and `swap_bytes`, treat the
+number as having the width of `T`.
+
+The arithmetic methods are provided by the `funty::IsInteger` and
+`funty::IsSigned` traits.
+
+# Examples
+
+```rust
+use surety::{Ensure, Int};
+
+#[allow(non_camel_case_types)]
+type i24 = Int<i32, 24>;
+
+let sample = i24::new(-8_000_000).unwrap();
+let louder = sample.saturating() * i24::new(2).unwrap();
+assert_eq!(louder, i24::MIN);
+assert_eq!(louder.into_inner().get(), -8_388_608);
+assert_eq!(format!("{:x}", i24::new(-1).unwrap()), "ffffff");
+```
+**/
+#[derive(Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Int<T, const BITS: u32> {
+	/// The number, sign-extended from bit `BITS - 1` through the rest of `T`.
+	value: T,
+}
+
+macro_rules! bitint {
+	($($t:ident<$s:ident> as $u:ident);+ $(;)?) => { $(
+		impl<const BITS: u32> $t<$s, BITS> {
+			/// The number of bits of the storage integer above the declared
+			/// width.
+			const SHIFT: u32 = {
+				assert!(
+					BITS > 0 && BITS <= $s::BITS,
+					"the width must be at least 1 and at most the width of the \
+					 storage integer",
+				);
+				$s::BITS - BITS
+			};
+
+			/// The smallest value of this type.
+			pub const MIN: Self = Self {
+				value: $s::MIN >> Self::SHIFT,
+			};
+
+			/// The largest value of this type.
+			pub const MAX: Self = Self {
+				value: $s::MAX >> Self::SHIFT,
+			};
+
+			/// The value zero.
+			pub const ZERO: Self = Self { value: 0 };
+
+			/// The value one, or its truncation to a single signed bit.
+			const ONE: Self = Self::new_wrapping(1);
+
+			/// Whether this type is signed.
+			const SIGNED: bool = $s::MIN != 0;
+
+			/// Creates a number, if `value` fits in `BITS` bits.
+			pub const fn new(value: $s) -> Option<Self> {
+				let out = Self::new_wrapping(value);
+				if out.value == value { Some(out) } else { None }
+			}
+
+			/// Creates a number from the lowest `BITS` bits of `value`,
+			/// discarding the rest.
+			pub const fn new_wrapping(value: $s) -> Self {
+				Self {
+					value: value << Self::SHIFT >> Self::SHIFT,
+				}
+			}
+
+			/// Gets the number, in its storage integer.
+			pub const fn get(self) -> $s {
+				self.value
+			}
+
+			/// Gets the lowest `BITS` bits of the number, with the rest clear.
+			const fn bits(self) -> $u {
+				(self.value as $u) << Self::SHIFT >> Self::SHIFT
+			}
+
+			/// Tests if the number is below zero.
+			fn is_neg(self) -> bool {
+				Self::SIGNED && self.value.leading_zeros() == 0
+			}
+
+			/// Narrows a result computed in the storage integer to `BITS`
+			/// bits, adding whether this changed it to the overflow flag.
+			fn narrow((value, ovf): ($s, bool)) -> (Self, bool) {
+				let out = Self::new_wrapping(value);
+				(out, ovf || out.value != value)
+			}
+
+			/// Tests if dividing by `rhs` overflows, which only `MIN / -1`
+			/// does.
+			fn div_overflows(self, rhs: Self) -> bool {
+				Self::SIGNED && self == Self::MIN && rhs == !Self::ZERO
+			}
+
+			/// Parses a number, as the fundamental integers do.
+			fn parse(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+				let value = $s::from_str_radix(src, radix)?;
+				Self::new(value).ok_or_else(|| {
+					if Self::SIGNED && value.leading_zeros() == 0 {
+						errors::neg_overflow()
+					}
+					else {
+						errors::pos_overflow()
+					}
+				})
+			}
+		}
+
+		impl<const BITS: u32> Debug for $t<$s, BITS> {
+			fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+				Display::fmt(self, fmt)
+			}
+		}
+
+		impl<const BITS: u32> Display for $t<$s, BITS> {
+			fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+				Display::fmt(&self.value, fmt)
+			}
+		}
+
+		impl<const BITS: u32> Binary for $t<$s, BITS> {
+			fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+				Binary::fmt(&self.bits(), fmt)
+			}
+		}
+
+		impl<const BITS: u32> Octal for $t<$s, BITS> {
+			fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+				Octal::fmt(&self.bits(), fmt)
+			}
+		}
+
+		impl<const BITS: u32> LowerHex for $t<$s, BITS> {
+			fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+				LowerHex::fmt(&self.bits(), fmt)
+			}
+		}
+
+		impl<const BITS: u32> UpperHex for $t<$s, BITS> {
+			fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+				UpperHex::fmt(&self.bits(), fmt)
+			}
+		}
+
+		impl<const BITS: u32> FromStr for $t<$s, BITS> {
+			type Err = ParseIntError;
+
+			fn from_str(src: &str) -> Result<Self, Self::Err> {
+				Self::parse(src, 10)
+			}
+		}
+
+		impl<const BITS: u32> IsNumber for $t<$s, BITS> {
+			type Bytes = <$s as IsNumber>::Bytes;
+
+			fn to_be_bytes(self) -> Self::Bytes {
+				self.value.to_be_bytes()
+			}
+
+			fn to_le_bytes(self) -> Self::Bytes {
+				self.value.to_le_bytes()
+			}
+
+			fn to_ne_bytes(self) -> Self::Bytes {
+				self.value.to_ne_bytes()
+			}
+
+			fn from_be_bytes(bytes: Self::Bytes) -> Self {
+				Self::new_wrapping($s::from_be_bytes(bytes))
+			}
+
+			fn from_le_bytes(bytes: Self::Bytes) -> Self {
+				Self::new_wrapping($s::from_le_bytes(bytes))
+			}
+
+			fn from_ne_bytes(bytes: Self::Bytes) -> Self {
+				Self::new_wrapping($s::from_ne_bytes(bytes))
+			}
+		}
+
+		impl<const BITS: u32> IsInteger for $t<$s, BITS> {
+			const ZERO: Self = Self::ZERO;
+			const MIN: Self = Self::MIN;
+			const MAX: Self = Self::MAX;
+
+			fn min_value() -> Self {
+				Self::MIN
+			}
+
+			fn max_value() -> Self {
+				Self::MAX
+			}
+
+			fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+				Self::parse(src, radix)
+			}
+
+			fn count_ones(self) -> u32 {
+				self.bits().count_ones()
+			}
+
+			fn count_zeros(self) -> u32 {
+				BITS - self.count_ones()
+			}
+
+			fn leading_zeros(self) -> u32 {
+				self.bits().leading_zeros() - Self::SHIFT
+			}
+
+			fn trailing_zeros(self) -> u32 {
+				self.bits().trailing_zeros().min(BITS)
+			}
+
+			fn leading_ones(self) -> u32 {
+				(!self).leading_zeros()
+			}
+
+			fn trailing_ones(self) -> u32 {
+				(!self).trailing_zeros()
+			}
+
+			fn rotate_left(self, n: u32) -> Self {
+				let n = n % BITS;
+				if n == 0 {
+					return self;
+				}
+				let bits = self.bits();
+				Self::new_wrapping((bits << n | bits >> (BITS - n)) as $s)
+			}
+
+			fn rotate_right(self, n: u32) -> Self {
+				self.rotate_left(BITS - n % BITS)
+			}
+
+			fn swap_bytes(self) -> Self {
+				Self::new_wrapping((self.bits().swap_bytes() >> Self::SHIFT) as $s)
+			}
+
+			fn reverse_bits(self) -> Self {
+				Self::new_wrapping((self.bits().reverse_bits() >> Self::SHIFT) as $s)
+			}
+
+			fn from_be(self) -> Self {
+				self.to_be()
+			}
+
+			fn from_le(self) -> Self {
+				self.to_le()
+			}
+
+			fn to_be(self) -> Self {
+				if cfg!(target_endian = "big") {
+					self
+				}
+				else {
+					self.swap_bytes()
+				}
+			}
+
+			fn to_le(self) -> Self {
+				if cfg!(target_endian = "big") {
+					self.swap_bytes()
+				}
+				else {
+					self
+				}
+			}
+
+			fn checked_add(self, rhs: Self) -> Option<Self> {
+				checked(self.overflowing_add(rhs))
+			}
+
+			fn checked_sub(self, rhs: Self) -> Option<Self> {
+				checked(self.overflowing_sub(rhs))
+			}
+
+			fn checked_mul(self, rhs: Self) -> Option<Self> {
+				checked(self.overflowing_mul(rhs))
+			}
+
+			fn checked_div(self, rhs: Self) -> Option<Self> {
+				if rhs == Self::ZERO {
+					return None;
+				}
+				checked(self.overflowing_div(rhs))
+			}
+
+			fn checked_div_euclid(self, rhs: Self) -> Option<Self> {
+				if rhs == Self::ZERO {
+					return None;
+				}
+				checked(self.overflowing_div_euclid(rhs))
+			}
+
+			fn checked_rem(self, rhs: Self) -> Option<Self> {
+				if rhs == Self::ZERO {
+					return None;
+				}
+				checked(self.overflowing_rem(rhs))
+			}
+
+			fn checked_rem_euclid(self, rhs: Self) -> Option<Self> {
+				if rhs == Self::ZERO {
+					return None;
+				}
+				checked(self.overflowing_rem_euclid(rhs))
+			}
+
+			fn checked_neg(self) -> Option<Self> {
+				checked(self.overflowing_neg())
+			}
+
+			fn checked_shl(self, rhs: u32) -> Option<Self> {
+				checked(self.overflowing_shl(rhs))
+			}
+
+			fn checked_shr(self, rhs: u32) -> Option<Self> {
+				checked(self.overflowing_shr(rhs))
+			}
+
+			fn checked_pow(self, rhs: u32) -> Option<Self> {
+				checked(self.overflowing_pow(rhs))
+			}
+
+			fn saturating_add(self, rhs: Self) -> Self {
+				self.checked_add(rhs).unwrap_or_else(|| {
+					if rhs.is_neg() { Self::MIN } else { Self::MAX }
+				})
+			}
+
+			fn saturating_sub(self, rhs: Self) -> Self {
+				self.checked_sub(rhs).unwrap_or_else(|| {
+					if Self::SIGNED && rhs.is_neg() {
+						Self::MAX
+					}
+					else {
+						Self::MIN
+					}
+				})
+			}
+
+			fn saturating_mul(self, rhs: Self) -> Self {
+				self.checked_mul(rhs).unwrap_or_else(|| {
+					if self.is_neg() != rhs.is_neg() {
+						Self::MIN
+					}
+					else {
+						Self::MAX
+					}
+				})
+			}
+
+			fn saturating_pow(self, rhs: u32) -> Self {
+				self.checked_pow(rhs).unwrap_or_else(|| {
+					if self.is_neg() && rhs % 2 == 1 {
+						Self::MIN
+					}
+					else {
+						Self::MAX
+					}
+				})
+			}
+
+			fn wrapping_add(self, rhs: Self) -> Self {
+				self.overflowing_add(rhs).0
+			}
+
+			fn wrapping_sub(self, rhs: Self) -> Self {
+				self.overflowing_sub(rhs).0
+			}
+
+			fn wrapping_mul(self, rhs: Self) -> Self {
+				self.overflowing_mul(rhs).0
+			}
+
+			fn wrapping_div(self, rhs: Self) -> Self {
+				self.overflowing_div(rhs).0
+			}
+
+			fn wrapping_div_euclid(self, rhs: Self) -> Self {
+				self.overflowing_div_euclid(rhs).0
+			}
+
+			fn wrapping_rem(self, rhs: Self) -> Self {
+				self.overflowing_rem(rhs).0
+			}
+
+			fn wrapping_rem_euclid(self, rhs: Self) -> Self {
+				self.overflowing_rem_euclid(rhs).0
+			}
+
+			fn wrapping_neg(self) -> Self {
+				self.overflowing_neg().0
+			}
+
+			fn wrapping_shl(self, rhs: u32) -> Self {
+				self.overflowing_shl(rhs).0
+			}
+
+			fn wrapping_shr(self, rhs: u32) -> Self {
+				self.overflowing_shr(rhs).0
+			}
+
+			fn wrapping_pow(self, rhs: u32) -> Self {
+				self.overflowing_pow(rhs).0
+			}
+
+			fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+				Self::narrow(self.value.overflowing_add(rhs.value))
+			}
+
+			fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+				Self::narrow(self.value.overflowing_sub(rhs.value))
+			}
+
+			fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+				Self::narrow(self.value.overflowing_mul(rhs.value))
+			}
+
+			fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+				Self::narrow(self.value.overflowing_div(rhs.value))
+			}
+
+			fn overflowing_div_euclid(self, rhs: Self) -> (Self, bool) {
+				Self::narrow(self.value.overflowing_div_euclid(rhs.value))
+			}
+
+			fn overflowing_rem(self, rhs: Self) -> (Self, bool) {
+				let (out, ovf) = Self::narrow(self.value.overflowing_rem(rhs.value));
+				(out, ovf || self.div_overflows(rhs))
+			}
+
+			fn overflowing_rem_euclid(self, rhs: Self) -> (Self, bool) {
+				let (out, ovf) =
+					Self::narrow(self.value.overflowing_rem_euclid(rhs.value));
+				(out, ovf || self.div_overflows(rhs))
+			}
+
+			fn overflowing_neg(self) -> (Self, bool) {
+				Self::narrow(self.value.overflowing_neg())
+			}
+
+			fn overflowing_shl(self, rhs: u32) -> (Self, bool) {
+				let out = Self::new_wrapping(self.value << (rhs % BITS));
+				(out, rhs >= BITS)
+			}
+
+			fn overflowing_shr(self, rhs: u32) -> (Self, bool) {
+				let out = Self {
+					value: self.value >> (rhs % BITS),
+				};
+				(out, rhs >= BITS)
+			}
+
+			fn overflowing_pow(self, exp: u32) -> (Self, bool) {
+				Self::narrow(self.value.overflowing_pow(exp))
+			}
+
+			fn pow(self, rhs: u32) -> Self {
+				debug(self.overflowing_pow(rhs), "attempt to multiply with overflow")
+			}
+
+			fn div_euclid(self, rhs: Self) -> Self {
+				let (out, ovf) = self.overflowing_div_euclid(rhs);
+				assert!(!ovf, "attempt to divide with overflow");
+				out
+			}
+
+			fn rem_euclid(self, rhs: Self) -> Self {
+				let (out, ovf) = self.overflowing_rem_euclid(rhs);
+				assert!(!ovf, "attempt to calculate the remainder with overflow");
+				out
+			}
+		}
+
+		impl<const BITS: u32> Not for $t<$s, BITS> {
+			type Output = Self;
+
+			fn not(self) -> Self::Output {
+				Self::new_wrapping(!self.value)
+			}
+		}
+
+		impl<const BITS: u32> Sum<Self> for $t<$s, BITS> {
+			fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+				iter.fold(Self::ZERO, |a, b| a + b)
+			}
+		}
+
+		impl<'a, const BITS: u32> Sum<&'a Self> for $t<$s, BITS> {
+			fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+				iter.fold(Self::ZERO, |a, b| a + b)
+			}
+		}
+
+		impl<const BITS: u32> Product<Self> for $t<$s, BITS> {
+			fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+				iter.fold(Self::ONE, |a, b| a * b)
+			}
+		}
+
+		impl<'a, const BITS: u32> Product<&'a Self> for $t<$s, BITS> {
+			fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+				iter.fold(Self::ONE, |a, b| a * b)
+			}
+		}
+
+		op!($t<$s>;
+			Add add AddAssign add_assign => |a: Self, b| {
+				debug(a.overflowing_add(b), "attempt to add with overflow")
+			};
+			Sub sub SubAssign sub_assign => |a: Self, b| {
+				debug(a.overflowing_sub(b), "attempt to subtract with overflow")
+			};
+			Mul mul MulAssign mul_assign => |a: Self, b| {
+				debug(a.overflowing_mul(b), "attempt to multiply with overflow")
+			};
+			Div div DivAssign div_assign => |a: Self, b| {
+				let (out, ovf) = a.overflowing_div(b);
+				assert!(!ovf, "attempt to divide with overflow");
+				out
+			};
+			Rem rem RemAssign rem_assign => |a: Self, b| {
+				let (out, ovf) = a.overflowing_rem(b);
+				assert!(!ovf, "attempt to calculate the remainder with overflow");
+				out
+			};
+			BitAnd bitand BitAndAssign bitand_assign => |a: Self, b: Self| {
+				Self { value: a.value & b.value }
+			};
+			BitOr bitor BitOrAssign bitor_assign => |a: Self, b: Self| {
+				Self { value: a.value | b.value }
+			};
+			BitXor bitxor BitXorAssign bitxor_assign => |a: Self, b: Self| {
+				Self { value: a.value ^ b.value }
+			};
+		);
+
+		shift!($t<$s> => i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+		convert!($t<$s> => i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+		//  `funty`’s integer traits require shifts by, and conversions with,
+		//  the 128-bit integers, so these remain under `no-128`. They only
+		//  compare the 128-bit operand, and never divide or multiply in it.
+		shift!($t<$s> => i128, u128);
+		convert!($t<$s> => i128, u128);
+	)+ };
+}
+
+/// Implements an operator trait, and its assigning form, for a narrow integer
+/// on both values and references.
+macro_rules! op {
+	($t:ident<$s:ident>; $($trait:ident $func:ident $assign:ident $assign_func:ident => $body:expr;)+) => { $(
+		impl<const BITS: u32> $trait<Self> for $t<$s, BITS> {
+			type Output = Self;
+
+			fn $func(self, rhs: Self) -> Self::Output {
+				($body)(self, rhs)
+			}
+		}
+
+		impl<const BITS: u32> $trait<&Self> for $t<$s, BITS> {
+			type Output = Self;
+
+			fn $func(self, rhs: &Self) -> Self::Output {
+				($body)(self, *rhs)
+			}
+		}
+
+		impl<const BITS: u32> $assign<Self> for $t<$s, BITS> {
+			fn $assign_func(&mut self, rhs: Self) {
+				*self = $trait::$func(*self, rhs);
+			}
+		}
+
+		impl<const BITS: u32> $assign<&Self> for $t<$s, BITS> {
+			fn $assign_func(&mut self, rhs: &Self) {
+				*self = $trait::$func(*self, *rhs);
+			}
+		}
+	)+ };
+}
+
+/// Implements the shift operators for a narrow integer, with a shift amount of
+/// each fundamental integer.
+macro_rules! shift {
+	($t:ident<$s:ident> => $($a:ty),+ $(,)?) => { $(
+		impl<const BITS: u32> Shl<$a> for $t<$s, BITS> {
+			type Output = Self;
+
+			fn shl(self, rhs: $a) -> Self::Output {
+				let (out, _) = self.overflowing_shl(rhs as u32);
+				debug((out, too_far(rhs, BITS)), "attempt to shift left with overflow")
+			}
+		}
+
+		impl<const BITS: u32> Shl<&$a> for $t<$s, BITS> {
+			type Output = Self;
+
+			fn shl(self, rhs: &$a) -> Self::Output {
+				self << *rhs
+			}
+		}
+
+		impl<const BITS: u32> ShlAssign<$a> for $t<$s, BITS> {
+			fn shl_assign(&mut self, rhs: $a) {
+				*self = *self << rhs;
+			}
+		}
+
+		impl<const BITS: u32> ShlAssign<&$a> for $t<$s, BITS> {
+			fn shl_assign(&mut self, rhs: &$a) {
+				*self = *self << *rhs;
+			}
+		}
+
+		impl<const BITS: u32> Shr<$a> for $t<$s, BITS> {
+			type Output = Self;
+
+			fn shr(self, rhs: $a) -> Self::Output {
+				let (out, _) = self.overflowing_shr(rhs as u32);
+				debug((out, too_far(rhs, BITS)), "attempt to shift right with overflow")
+			}
+		}
+
+		impl<const BITS: u32> Shr<&$a> for $t<$s, BITS> {
+			type Output = Self;
+
+			fn shr(self, rhs: &$a) -> Self::Output {
+				self >> *rhs
+			}
+		}
+
+		impl<const BITS: u32> ShrAssign<$a> for $t<$s, BITS> {
+			fn shr_assign(&mut self, rhs: $a) {
+				*self = *self >> rhs;
+			}
+		}
+
+		impl<const BITS: u32> ShrAssign<&$a> for $t<$s, BITS> {
+			fn shr_assign(&mut self, rhs: &$a) {
+				*self = *self >> *rhs;
+			}
+		}
+	)+ };
+}
+
+/// Implements the fallible conversions between a narrow integer and each
+/// fundamental integer.
+macro_rules! convert {
+	($t:ident<$s:ident> => $($p:ty),+ $(,)?) => { $(
+		impl<const BITS: u32> TryFrom<$p> for $t<$s, BITS> {
+			type Error = TryFromIntError;
+
+			fn try_from(value: $p) -> Result<Self, Self::Error> {
+				$s::try_from(value)
+					.ok()
+					.and_then(Self::new)
+					.ok_or_else(errors::try_from)
+			}
+		}
+
+		impl<const BITS: u32> TryFrom<$t<$s, BITS>> for $p {
+			type Error = TryFromIntError;
+
+			fn try_from(value: $t<$s, BITS>) -> Result<Self, Self::Error> {
+				Self::try_from(value.value).map_err(|_| errors::try_from())
+			}
+		}
+	)+ };
+}
+
+bitint! {
+	UInt<u8> as u8;
+	UInt<u16> as u16;
+	UInt<u32> as u32;
+	UInt<u64> as u64;
+	Int<i8> as u8;
+	Int<i16> as u16;
+	Int<i32> as u32;
+	Int<i64> as u64;
+}
+
+#[cfg(not(feature = "no-128"))]
+bitint! {
+	UInt<u128> as u128;
+	Int<i128> as u128;
+}
+
+macro_rules! unsigned {
+	($($s:ident),+ $(,)?) => { $(
+		impl<const BITS: u32> IsUnsigned for UInt<$s, BITS> {
+			fn is_power_of_two(self) -> bool {
+				self.count_ones() == 1
+			}
+
+			fn next_power_of_two(self) -> Self {
+				debug(
+					self.checked_next_power_of_two()
+						.map_or((Self::ZERO, true), |out| (out, false)),
+					"attempt to add with overflow",
+				)
+			}
+
+			fn checked_next_power_of_two(self) -> Option<Self> {
+				if self <= Self::ONE {
+					return Some(Self::ONE);
+				}
+				let bits = BITS - (self - Self::ONE).leading_zeros();
+				Self::ONE.checked_shl(bits)
+			}
+		}
+	)+ };
+}
+
+unsigned!(u8, u16, u32, u64);
+#[cfg(not(feature = "no-128"))]
+unsigned!(u128);
+
+macro_rules! signed {
+	($($s:ident),+ $(,)?) => { $(
+		impl<const BITS: u32> Neg for Int<$s, BITS> {
+			type Output = Self;
+
+			fn neg(self) -> Self::Output {
+				debug(self.overflowing_neg(), "attempt to negate with overflow")
+			}
+		}
+
+		impl<const BITS: u32> IsSigned for Int<$s, BITS> {
+			fn checked_abs(self) -> Option<Self> {
+				checked(self.overflowing_abs())
+			}
+
+			fn wrapping_abs(self) -> Self {
+				self.overflowing_abs().0
+			}
+
+			fn overflowing_abs(self) -> (Self, bool) {
+				if self.is_neg() {
+					self.overflowing_neg()
+				}
+				else {
+					(self, false)
+				}
+			}
+
+			fn abs(self) -> Self {
+				debug(self.overflowing_abs(), "attempt to negate with overflow")
+			}
+
+			fn signum(self) -> Self {
+				match self.cmp(&Self::ZERO) {
+					Ordering::Less => !Self::ZERO,
+					Ordering::Equal => Self::ZERO,
+					Ordering::Greater => Self::ONE,
+				}
+			}
+
+			fn is_positive(self) -> bool {
+				self > Self::ZERO
+			}
+
+			fn is_negative(self) -> bool {
+				self.is_neg()
+			}
+		}
+	)+ };
+}
+
+signed!(i8, i16, i32, i64);
+#[cfg(not(feature = "no-128"))]
+signed!(i128);
+
+/// Tests if a shift amount is outside a width.
+fn too_far<T: TryInto<u32>>(amount: T, width: u32) -> bool {
+	amount.try_into().map_or(true, |amount| amount >= width)
+}
@@ -0,0 +1,371 @@
+//! Single-bit and bit-field access on every wrapper.
+//!
+//! Each wrapper applies its own policy to a bit index that is not less than the
+//! width of its integer: `Checked` poisons, `Saturating` clamps the index to the
+//! highest bit, `Wrapping` reduces it modulo the width, and `Overflowing` does
+//! the same and sets its flag.
+//!
+//! Bit fields are half-open ranges of bit indices, and hold unsigned values.
+//! A field which extends past the width of the integer poisons `Checked`, and
+//! is cut off at the width by the other wrappers, which `Overflowing` flags. A
+//! value inserted into a field which cannot hold it poisons `Checked`, is
+//! clamped to the field’s range by `Saturating`, and is truncated to the
+//! field’s width by `Wrapping` and `Overflowing`, which again `Overflowing`
+//! flags.
+
+use core::ops::Range;
+
+use funty::IsInteger;
+
+use crate::{
+	Checked,
+	Overflowing,
+	Saturating,
+	Wrapping,
+};
+
+/// Counts the bits in `T`.
+///
+/// This asks the integer rather than its size in memory, so that it is correct
+/// for the integers narrower than their storage.
+pub(crate) fn width<T: IsInteger>() -> u32 {
+	T::ZERO.count_zeros()
+}
+
+/// Produces the integer 1, which `funty` does not provide as a constant.
+pub(crate) fn one<T: IsInteger>() -> T {
+	//  `!0 << 1` clears only the lowest bit, so its inverse is 1.
+	!(!T::ZERO).wrapping_shl(1)
+}
+
+/// Produces the mask with only bit `n` set. `n` must be less than the width.
+fn mask<T: IsInteger>(n: u32) -> T {
+	one::<T>().wrapping_shl(n)
+}
+
+fn set<T: IsInteger>(value: T, n: u32) -> T {
+	value | mask::<T>(n)
+}
+
+fn clear<T: IsInteger>(value: T, n: u32) -> T {
+	value & !mask::<T>(n)
+}
+
+fn toggle<T: IsInteger>(value: T, n: u32) -> T {
+	value ^ mask::<T>(n)
+}
+
+fn test<T: IsInteger>(value: T, n: u32) -> bool {
+	value & mask::<T>(n) != T::ZERO
+}
+
+/// Produces the mask of the lowest `len` bits. `len` must not exceed the
+/// width.
+fn low<T: IsInteger>(len: u32) -> T {
+	if len == width::<T>() {
+		return !T::ZERO;
+	}
+	!(!T::ZERO).wrapping_shl(len)
+}
+
+/// Cuts a field off at the width of `T`, and reports whether this changed it.
+fn clip<T: IsInteger>(field: Range<u32>) -> (Range<u32>, bool) {
+	let end = field.end.min(width::<T>());
+	let start = field.start.min(end);
+	let clipped = start .. end;
+	let changed = clipped != field;
+	(clipped, changed)
+}
+
+/// Tests whether a field lies within the width of `T`.
+fn valid<T: IsInteger>(field: &Range<u32>) -> bool {
+	field.start <= field.end && field.end <= width::<T>()
+}
+
+/// Reads a field which lies within the width of `T`.
+fn extract<T: IsInteger>(value: T, field: Range<u32>) -> T {
+	value.wrapping_shr(field.start) & low::<T>(field.end - field.start)
+}
+
+/// Tests whether `bits` can be held in a field of `len` bits.
+fn fits<T: IsInteger>(bits: T, len: u32) -> bool {
+	bits & !low::<T>(len) == T::ZERO
+}
+
+/// Writes the low bits of `bits` into a field which lies within the width of
+/// `T`.
+fn insert<T: IsInteger>(value: T, field: Range<u32>, bits: T) -> T {
+	let mask = low::<T>(field.end - field.start);
+	let cleared = value & !mask.wrapping_shl(field.start);
+	cleared | (bits & mask).wrapping_shl(field.start)
+}
+
+impl<T: IsInteger> Checked<T> {
+	/// Sets bit `n`. The result is poisoned if `n` is not less than the bit
+	/// width of the integer.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Checked;
+	///
+	/// let reg = Checked::new(0u8).set_bit(7).toggle_bit(0);
+	/// assert_eq!(reg, Some(0x81));
+	/// assert_eq!(reg.bit(7), Some(true));
+	/// assert!(reg.set_bit(8).is_none());
+	/// assert_eq!(reg.bit(8), None);
+	/// ```
+	pub fn set_bit(self, n: u32) -> Self {
+		self.with_bit(n, set)
+	}
+
+	/// Clears bit `n`. The result is poisoned if `n` is not less than the bit
+	/// width of the integer.
+	pub fn clear_bit(self, n: u32) -> Self {
+		self.with_bit(n, clear)
+	}
+
+	/// Inverts bit `n`. The result is poisoned if `n` is not less than the bit
+	/// width of the integer.
+	pub fn toggle_bit(self, n: u32) -> Self {
+		self.with_bit(n, toggle)
+	}
+
+	/// Tests bit `n`, or produces `None` if the value is poisoned or `n` is not
+	/// less than the bit width of the integer.
+	pub fn bit(&self, n: u32) -> Option<bool> {
+		if n >= width::<T>() {
+			return None;
+		}
+		self.value.map(|val| test(val, n))
+	}
+
+	/// Reads a bit field, shifted down to the lowest bits. The result is
+	/// poisoned if the field extends past the width of the integer.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Checked;
+	///
+	/// let reg = Checked::new(0xABCDu16);
+	/// assert_eq!(reg.extract_bits(4 .. 12), Some(0xBC));
+	/// assert!(reg.extract_bits(12 .. 20).is_none());
+	/// ```
+	pub fn extract_bits(self, field: Range<u32>) -> Self {
+		if !valid::<T>(&field) {
+			return Self { value: None };
+		}
+		self.map(|val| extract(val, field))
+	}
+
+	/// Writes `bits` into a bit field. The result is poisoned if the field
+	/// extends past the width of the integer, or if `bits` does not fit in it.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Checked;
+	///
+	/// let reg = Checked::new(0u16);
+	/// assert_eq!(reg.insert_bits(4 .. 8, 0xF), Some(0xF0));
+	/// assert!(reg.insert_bits(4 .. 8, 0x10).is_none());
+	/// ```
+	pub fn insert_bits(self, field: Range<u32>, bits: T) -> Self {
+		if !valid::<T>(&field) || !fits(bits, field.end - field.start) {
+			return Self { value: None };
+		}
+		self.map(|val| insert(val, field, bits))
+	}
+
+	fn with_bit(self, n: u32, func: fn(T, u32) -> T) -> Self {
+		if n >= width::<T>() {
+			return Self { value: None };
+		}
+		self.map(|val| func(val, n))
+	}
+}
+
+impl<T: IsInteger> Overflowing<T> {
+	/// Sets bit `n`, reducing `n` modulo the bit width of the integer and
+	/// setting the overflow flag if this changed it.
+	pub fn set_bit(self, n: u32) -> Self {
+		self.with_bit(n, set)
+	}
+
+	/// Clears bit `n`, reducing `n` modulo the bit width of the integer and
+	/// setting the overflow flag if this changed it.
+	pub fn clear_bit(self, n: u32) -> Self {
+		self.with_bit(n, clear)
+	}
+
+	/// Inverts bit `n`, reducing `n` modulo the bit width of the integer and
+	/// setting the overflow flag if this changed it.
+	pub fn toggle_bit(self, n: u32) -> Self {
+		self.with_bit(n, toggle)
+	}
+
+	/// Tests bit `n`, reducing `n` modulo the bit width of the integer.
+	pub fn bit(&self, n: u32) -> bool {
+		test(self.value, n % width::<T>())
+	}
+
+	/// Reads a bit field, shifted down to the lowest bits. A field which
+	/// extends past the width of the integer is cut off at the width, and sets
+	/// the overflow flag.
+	pub fn extract_bits(self, field: Range<u32>) -> Self {
+		let (field, clipped) = clip::<T>(field);
+		let mut out = self;
+		out.value = extract(self.value, field);
+		out.has_overflowed |= clipped;
+		out
+	}
+
+	/// Writes the low bits of `bits` into a bit field. A field which extends
+	/// past the width of the integer is cut off at the width. The overflow flag
+	/// is set if either the field or `bits` was cut off.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Overflowing;
+	///
+	/// let reg = Overflowing::new(0u8).insert_bits(4 .. 8, 0x1F);
+	/// assert_eq!(reg, 0xF0);
+	/// assert!(reg.has_overflowed());
+	/// ```
+	pub fn insert_bits(self, field: Range<u32>, bits: T) -> Self {
+		let (field, clipped) = clip::<T>(field);
+		let truncated = !fits(bits, field.end - field.start);
+		let mut out = self;
+		out.value = insert(self.value, field, bits);
+		out.has_overflowed |= clipped | truncated;
+		out
+	}
+
+	fn with_bit(self, n: u32, func: fn(T, u32) -> T) -> Self {
+		let mut out = self;
+		out.value = func(self.value, n % width::<T>());
+		out.has_overflowed |= n >= width::<T>();
+		out
+	}
+}
+
+impl<T: IsInteger> Saturating<T> {
+	/// Sets bit `n`, clamping `n` to the highest bit of the integer.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Saturating;
+	///
+	/// assert_eq!(Saturating::new(0u8).set_bit(20), 0x80);
+	/// ```
+	pub fn set_bit(self, n: u32) -> Self {
+		set(self.value, Self::clamp(n)).into()
+	}
+
+	/// Clears bit `n`, clamping `n` to the highest bit of the integer.
+	pub fn clear_bit(self, n: u32) -> Self {
+		clear(self.value, Self::clamp(n)).into()
+	}
+
+	/// Inverts bit `n`, clamping `n` to the highest bit of the integer.
+	pub fn toggle_bit(self, n: u32) -> Self {
+		toggle(self.value, Self::clamp(n)).into()
+	}
+
+	/// Tests bit `n`, clamping `n` to the highest bit of the integer.
+	pub fn bit(&self, n: u32) -> bool {
+		test(self.value, Self::clamp(n))
+	}
+
+	/// Reads a bit field, shifted down to the lowest bits. A field which
+	/// extends past the width of the integer is cut off at the width.
+	pub fn extract_bits(self, field: Range<u32>) -> Self {
+		extract(self.value, clip::<T>(field).0).into()
+	}
+
+	/// Writes `bits` into a bit field, clamping it to the range the field can
+	/// hold. A field which extends past the width of the integer is cut off at
+	/// the width.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Saturating;
+	///
+	/// let reg = Saturating::new(0u16);
+	/// assert_eq!(reg.insert_bits(4 .. 8, 0x30), 0xF0);
+	/// assert_eq!(Saturating::new(0xFFi16).insert_bits(0 .. 4, -3), 0xF0);
+	/// ```
+	pub fn insert_bits(self, field: Range<u32>, bits: T) -> Self {
+		let (field, _) = clip::<T>(field);
+		let max = low::<T>(field.end - field.start);
+		let bits = if bits < T::ZERO {
+			T::ZERO
+		}
+		else if !fits(bits, field.end - field.start) {
+			max
+		}
+		else {
+			bits
+		};
+		insert(self.value, field, bits).into()
+	}
+
+	fn clamp(n: u32) -> u32 {
+		n.min(width::<T>() - 1)
+	}
+}
+
+impl<T: IsInteger> Wrapping<T> {
+	/// Sets bit `n`, reducing `n` modulo the bit width of the integer.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Wrapping;
+	///
+	/// assert_eq!(Wrapping::new(0u8).set_bit(9), 0x02);
+	/// ```
+	pub fn set_bit(self, n: u32) -> Self {
+		set(self.value, n % width::<T>()).into()
+	}
+
+	/// Clears bit `n`, reducing `n` modulo the bit width of the integer.
+	pub fn clear_bit(self, n: u32) -> Self {
+		clear(self.value, n % width::<T>()).into()
+	}
+
+	/// Inverts bit `n`, reducing `n` modulo the bit width of the integer.
+	pub fn toggle_bit(self, n: u32) -> Self {
+		toggle(self.value, n % width::<T>()).into()
+	}
+
+	/// Tests bit `n`, reducing `n` modulo the bit width of the integer.
+	pub fn bit(&self, n: u32) -> bool {
+		test(self.value, n % width::<T>())
+	}
+
+	/// Reads a bit field, shifted down to the lowest bits. A field which
+	/// extends past the width of the integer is cut off at the width.
+	pub fn extract_bits(self, field: Range<u32>) -> Self {
+		extract(self.value, clip::<T>(field).0).into()
+	}
+
+	/// Writes the low bits of `bits` into a bit field, discarding the bits that
+	/// do not fit. A field which extends past the width of the integer is cut
+	/// off at the width.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Wrapping;
+	///
+	/// assert_eq!(Wrapping::new(0u16).insert_bits(4 .. 8, 0x3A), 0xA0);
+	/// ```
+	pub fn insert_bits(self, field: Range<u32>, bits: T) -> Self {
+		insert(self.value, clip::<T>(field).0, bits).into()
+	}
+}
@@ -0,0 +1,414 @@
+use core::convert::TryFrom;
+
+use funty::IsInteger;
+
+use crate::{
+	Checked,
+	Saturating,
+	Wrapping,
+};
+
+/** Converts between the fundamental integers under each overflow policy.
+
+This trait is implemented between every pair of fundamental integers, and from
+`f64` into every fundamental integer, and cannot be implemented outside this
+crate. It is the bound on the target type of the [`CheckedCast`],
+[`SaturatingCast`], and [`WrappingCast`] conversions, and of the `from_f64`
+constructors.
+
+[`CheckedCast`]: crate::CheckedCast
+[`SaturatingCast`]: crate::SaturatingCast
+[`WrappingCast`]: crate::WrappingCast
+**/
+pub trait CastFrom<T>: IsInteger + sealed::Sealed {
+	/// Converts `value`, producing `None` if it is out of range.
+	#[doc(hidden)]
+	fn checked_from(value: T) -> Option<Self>;
+
+	/// Converts `value`, clamping it to the range of `Self`.
+	#[doc(hidden)]
+	fn saturating_from(value: T) -> Self;
+
+	/// Converts `value`, keeping only the bits that fit in `Self`.
+	#[doc(hidden)]
+	fn wrapping_from(value: T) -> Self;
+}
+
+/** Converts an integer into another width or signedness, poisoning the result
+if the value does not fit.
+
+# Examples
+
+```rust
+use surety::CheckedCast as _;
+
+assert_eq!(300i32.checked_cast::<u16>(), Some(300));
+assert!(300i32.checked_cast::<u8>().is_none());
+assert!((-1i8).checked_cast::<u64>().is_none());
+```
+**/
+pub trait CheckedCast: IsInteger {
+	/// Converts into `U`, poisoning the result if the value is out of range.
+	fn checked_cast<U: CastFrom<Self>>(self) -> Checked<U>;
+}
+
+/** Converts an integer into another width or signedness, clamping the value to
+the range of the target.
+
+# Examples
+
+```rust
+use surety::SaturatingCast as _;
+
+assert_eq!(300i32.saturating_cast::<u8>(), 255);
+assert_eq!((-300i32).saturating_cast::<i8>(), -128);
+assert_eq!((-1i8).saturating_cast::<u64>(), 0);
+```
+**/
+pub trait SaturatingCast: IsInteger {
+	/// Converts into `U`, clamping the value to `U::MIN ..= U::MAX`.
+	fn saturating_cast<U: CastFrom<Self>>(self) -> Saturating<U>;
+}
+
+/** Converts an integer into another width or signedness, keeping only the bits
+that fit in the target.
+
+This is the behavior of the `as` operator, made explicit.
+
+# Examples
+
+```rust
+use surety::WrappingCast as _;
+
+assert_eq!(300i32.wrapping_cast::<u8>(), 44);
+assert_eq!(40_000u16.wrapping_cast::<i16>(), -25_536);
+```
+**/
+pub trait WrappingCast: IsInteger {
+	/// Converts into `U`, truncating or reinterpreting the value as `as` does.
+	fn wrapping_cast<U: CastFrom<Self>>(self) -> Wrapping<U>;
+}
+
+/** Converts an integer into a float only if the float holds it exactly.
+
+The `as` operator rounds an integer which has more significant bits than the
+float’s mantissa, so that, for example, large `u64` identifiers silently change
+value on their way through an `f64`. These conversions produce `None` instead.
+
+# Examples
+
+```rust
+use surety::ToFloat as _;
+
+assert_eq!((1u64 << 53).to_f64_lossless(), Some(9007199254740992.0));
+assert!(((1u64 << 53) + 1).to_f64_lossless().is_none());
+assert_eq!(16_777_216i32.to_f32_lossless(), Some(16_777_216.0));
+assert!(16_777_217i32.to_f32_lossless().is_none());
+# #[cfg(not(feature = "no-128"))]
+assert!(u128::MAX.to_f32_lossless().is_none());
+```
+**/
+pub trait ToFloat: CastFrom<f64> {
+	/// Converts into `f32`, producing `None` if the value would be rounded.
+	fn to_f32_lossless(self) -> Option<f32>;
+
+	/// Converts into `f64`, producing `None` if the value would be rounded.
+	fn to_f64_lossless(self) -> Option<f64>;
+}
+
+impl<T: IsInteger> CheckedCast for T {
+	fn checked_cast<U: CastFrom<Self>>(self) -> Checked<U> {
+		U::checked_from(self).into()
+	}
+}
+
+impl<T: IsInteger> SaturatingCast for T {
+	fn saturating_cast<U: CastFrom<Self>>(self) -> Saturating<U> {
+		U::saturating_from(self).into()
+	}
+}
+
+impl<T: IsInteger> WrappingCast for T {
+	fn wrapping_cast<U: CastFrom<Self>>(self) -> Wrapping<U> {
+		U::wrapping_from(self).into()
+	}
+}
+
+impl<T: IsInteger> Checked<T> {
+	/// Converts into another integer, poisoning the result if the value is out
+	/// of range. A poisoned value remains poisoned.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Checked;
+	///
+	/// assert_eq!(Checked::new(200u32).cast::<u8>(), Some(200));
+	/// assert!(Checked::new(-1i32).cast::<u32>().is_none());
+	/// ```
+	pub fn cast<U: CastFrom<T>>(self) -> Checked<U> {
+		self.value.and_then(U::checked_from).into()
+	}
+}
+
+impl<T: CastFrom<f64>> Checked<T> {
+	/// Converts a float, discarding its fractional part. The result is poisoned
+	/// if the float is NaN, or if its integral part is out of range.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Checked;
+	///
+	/// assert_eq!(Checked::<i64>::from_f64(-2.9), Some(-2));
+	/// assert!(Checked::<u8>::from_f64(256.0).is_none());
+	/// assert!(Checked::<i32>::from_f64(f64::NAN).is_none());
+	/// ```
+	pub fn from_f64(value: f64) -> Self {
+		T::checked_from(value).into()
+	}
+}
+
+impl<T: CastFrom<f64>> Saturating<T> {
+	/// Converts a float, discarding its fractional part and clamping it to the
+	/// range of the integer. NaN converts to 0.
+	///
+	/// This is the behavior of the `as` operator, made explicit.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Saturating;
+	///
+	/// assert_eq!(Saturating::<i32>::from_f64(1e20), i32::MAX);
+	/// assert_eq!(Saturating::<u8>::from_f64(-3.5), 0);
+	/// ```
+	pub fn from_f64(value: f64) -> Self {
+		T::saturating_from(value).into()
+	}
+}
+
+impl<T: CastFrom<f64>> Wrapping<T> {
+	/// Converts a float, discarding its fractional part and wrapping it into
+	/// the range of the integer.
+	///
+	/// The integral part is reduced modulo 2<sup>N</sup>, where N is the bit
+	/// width of the integer, so only its low N bits are kept. NaN and the
+	/// infinities convert to 0. This differs from the `as` operator, which
+	/// saturates.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Wrapping;
+	///
+	/// assert_eq!(Wrapping::<u8>::from_f64(300.7), 44);
+	/// assert_eq!(Wrapping::<i8>::from_f64(-129.0), 127);
+	/// assert_eq!(Wrapping::<u32>::from_f64(1e300), 0);
+	/// ```
+	pub fn from_f64(value: f64) -> Self {
+		T::wrapping_from(value).into()
+	}
+}
+
+impl<T: ToFloat> Checked<T> {
+	/// Converts into `f32` if the float holds the value exactly. A poisoned
+	/// value, or one which would be rounded, produces `None`.
+	pub fn to_f32_lossless(self) -> Option<f32> {
+		self.value.and_then(T::to_f32_lossless)
+	}
+
+	/// Converts into `f64` if the float holds the value exactly. A poisoned
+	/// value, or one which would be rounded, produces `None`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Checked;
+	///
+	/// assert_eq!(Checked::new(-3i64).to_f64_lossless(), Some(-3.0));
+	/// assert!(Checked::new(u64::MAX).to_f64_lossless().is_none());
+	/// ```
+	pub fn to_f64_lossless(self) -> Option<f64> {
+		self.value.and_then(T::to_f64_lossless)
+	}
+}
+
+impl<T: ToFloat> Saturating<T> {
+	/// Converts into `f32`, producing `None` if the value would be rounded.
+	pub fn to_f32_lossless(self) -> Option<f32> {
+		self.value.to_f32_lossless()
+	}
+
+	/// Converts into `f64`, producing `None` if the value would be rounded.
+	pub fn to_f64_lossless(self) -> Option<f64> {
+		self.value.to_f64_lossless()
+	}
+}
+
+impl<T: ToFloat> Wrapping<T> {
+	/// Converts into `f32`, producing `None` if the value would be rounded.
+	pub fn to_f32_lossless(self) -> Option<f32> {
+		self.value.to_f32_lossless()
+	}
+
+	/// Converts into `f64`, producing `None` if the value would be rounded.
+	pub fn to_f64_lossless(self) -> Option<f64> {
+		self.value.to_f64_lossless()
+	}
+}
+
+impl<T: IsInteger> Saturating<T> {
+	/// Converts into another integer, clamping the value to its range.
+	pub fn cast<U: CastFrom<T>>(self) -> Saturating<U> {
+		self.value.saturating_cast()
+	}
+}
+
+impl<T: IsInteger> Wrapping<T> {
+	/// Converts into another integer, keeping only the bits that fit in it.
+	pub fn cast<U: CastFrom<T>>(self) -> Wrapping<U> {
+		self.value.wrapping_cast()
+	}
+}
+
+macro_rules! cast {
+	($($from:ty),+ $(,)?) => { $(
+		cast!(@ $from => i8 i16 i32 i64 isize u8 u16 u32 u64 usize);
+		#[cfg(not(feature = "no-128"))]
+		cast!(@ $from => i128 u128);
+	)+ };
+
+	(@ $from:ty => $($to:ty)+) => { $(
+		impl CastFrom<$from> for $to {
+			fn checked_from(value: $from) -> Option<Self> {
+				Self::try_from(value).ok()
+			}
+
+			fn saturating_from(value: $from) -> Self {
+				match Self::try_from(value) {
+					Ok(value) => value,
+					Err(_) if value < <$from>::default() => Self::MIN,
+					Err(_) => Self::MAX,
+				}
+			}
+
+			fn wrapping_from(value: $from) -> Self {
+				value as Self
+			}
+		}
+	)+ };
+}
+
+cast!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+#[cfg(not(feature = "no-128"))]
+cast!(i128, u128);
+
+macro_rules! float {
+	($($to:ty),+ $(,)?) => { $(
+		impl CastFrom<f64> for $to {
+			fn checked_from(value: f64) -> Option<Self> {
+				let min = Self::MIN as f64;
+				//  `MAX + 1` is a power of two, and is exact even where `MAX`
+				//  itself rounds up to it.
+				let max = Self::MAX as f64 + 1.0;
+				//  `MIN - 1` rounds back to `MIN` in the wide integers, whose
+				//  minimum is then the only in-range value at that boundary.
+				let above = value > min - 1.0 || value == min;
+				if above && value < max {
+					Some(value as Self)
+				}
+				else {
+					None
+				}
+			}
+
+			fn saturating_from(value: f64) -> Self {
+				value as Self
+			}
+
+			fn wrapping_from(value: f64) -> Self {
+				low_bits(value) as Self
+			}
+		}
+	)+ };
+}
+
+float!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+#[cfg(not(feature = "no-128"))]
+float!(i128, u128);
+
+macro_rules! to_float {
+	($($from:ty),+ $(,)?) => { $(
+		impl ToFloat for $from {
+			fn to_f32_lossless(self) -> Option<f32> {
+				let out = self as f32;
+				//  `f32` to `f64` is exact, and the checked conversion back
+				//  rejects a value which rounded up past `MAX`.
+				if Self::checked_from(out as f64) == Some(self) {
+					Some(out)
+				}
+				else {
+					None
+				}
+			}
+
+			fn to_f64_lossless(self) -> Option<f64> {
+				let out = self as f64;
+				if Self::checked_from(out) == Some(self) {
+					Some(out)
+				}
+				else {
+					None
+				}
+			}
+		}
+	)+ };
+}
+
+to_float!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+#[cfg(not(feature = "no-128"))]
+to_float!(i128, u128);
+
+/// Computes the integral part of a float modulo 2<sup>128</sup>.
+fn low_bits(value: f64) -> u128 {
+	if !value.is_finite() {
+		return 0;
+	}
+	let bits = value.to_bits();
+	let exp = ((bits >> 52) & 0x7FF) as i32;
+	//  Zero and the subnormals have no integral part.
+	if exp == 0 {
+		return 0;
+	}
+	let mantissa = (bits & ((1 << 52) - 1)) | (1 << 52);
+	//  The float is `mantissa * 2^shift`.
+	let shift = exp - 1075;
+	let magnitude = match shift {
+		s if s <= -53 => 0,
+		s if s < 0 => (mantissa >> -s) as u128,
+		s if s < 128 => (mantissa as u128) << s,
+		_ => 0,
+	};
+	if value < 0.0 {
+		magnitude.wrapping_neg()
+	}
+	else {
+		magnitude
+	}
+}
+
+mod sealed {
+	pub trait Sealed {}
+
+	macro_rules! sealed {
+		($($t:ty),+) => { $(
+			impl Sealed for $t {
+			}
+		)+ };
+	}
+
+	sealed!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+	#[cfg(not(feature = "no-128"))]
+	sealed!(i128, u128);
+}
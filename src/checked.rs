@@ -1,6 +1,11 @@
 use core::{
 	cmp::Ordering,
-	convert::TryInto as _,
+	fmt::{
+		self,
+		Display,
+		Formatter,
+	},
+	iter::Sum,
 	ops::{
 		Add,
 		AddAssign,
@@ -9,6 +14,7 @@ use core::{
 		Mul,
 		MulAssign,
 		Neg,
+		RangeInclusive,
 		Rem,
 		RemAssign,
 		Shl,
@@ -29,6 +35,17 @@ use funty::{
 	IsSigned,
 };
 
+use crate::{
+	bits,
+	shift,
+	ArithmeticError,
+	Overflowing,
+	Saturating,
+	ShiftAmount,
+	ToUnsigned,
+	Wrapping,
+};
+
 /** Marks an integer for checked-overflow arithmetic.
 
 This type encloses a Rust integer, and causes all arithmetic operations done on
@@ -40,9 +57,33 @@ after executing the arithmetic instruction, so the processor may observe an
 overflow event.
 
 Once a `Checked<_>` integer enters the overflow state, it will no longer execute
-arithmetic instructions until it is reset to a valid value.
+arithmetic instructions until it is reset to a valid value. A negative shift
+amount shifts in the opposite direction, and a shift whose magnitude is not less
+than the bit width of the integer also enters the overflow state.
 
 This type provides an `Option`-like API in addition to its integer properties.
+
+# Ordering
+
+As with `Option`, the `Ord` implementation of `Checked` places a poisoned value
+before every integer, so that overflowed results sort as the minimum. Use
+[`Checked::cmp_overflow_last`], or the [`OverflowLast`] adapter, to sort them
+after every integer instead.
+
+# Performance
+
+Each operator tests its operands for poison and its result for overflow, so a
+chain like `a + b + c + d` may branch at every step. When a chain needs to be
+straight-line code, use [`Overflowing`] instead, whose operators only combine
+their overflow flags, and convert the result with [`Overflowing::to_checked`].
+To add many integers at once, use [`Checked::add_slice`], which tests for
+overflow once per 16-element chunk.
+
+[`Checked::add_slice`]: crate::Checked::add_slice
+[`Overflowing`]: crate::Overflowing
+[`Overflowing::to_checked`]: crate::Overflowing::to_checked
+[`Checked::cmp_overflow_last`]: crate::Checked::cmp_overflow_last
+[`OverflowLast`]: crate::OverflowLast
 **/
 #[repr(transparent)]
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
@@ -52,10 +93,85 @@ pub struct Checked<T: IsInteger> {
 	/// This is `Some` while the value has not yet overflowed an arithmetic
 	/// operation. Once an overflow occurs, this is set to `None` until
 	/// explicitly reset to a fresh value.
-	pub value: Option<T>,
+	pub(crate) value: Option<T>,
 }
 
 impl<T: IsInteger> Checked<T> {
+	/// Wraps a valid integer for checked arithmetic.
+	pub const fn new(value: T) -> Self {
+		Self { value: Some(value) }
+	}
+
+	/// Gets the contained integer, if it has not overflowed.
+	pub const fn value(&self) -> Option<T> {
+		self.value
+	}
+
+	/// Unwraps the contained integer, if it has not overflowed.
+	pub const fn into_inner(self) -> Option<T> {
+		self.value
+	}
+
+	/// Borrows the contained integer, which is `None` if it has overflowed.
+	pub const fn as_option(&self) -> &Option<T> {
+		&self.value
+	}
+
+	/// Replaces the contained integer. `None` poisons the value.
+	pub fn set(&mut self, value: Option<T>) {
+		self.value = value;
+	}
+
+	/// Returns the value unchanged. This exists so that generic code can call
+	/// `to_checked` on any of the wrappers.
+	pub const fn to_checked(self) -> Self {
+		self
+	}
+
+	/// Switches to wrapping arithmetic, if the integer has not overflowed.
+	///
+	/// A poisoned `Checked` has no integer to carry over, and so produces
+	/// `None`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Checked;
+	///
+	/// let num = Checked::new(200u8);
+	/// assert_eq!(num.to_wrapping().unwrap() + 100, 44);
+	/// assert!((num + 100).to_wrapping().is_none());
+	/// ```
+	pub const fn to_wrapping(self) -> Option<Wrapping<T>> {
+		match self.value {
+			Some(value) => Some(Wrapping { value }),
+			None => None,
+		}
+	}
+
+	/// Switches to saturating arithmetic, if the integer has not overflowed.
+	///
+	/// A poisoned `Checked` has no integer to carry over, and so produces
+	/// `None`.
+	pub const fn to_saturating(self) -> Option<Saturating<T>> {
+		match self.value {
+			Some(value) => Some(Saturating { value }),
+			None => None,
+		}
+	}
+
+	/// Switches to overflow-detecting arithmetic, with the overflow flag
+	/// cleared, if the integer has not overflowed.
+	///
+	/// A poisoned `Checked` has no integer to carry over, and so produces
+	/// `None`.
+	pub const fn to_overflowing(self) -> Option<Overflowing<T>> {
+		match self.value {
+			Some(value) => Some(Overflowing::new(value)),
+			None => None,
+		}
+	}
+
 	/// Checked Euclidean division. Computes `self.value?.div_euclid(rhs)`,
 	/// returning `None` if `rhs == 0` or the division results in overflow.
 	pub fn div_euclid(self, rhs: Self) -> Self {
@@ -85,6 +201,139 @@ impl<T: IsInteger> Checked<T> {
 		self.and_then(|val| val.checked_pow(exp))
 	}
 
+	/// Shifts left, producing zero instead of poisoning when `rhs` is not less
+	/// than the bit width of the integer.
+	///
+	/// This matches the behavior of many instruction sets, where every bit is
+	/// shifted out of the register. The `<<` operator poisons instead.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Checked;
+	///
+	/// let num = Checked::new(1u8);
+	/// assert_eq!(num.shl_or_zero(7), Some(128));
+	/// assert_eq!(num.shl_or_zero(8), Some(0));
+	/// assert!((num << 8u32).is_none());
+	/// ```
+	pub fn shl_or_zero(self, rhs: u32) -> Self {
+		self.map(|val| val.checked_shl(rhs).unwrap_or(T::ZERO))
+	}
+
+	/// Shifts right, filling with the sign bit instead of poisoning when `rhs`
+	/// is not less than the bit width of the integer.
+	///
+	/// An over-shifted unsigned integer, or non-negative signed integer, is
+	/// zero, and an over-shifted negative integer is `-1`. The `>>` operator
+	/// poisons instead.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Checked;
+	///
+	/// assert_eq!(Checked::new(200u8).shr_or_zero(8), Some(0));
+	/// assert_eq!(Checked::new(-100i8).shr_or_zero(8), Some(-1));
+	/// assert_eq!(Checked::new(100i8).shr_or_zero(99), Some(0));
+	/// ```
+	pub fn shr_or_zero(self, rhs: u32) -> Self {
+		let last = bits::width::<T>() - 1;
+		//  Shifting by one less than the width fills every bit with the sign
+		//  of a signed integer, and leaves only the high bit of an unsigned
+		//  integer, which the final shift then clears.
+		self.map(|val| {
+			val.checked_shr(rhs)
+				.unwrap_or_else(|| val.wrapping_shr(last).wrapping_shr(1))
+		})
+	}
+
+	/// Adds every integer in a sequence, stopping at the first overflow.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Checked;
+	///
+	/// assert_eq!(Checked::new(1u8).add_all(1 ..= 10), Some(56));
+	/// assert!(Checked::new(1u8).add_all(1 ..= 100).is_none());
+	/// ```
+	pub fn add_all(self, iter: impl IntoIterator<Item = T>) -> Self {
+		let mut iter = iter.into_iter();
+		self.value
+			.and_then(|acc| iter.try_fold(acc, T::checked_add))
+			.into()
+	}
+
+	/// Adds every integer in a slice.
+	///
+	/// This checks for overflow once per 16-element chunk of the slice, rather
+	/// than after each addition.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Checked;
+	///
+	/// let data = [100i8, 20, -50, 7];
+	/// assert_eq!(Checked::new(0).add_slice(&data), Some(77));
+	/// assert!(Checked::new(10).add_slice(&data).is_none());
+	/// ```
+	pub fn add_slice(self, addends: &[T]) -> Self {
+		let mut acc = match self.value {
+			Some(value) => value,
+			None => return self,
+		};
+		for chunk in addends.chunks(16) {
+			//  An overflowing addition sets its flag at exactly the step where
+			//  a checked addition would fail, so the chunk overflows if any of
+			//  its additions did.
+			let mut overflowed = false;
+			for &addend in chunk {
+				let (sum, ovf) = acc.overflowing_add(addend);
+				acc = sum;
+				overflowed |= ovf;
+			}
+			if overflowed {
+				return Self { value: None };
+			}
+		}
+		acc.into()
+	}
+
+	/// Replaces the value in place with the result of a function applied to
+	/// it.
+	///
+	/// This allows values stored in fields or behind references to be modified
+	/// without moving them out into a temporary.
+	pub fn update(&mut self, func: impl FnOnce(Self) -> Self) {
+		*self = func(*self);
+	}
+
+	/// Replaces the value in place with the result of a function applied to
+	/// it, only if the result has not overflowed.
+	///
+	/// If the function produces an overflowed result, the original value is
+	/// kept. Returns whether the new value was stored.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Ensure as _;
+	///
+	/// let mut num = 250u8.checked();
+	/// assert!(num.try_update(|n| n + 5));
+	/// assert!(!num.try_update(|n| n + 1));
+	/// assert_eq!(num, Some(255));
+	/// ```
+	pub fn try_update(&mut self, func: impl FnOnce(Self) -> Self) -> bool {
+		let next = func(*self);
+		if next.is_some() {
+			*self = next;
+		}
+		next.is_some()
+	}
+
 	/// Tests if the integer is still valid, and has not yet overflowed.
 	///
 	/// # Original
@@ -139,6 +388,20 @@ impl<T: IsInteger> Checked<T> {
 		self.value.unwrap()
 	}
 
+	/// Unwraps the bare integer value, without checking that it is present.
+	///
+	/// # Safety
+	///
+	/// The value must not have overflowed. Calling this on a poisoned
+	/// `Checked` is undefined behavior.
+	///
+	/// # Original
+	///
+	/// [`Option::unwrap_unchecked`](https://doc.rust-lang.org/core/option/enum.Option.html#method.unwrap_unchecked)
+	pub unsafe fn unwrap_unchecked(self) -> T {
+		self.value.unwrap_unchecked()
+	}
+
 	/// Unwraps the bare integer value, substituting a default value if absent.
 	///
 	/// # Original
@@ -157,6 +420,24 @@ impl<T: IsInteger> Checked<T> {
 		self.value.unwrap_or_else(func)
 	}
 
+	/// Unwraps the bare integer value, substituting zero if absent.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Ensure;
+	///
+	/// let total = [200u8, 50, 10].iter().fold(0u8.checked(), |acc, n| acc + n);
+	/// assert_eq!(total.unwrap_or_default(), 0);
+	/// ```
+	///
+	/// # Original
+	///
+	/// [`Option::unwrap_or_default`](https://doc.rust-lang.org/core/option/enum.Option.html#method.unwrap_or_default)
+	pub fn unwrap_or_default(self) -> T {
+		self.value.unwrap_or(T::ZERO)
+	}
+
 	/// Transforms the integer value to a new integer, if present.
 	///
 	/// # Original
@@ -200,6 +481,57 @@ impl<T: IsInteger> Checked<T> {
 		self.value.map_or_else(default, func).into()
 	}
 
+	/// Applies a function to the contained integer, substituting zero if
+	/// absent.
+	///
+	/// The returned `Checked` is always in a valid state.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Ensure;
+	///
+	/// let halve = |n: u8| (n.checked() * 2).map_or_default(|n| n / 4);
+	/// assert_eq!(halve(100), Some(50));
+	/// assert_eq!(halve(200), Some(0));
+	/// ```
+	///
+	/// # Original
+	///
+	/// [`Option::map_or_default`](https://doc.rust-lang.org/core/option/enum.Option.html#method.map_or_default)
+	pub fn map_or_default<U: IsInteger>(
+		self,
+		func: impl FnOnce(T) -> U,
+	) -> Checked<U>
+	{
+		self.value.map_or(U::ZERO, func).into()
+	}
+
+	/// Applies a fallible function to the contained integer, if present.
+	///
+	/// An error from the function is returned as `Err`. A poisoned value does
+	/// not call the function, and stays poisoned inside `Ok`, so that overflow
+	/// and the function’s own failure remain distinguishable.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use core::convert::TryFrom;
+	/// use surety::Ensure;
+	///
+	/// let narrow = |n: u16| (200u16.checked() + n).try_map(u8::try_from);
+	/// assert_eq!(narrow(50).unwrap(), Some(250));
+	/// assert!(narrow(100).is_err());
+	/// assert!(narrow(u16::MAX).unwrap().is_none());
+	/// ```
+	pub fn try_map<U: IsInteger, E>(
+		self,
+		func: impl FnOnce(T) -> Result<U, E>,
+	) -> Result<Checked<U>, E>
+	{
+		self.value.map(func).transpose().map(Checked::from)
+	}
+
 	/// Transforms the `Checked<T>` into a `Result<T, E>`, producing `Ok(num)`
 	/// if the integer is present and `Err(err)` if it is not.
 	///
@@ -220,12 +552,32 @@ impl<T: IsInteger> Checked<T> {
 		self.value.ok_or_else(func)
 	}
 
+	/// Transforms the `Checked<T>` into a `Result<T, ArithmeticError>`,
+	/// producing `Ok(num)` if the integer is present and `Err(ArithmeticError)`
+	/// if it is not.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::{ArithmeticError, Checked};
+	///
+	/// fn total(prices: &[u32]) -> surety::Result<u32> {
+	///     Checked::new(0).add_slice(prices).ok_or_overflow()
+	/// }
+	///
+	/// assert_eq!(total(&[3, 4]), Ok(7));
+	/// assert_eq!(total(&[u32::MAX, 1]), Err(ArithmeticError));
+	/// ```
+	pub fn ok_or_overflow(self) -> Result<T, ArithmeticError> {
+		self.value.ok_or(ArithmeticError)
+	}
+
 	/// Returns an iterator over the possibly-contained integer.
 	///
 	/// # Original
 	///
 	/// [`Option::iter`](https://doc.rust-lang.org/core/option/enum.Option.html#method.iter)
-	pub fn iter(&self) -> Iter<T> {
+	pub fn iter(&self) -> Iter<'_, T> {
 		self.value.iter()
 	}
 
@@ -234,7 +586,7 @@ impl<T: IsInteger> Checked<T> {
 	/// # Original
 	///
 	/// [`Option::iter_mut`](https://doc.rust-lang.org/core/option/enum.Option.html#method.iter_mut)
-	pub fn iter_mut(&mut self) -> IterMut<T> {
+	pub fn iter_mut(&mut self) -> IterMut<'_, T> {
 		self.value.iter_mut()
 	}
 
@@ -274,6 +626,21 @@ impl<T: IsInteger> Checked<T> {
 		self.value.filter(func).into()
 	}
 
+	/// Poisons the integer if it is outside an inclusive range.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Checked;
+	///
+	/// let port = Checked::new(8080u32);
+	/// assert_eq!(port.filter_range(1024 ..= 49151), Some(8080));
+	/// assert!(port.filter_range(1 ..= 1023).is_none());
+	/// ```
+	pub fn filter_range(self, range: RangeInclusive<T>) -> Self {
+		self.filter(|val| range.contains(val))
+	}
+
 	/// If the integer is missing, replaces it with a new checked integer.
 	///
 	/// # Original
@@ -362,6 +729,158 @@ impl<T: IsInteger> Checked<T> {
 	pub fn replace_value(&mut self, other: T) -> Option<T> {
 		self.value.replace(other)
 	}
+
+	/// Compares two values, ordering a poisoned value after every integer.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Checked;
+	///
+	/// let mut values = [Checked::new(3u8), Checked::new(200) * 2, Checked::new(1)];
+	/// values.sort_by(Checked::cmp_overflow_last);
+	/// assert_eq!(values, [Some(1), Some(3), None]);
+	/// ```
+	pub fn cmp_overflow_last(&self, other: &Self) -> Ordering {
+		match (self.value, other.value) {
+			(Some(this), Some(that)) => this.cmp(&that),
+			(Some(_), None) => Ordering::Less,
+			(None, Some(_)) => Ordering::Greater,
+			(None, None) => Ordering::Equal,
+		}
+	}
+
+	/// Prints the integer, or `placeholder` if the value is poisoned.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Checked;
+	///
+	/// let total = Checked::new(i32::MAX) + 1;
+	/// assert_eq!(format!("{:>5}", total.display_or("n/a")), "  n/a");
+	/// ```
+	pub fn display_or(self, placeholder: &str) -> DisplayOr<'_, T> {
+		DisplayOr {
+			value: self,
+			placeholder,
+		}
+	}
+}
+
+impl<T: ToUnsigned> Checked<T> {
+	/// Computes how much can be added to the value before it overflows, or
+	/// `None` if it is poisoned.
+	///
+	/// This is reported in the unsigned integer of the same width, which can
+	/// hold the full range of a signed integer.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Checked;
+	///
+	/// let used = Checked::new(-100i8);
+	/// assert_eq!(used.headroom(), Some(227u8));
+	/// assert_eq!(used.room_below(), Some(28));
+	/// assert_eq!((used - 100).headroom(), None);
+	/// ```
+	pub fn headroom(&self) -> Option<T::Unsigned> {
+		self.value.map(|val| T::MAX.distance_above(val))
+	}
+
+	/// Computes how much can be subtracted from the value before it overflows,
+	/// or `None` if it is poisoned.
+	pub fn room_below(&self) -> Option<T::Unsigned> {
+		self.value.map(|val| val.distance_above(T::MIN))
+	}
+}
+
+/** Orders `Checked` values with poisoned values after every integer.
+
+This adapter implements `Ord` through [`Checked::cmp_overflow_last`], so that
+sorts, heaps, and ordered maps of checked results rank overflows as the maximum
+rather than the minimum.
+
+# Examples
+
+```rust
+use surety::{Checked, OverflowLast};
+
+let values = [Checked::new(3u8), Checked::new(200) * 2, Checked::new(1)];
+let max = values.iter().copied().map(OverflowLast).max().unwrap();
+assert!(max.0.is_none());
+```
+
+[`Checked::cmp_overflow_last`]: crate::Checked::cmp_overflow_last
+**/
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct OverflowLast<T: IsInteger>(pub Checked<T>);
+
+impl<T: IsInteger> PartialOrd for OverflowLast<T> {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl<T: IsInteger> Ord for OverflowLast<T> {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.0.cmp_overflow_last(&other.0)
+	}
+}
+
+impl<T: IsInteger> From<Checked<T>> for OverflowLast<T> {
+	fn from(value: Checked<T>) -> Self {
+		Self(value)
+	}
+}
+
+/** Prints a `Checked` value with a chosen placeholder for the poisoned state.
+
+This is produced by [`Checked::display_or`].
+
+[`Checked::display_or`]: crate::Checked::display_or
+**/
+#[derive(Clone, Copy, Debug)]
+pub struct DisplayOr<'a, T: IsInteger> {
+	/// The value to print.
+	value: Checked<T>,
+	/// The text printed in place of a poisoned value.
+	placeholder: &'a str,
+}
+
+impl<T: IsInteger> Display for DisplayOr<'_, T> {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		match self.value.value {
+			Some(ref value) => Display::fmt(value, fmt),
+			None => fmt.pad(self.placeholder),
+		}
+	}
+}
+
+/// Prints the integer, or `overflow` if the value is poisoned. The alternate
+/// form, `{:#}`, prints `-` instead. Formatting options such as width and fill
+/// apply to the placeholder as well as to the integer, so checked values line
+/// up in columns.
+///
+/// Use [`Checked::display_or`] to choose another placeholder.
+///
+/// # Examples
+///
+/// ```rust
+/// use surety::Checked;
+///
+/// let poisoned = Checked::new(200u8) * 2;
+/// assert_eq!(format!("{}", Checked::new(5u8)), "5");
+/// assert_eq!(format!("{}", poisoned), "overflow");
+/// assert_eq!(format!("{:>4}|{:>#4}|", poisoned, poisoned), "overflow|   -|");
+/// ```
+impl<T: IsInteger> Display for Checked<T> {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		let placeholder = if fmt.alternate() { "-" } else { "overflow" };
+		Display::fmt(&self.display_or(placeholder), fmt)
+	}
 }
 
 impl<T: IsInteger> PartialEq<Option<T>> for Checked<T> {
@@ -376,6 +895,34 @@ impl<T: IsInteger> PartialOrd<Option<T>> for Checked<T> {
 	}
 }
 
+/// A poisoned `Checked` is unequal to every integer.
+impl<T: IsInteger> PartialEq<T> for Checked<T> {
+	fn eq(&self, other: &T) -> bool {
+		self.value == Some(*other)
+	}
+}
+
+/// A poisoned `Checked` is unordered with respect to every integer.
+///
+/// # Examples
+///
+/// ```rust
+/// use surety::Checked;
+///
+/// let num = Checked::new(5u8);
+/// assert!(num == 5 && num < 6);
+/// assert!(5 == num && 6 > num);
+/// let poisoned = num * 100;
+/// assert!(poisoned != 5);
+/// assert!(!(poisoned < 5) && !(poisoned >= 5));
+/// assert!(!(5 > poisoned) && !(5 <= poisoned));
+/// ```
+impl<T: IsInteger> PartialOrd<T> for Checked<T> {
+	fn partial_cmp(&self, other: &T) -> Option<Ordering> {
+		self.value.and_then(|value| value.partial_cmp(other))
+	}
+}
+
 impl<T: IsInteger> From<T> for Checked<T> {
 	fn from(num: T) -> Self {
 		Self { value: Some(num) }
@@ -388,6 +935,39 @@ impl<T: IsInteger> From<Option<T>> for Checked<T> {
 	}
 }
 
+/// Poisons the result if the overflow flag is set.
+impl<T: IsInteger> From<Overflowing<T>> for Checked<T> {
+	fn from(value: Overflowing<T>) -> Self {
+		value.to_checked()
+	}
+}
+
+/// Starts checked arithmetic from the current value.
+impl<T: IsInteger> From<Saturating<T>> for Checked<T> {
+	fn from(value: Saturating<T>) -> Self {
+		value.to_checked()
+	}
+}
+
+/// Starts checked arithmetic from the current value.
+impl<T: IsInteger> From<Wrapping<T>> for Checked<T> {
+	fn from(value: Wrapping<T>) -> Self {
+		value.to_checked()
+	}
+}
+
+impl<T: IsInteger> Sum<Self> for Checked<T> {
+	fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+		iter.fold(T::default().into(), |acc, next| acc + next)
+	}
+}
+
+impl<'a, T: 'a + IsInteger> Sum<&'a Self> for Checked<T> {
+	fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+		iter.copied().sum()
+	}
+}
+
 impl<T: IsInteger> Add<Self> for Checked<T> {
 	type Output = Self;
 
@@ -676,122 +1256,122 @@ impl<T: IsInteger> RemAssign<&T> for Checked<T> {
 	}
 }
 
-macro_rules! shift {
-	($($t:ty),* $(,)?) => { $(
-		impl<T: IsInteger> Shl<Checked<$t>> for Checked<T> {
-			type Output = Self;
-
-			fn shl(self, rhs: Checked<$t>) -> Self::Output {
-				self.and_then(|val| val.checked_shl(rhs.value?.try_into().ok()?))
-			}
-		}
-
-		impl<T: IsInteger> Shl<&Checked<$t>> for Checked<T> {
-			type Output = Self;
-
-			fn shl(self, rhs: &Checked<$t>) -> Self::Output {
-				self << *rhs
-			}
-		}
-
-		impl<T: IsInteger> Shl<$t> for Checked<T> {
-			type Output = Self;
-
-			fn shl(self, rhs: $t) -> Self::Output {
-				self.and_then(|val| val.checked_shl(rhs.try_into().ok()?))
-			}
-		}
-
-		impl<T: IsInteger> Shl<&$t> for Checked<T> {
-			type Output = Self;
-
-			fn shl(self, rhs: &$t) -> Self::Output {
-				self << *rhs
-			}
-		}
+impl<T, U> Shl<Checked<U>> for Checked<T>
+where
+	T: IsInteger,
+	U: IsInteger + ShiftAmount,
+{
+	type Output = Self;
 
-		impl<T: IsInteger> ShlAssign<Checked<$t>> for Checked<T> {
-			fn shl_assign(&mut self, rhs: Checked<$t>) {
-				*self = *self << rhs
-			}
-		}
+	fn shl(self, rhs: Checked<U>) -> Self::Output {
+		self.and_then(|val| {
+			shift::checked(val, rhs.value?.shift_magnitude(), true)
+		})
+	}
+}
 
-		impl<T: IsInteger> ShlAssign<&Checked<$t>> for Checked<T> {
-			fn shl_assign(&mut self, rhs: &Checked<$t>) {
-				*self = *self << rhs
-			}
-		}
+impl<T, U> Shl<&Checked<U>> for Checked<T>
+where
+	T: IsInteger,
+	U: IsInteger + ShiftAmount,
+{
+	type Output = Self;
 
-		impl<T: IsInteger> ShlAssign<$t> for Checked<T> {
-			fn shl_assign(&mut self, rhs: $t) {
-				*self = *self << rhs
-			}
-		}
+	fn shl(self, rhs: &Checked<U>) -> Self::Output {
+		self << *rhs
+	}
+}
 
-		impl<T: IsInteger> ShlAssign<&$t> for Checked<T> {
-			fn shl_assign(&mut self, rhs: &$t) {
-				*self = *self << rhs
-			}
-		}
+impl<T: IsInteger, U: ShiftAmount> Shl<U> for Checked<T> {
+	type Output = Self;
 
-		impl<T: IsInteger> Shr<Checked<$t>> for Checked<T> {
-			type Output = Self;
+	fn shl(self, rhs: U) -> Self::Output {
+		self.and_then(|val| shift::checked(val, rhs.shift_magnitude(), true))
+	}
+}
 
-			fn shr(self, rhs: Checked<$t>) -> Self::Output {
-				self.and_then(|val| val.checked_shr(rhs.value?.try_into().ok()?))
-			}
-		}
+impl<T, U> ShlAssign<Checked<U>> for Checked<T>
+where
+	T: IsInteger,
+	U: IsInteger + ShiftAmount,
+{
+	fn shl_assign(&mut self, rhs: Checked<U>) {
+		*self = *self << rhs
+	}
+}
 
-		impl<T: IsInteger> Shr<&Checked<$t>> for Checked<T> {
-			type Output = Self;
+impl<T, U> ShlAssign<&Checked<U>> for Checked<T>
+where
+	T: IsInteger,
+	U: IsInteger + ShiftAmount,
+{
+	fn shl_assign(&mut self, rhs: &Checked<U>) {
+		*self = *self << rhs
+	}
+}
 
-			fn shr(self, rhs: &Checked<$t>) -> Self::Output {
-				self >> *rhs
-			}
-		}
+impl<T: IsInteger, U: ShiftAmount> ShlAssign<U> for Checked<T> {
+	fn shl_assign(&mut self, rhs: U) {
+		*self = *self << rhs
+	}
+}
 
-		impl<T: IsInteger> Shr<$t> for Checked<T> {
-			type Output = Self;
+impl<T, U> Shr<Checked<U>> for Checked<T>
+where
+	T: IsInteger,
+	U: IsInteger + ShiftAmount,
+{
+	type Output = Self;
 
-			fn shr(self, rhs: $t) -> Self::Output {
-				self.and_then(|val| val.checked_shr(rhs.try_into().ok()?))
-			}
-		}
+	fn shr(self, rhs: Checked<U>) -> Self::Output {
+		self.and_then(|val| {
+			shift::checked(val, rhs.value?.shift_magnitude(), false)
+		})
+	}
+}
 
-		impl<T: IsInteger> Shr<&$t> for Checked<T> {
-			type Output = Self;
+impl<T, U> Shr<&Checked<U>> for Checked<T>
+where
+	T: IsInteger,
+	U: IsInteger + ShiftAmount,
+{
+	type Output = Self;
 
-			fn shr(self, rhs: &$t) -> Self::Output {
-				self >> *rhs
-			}
-		}
+	fn shr(self, rhs: &Checked<U>) -> Self::Output {
+		self >> *rhs
+	}
+}
 
-		impl<T: IsInteger> ShrAssign<Checked<$t>> for Checked<T> {
-			fn shr_assign(&mut self, rhs: Checked<$t>) {
-				*self = *self >> rhs
-			}
-		}
+impl<T: IsInteger, U: ShiftAmount> Shr<U> for Checked<T> {
+	type Output = Self;
 
-		impl<T: IsInteger> ShrAssign<&Checked<$t>> for Checked<T> {
-			fn shr_assign(&mut self, rhs: &Checked<$t>) {
-				*self = *self >> rhs
-			}
-		}
+	fn shr(self, rhs: U) -> Self::Output {
+		self.and_then(|val| shift::checked(val, rhs.shift_magnitude(), false))
+	}
+}
 
-		impl<T: IsInteger> ShrAssign<$t> for Checked<T> {
-			fn shr_assign(&mut self, rhs: $t) {
-				*self = *self >> rhs
-			}
-		}
+impl<T, U> ShrAssign<Checked<U>> for Checked<T>
+where
+	T: IsInteger,
+	U: IsInteger + ShiftAmount,
+{
+	fn shr_assign(&mut self, rhs: Checked<U>) {
+		*self = *self >> rhs
+	}
+}
 
-		impl<T: IsInteger> ShrAssign<&$t> for Checked<T> {
-			fn shr_assign(&mut self, rhs: &$t) {
-				*self = *self >> rhs
-			}
-		}
-	)* };
+impl<T, U> ShrAssign<&Checked<U>> for Checked<T>
+where
+	T: IsInteger,
+	U: IsInteger + ShiftAmount,
+{
+	fn shr_assign(&mut self, rhs: &Checked<U>) {
+		*self = *self >> rhs
+	}
 }
 
-shift!(
-	i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
-);
+impl<T: IsInteger, U: ShiftAmount> ShrAssign<U> for Checked<T> {
+	fn shr_assign(&mut self, rhs: U) {
+		*self = *self >> rhs
+	}
+}
@@ -0,0 +1,131 @@
+use core::cmp::Ordering;
+
+use funty::IsInteger;
+
+use crate::{
+	shift::{
+		Amount,
+		Magnitude,
+	},
+	i256,
+	u256,
+	Checked,
+	Overflowing,
+	Saturating,
+	Wrapping,
+};
+
+/** Compares integers of different signedness and width by their numeric value.
+
+The comparison operators require both sides to be the same type, and casting one
+side to match the other can change its value: `-1i64 as u64` is `u64::MAX`, and
+`u64::MAX as i64` is `-1`. These comparisons never cast, and are correct for
+every pair of fundamental integers.
+
+This trait is implemented for all of the fundamental integers, and cannot be
+implemented outside this crate.
+
+# Examples
+
+```rust
+use core::cmp::Ordering;
+use surety::MixedCmp as _;
+
+assert_eq!((-1i64).cmp_mixed(u64::MAX), Ordering::Less);
+assert_eq!(usize::MAX.cmp_mixed(-1isize), Ordering::Greater);
+# #[cfg(not(feature = "no-128"))]
+assert_eq!(200u8.cmp_mixed(200i128), Ordering::Equal);
+```
+**/
+pub trait MixedCmp: IsInteger + sealed::Sealed {
+	/// Splits the integer into whether it is negative and its magnitude.
+	#[doc(hidden)]
+	fn sign_magnitude(self) -> (bool, Magnitude);
+
+	/// Compares the numeric values of two integers of any signedness or width.
+	fn cmp_mixed<U: MixedCmp>(self, other: U) -> Ordering {
+		match (self.sign_magnitude(), other.sign_magnitude()) {
+			((false, this), (false, that)) => this.cmp(&that),
+			((true, this), (true, that)) => that.cmp(&this),
+			((false, _), (true, _)) => Ordering::Greater,
+			((true, _), (false, _)) => Ordering::Less,
+		}
+	}
+}
+
+impl<T: IsInteger + Amount> MixedCmp for T {
+	fn sign_magnitude(self) -> (bool, Magnitude) {
+		self.magnitude()
+	}
+}
+
+impl<T: MixedCmp> Checked<T> {
+	/// Compares the value against an integer of any signedness or width, or
+	/// produces `None` if it is poisoned.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use core::cmp::Ordering;
+	/// use surety::Checked;
+	///
+	/// let len = 10usize;
+	/// assert_eq!(Checked::new(-3i64).cmp_mixed(len), Some(Ordering::Less));
+	/// assert_eq!((Checked::new(i64::MAX) + 1).cmp_mixed(len), None);
+	/// ```
+	pub fn cmp_mixed<U: MixedCmp>(&self, other: U) -> Option<Ordering> {
+		self.value.map(|val| val.cmp_mixed(other))
+	}
+}
+
+macro_rules! compare {
+	($($w:ident),+ $(,)?) => { $(
+		impl<T: MixedCmp> $w<T> {
+			/// Compares the value against an integer of any signedness or
+			/// width.
+			pub fn cmp_mixed<U: MixedCmp>(&self, other: U) -> Ordering {
+				self.value.cmp_mixed(other)
+			}
+		}
+	)+ };
+}
+
+compare!(Overflowing, Saturating, Wrapping);
+
+/// Implements the integer-on-the-left comparisons, by reversing the operands
+/// of the wrapper-on-the-left ones.
+///
+/// These cannot be implemented generically over `T`, so they are implemented
+/// for each integer in the crate.
+macro_rules! reverse {
+	($($t:ty),+ $(,)?) => { $(
+		reverse!(@ $t => Checked Overflowing Saturating Wrapping);
+	)+ };
+
+	(@ $t:ty => $($w:ident)+) => { $(
+		impl PartialEq<$w<$t>> for $t {
+			fn eq(&self, other: &$w<$t>) -> bool {
+				other == self
+			}
+		}
+
+		impl PartialOrd<$w<$t>> for $t {
+			fn partial_cmp(&self, other: &$w<$t>) -> Option<Ordering> {
+				other.partial_cmp(self).map(Ordering::reverse)
+			}
+		}
+	)+ };
+}
+
+reverse!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, i256, u256);
+#[cfg(not(feature = "no-128"))]
+reverse!(i128, u128);
+
+mod sealed {
+	use crate::shift::Amount;
+
+	pub trait Sealed {}
+
+	impl<T: Amount> Sealed for T {
+	}
+}
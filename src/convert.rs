@@ -0,0 +1,74 @@
+//! Lossless conversions between the widths of an integer, inside a wrapper.
+
+use crate::{
+	i256,
+	u256,
+	Checked,
+	Overflowing,
+	Saturating,
+	Wrapping,
+};
+
+macro_rules! widen {
+	($($from:ty => $($to:ty)+);+ $(;)?) => { $( $(
+		impl From<Checked<$from>> for Checked<$to> {
+			fn from(num: Checked<$from>) -> Self {
+				Self {
+					value: num.value.map(<$to>::from),
+				}
+			}
+		}
+
+		impl From<Overflowing<$from>> for Overflowing<$to> {
+			fn from(num: Overflowing<$from>) -> Self {
+				Self {
+					value: num.value.into(),
+					has_overflowed: num.has_overflowed,
+				}
+			}
+		}
+
+		impl From<Saturating<$from>> for Saturating<$to> {
+			fn from(num: Saturating<$from>) -> Self {
+				Self {
+					value: num.value.into(),
+				}
+			}
+		}
+
+		impl From<Wrapping<$from>> for Wrapping<$to> {
+			fn from(num: Wrapping<$from>) -> Self {
+				Self {
+					value: num.value.into(),
+				}
+			}
+		}
+	)+ )+ };
+}
+
+widen! {
+	u8 => u16 u32 u64 usize i16 i32 i64 isize u256 i256;
+	u16 => u32 u64 usize i32 i64 u256 i256;
+	u32 => u64 i64 u256 i256;
+	u64 => u256 i256;
+	usize => u256 i256;
+	i8 => i16 i32 i64 isize i256;
+	i16 => i32 i64 isize i256;
+	i32 => i64 i256;
+	i64 => i256;
+	isize => i256;
+}
+
+#[cfg(not(feature = "no-128"))]
+widen! {
+	u8 => u128 i128;
+	u16 => u128 i128;
+	u32 => u128 i128;
+	u64 => u128 i128;
+	u128 => u256 i256;
+	i8 => i128;
+	i16 => i128;
+	i32 => i128;
+	i64 => i128;
+	i128 => i256;
+}
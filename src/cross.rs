@@ -0,0 +1,92 @@
+//! Single operations under a policy other than the wrapper’s own.
+//!
+//! Each wrapper can perform an addition, subtraction, or multiplication with
+//! the checked, saturating, or wrapping policy. The result stays in the
+//! wrapper’s own type, except that a checked operation on a wrapper which
+//! cannot be poisoned produces a `Checked`.
+
+use funty::IsInteger;
+
+use crate::{
+	Checked,
+	Overflowing,
+	Saturating,
+	Wrapping,
+};
+
+macro_rules! cross {
+	($($checked:ident $saturating:ident $wrapping:ident);+ $(;)?) => {
+		impl<T: IsInteger> Checked<T> {
+			$(
+				/// Performs one saturating operation. A poisoned value remains
+				/// poisoned.
+				pub fn $saturating(self, rhs: T) -> Self {
+					self.map(|val| val.$saturating(rhs))
+				}
+
+				/// Performs one wrapping operation. A poisoned value remains
+				/// poisoned.
+				pub fn $wrapping(self, rhs: T) -> Self {
+					self.map(|val| val.$wrapping(rhs))
+				}
+			)+
+		}
+
+		impl<T: IsInteger> Overflowing<T> {
+			$(
+				/// Performs one checked operation. The result is poisoned if
+				/// the operation overflows, or if the overflow flag is already
+				/// set.
+				pub fn $checked(self, rhs: T) -> Checked<T> {
+					self.to_checked().and_then(|val| val.$checked(rhs))
+				}
+
+				/// Performs one saturating operation, leaving the overflow flag
+				/// unchanged.
+				pub fn $saturating(self, rhs: T) -> Self {
+					self.map(|val| val.$saturating(rhs))
+				}
+
+				/// Performs one wrapping operation, without setting the
+				/// overflow flag.
+				pub fn $wrapping(self, rhs: T) -> Self {
+					self.map(|val| val.$wrapping(rhs))
+				}
+			)+
+		}
+
+		impl<T: IsInteger> Saturating<T> {
+			$(
+				/// Performs one checked operation, producing a `Checked`.
+				pub fn $checked(self, rhs: T) -> Checked<T> {
+					self.value.$checked(rhs).into()
+				}
+
+				/// Performs one wrapping operation.
+				pub fn $wrapping(self, rhs: T) -> Self {
+					self.value.$wrapping(rhs).into()
+				}
+			)+
+		}
+
+		impl<T: IsInteger> Wrapping<T> {
+			$(
+				/// Performs one checked operation, producing a `Checked`.
+				pub fn $checked(self, rhs: T) -> Checked<T> {
+					self.value.$checked(rhs).into()
+				}
+
+				/// Performs one saturating operation.
+				pub fn $saturating(self, rhs: T) -> Self {
+					self.value.$saturating(rhs).into()
+				}
+			)+
+		}
+	};
+}
+
+cross! {
+	checked_add saturating_add wrapping_add;
+	checked_sub saturating_sub wrapping_sub;
+	checked_mul saturating_mul wrapping_mul;
+}
@@ -0,0 +1,155 @@
+/*! Constant-time selection and comparison, built on `subtle`.
+
+This module is only present when the `ct` feature is enabled.
+
+`Overflowing`, `Saturating`, and `Wrapping` implement `subtle`’s
+`ConditionallySelectable` and `ConstantTimeEq`, and `Saturating` and `Wrapping`
+also implement `ConstantTimeGreater` and `ConstantTimeLess` where their integer
+does. These defer to `subtle`’s implementations for the contained integer, and
+`Overflowing` combines its flag with the same masking, so none of them branch on
+the values they are given.
+
+# Data Independence
+
+Of the arithmetic operators, only the following are free of branches on their
+operands:
+
+- `Wrapping`: `+`, `-`, `*`, and negation, which are the processor’s native
+  wrapping instructions.
+- `Overflowing`: the same operators, whose overflow flag is taken from the
+  processor’s carry or overflow flag and merged with a non-short-circuiting `|`.
+- `Wrapping` and `Overflowing`: shifts by an unsigned amount, which is masked
+  to the bit width. A signed shift amount branches on its sign.
+
+`Saturating` arithmetic compiles to conditional moves on the mainstream targets,
+but neither Rust nor LLVM promises this, so it must not be relied on for secret
+data. Division and remainder take data-dependent time on most processors, under
+every policy.
+
+`Checked` branches on every operation, both to test for poison and to stop at
+the first overflow, and so does not implement any of these traits. Its value can
+be moved into a `CtOption` with `From`, to leave the checked world before
+handling secrets, and a `CtOption` can be moved back with
+[`Checked::from_ct_option`].
+
+# Examples
+
+```rust
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use surety::{Overflowing, Wrapping};
+
+let a = Wrapping::new(3u32);
+let b = Wrapping::new(7u32);
+assert_eq!(Wrapping::conditional_select(&a, &b, Choice::from(1)), b);
+assert!(bool::from((a + 4).ct_eq(&b)));
+
+let over = Overflowing::new(250u8) + 10;
+let mut dst = Overflowing::new(4u8);
+assert!(!bool::from(dst.ct_eq(&over)));
+dst.conditional_assign(&over, Choice::from(1));
+assert!(dst.has_overflowed());
+```
+
+[`Checked::from_ct_option`]: crate::Checked::from_ct_option
+!*/
+
+use funty::IsInteger;
+use subtle::{
+	Choice,
+	ConditionallySelectable,
+	ConstantTimeEq,
+	ConstantTimeGreater,
+	ConstantTimeLess,
+	CtOption,
+};
+
+use crate::{
+	Checked,
+	Overflowing,
+	Saturating,
+	Wrapping,
+};
+
+macro_rules! ct {
+	($($w:ident),+ $(,)?) => { $(
+		impl<T> ConditionallySelectable for $w<T>
+		where T: IsInteger + ConditionallySelectable {
+			fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+				T::conditional_select(&a.value, &b.value, choice).into()
+			}
+		}
+
+		impl<T> ConstantTimeEq for $w<T>
+		where T: IsInteger + ConstantTimeEq {
+			fn ct_eq(&self, other: &Self) -> Choice {
+				self.value.ct_eq(&other.value)
+			}
+		}
+
+		impl<T> ConstantTimeGreater for $w<T>
+		where T: IsInteger + ConstantTimeGreater {
+			fn ct_gt(&self, other: &Self) -> Choice {
+				self.value.ct_gt(&other.value)
+			}
+		}
+
+		impl<T> ConstantTimeLess for $w<T>
+		where T: IsInteger + ConstantTimeLess {
+		}
+	)+ };
+}
+
+ct!(Saturating, Wrapping);
+
+impl<T> ConditionallySelectable for Overflowing<T>
+where T: IsInteger + ConditionallySelectable
+{
+	fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+		let value = T::conditional_select(&a.value, &b.value, choice);
+		let flag = u8::conditional_select(
+			&(a.has_overflowed as u8),
+			&(b.has_overflowed as u8),
+			choice,
+		);
+		Self::from_parts(value, flag != 0)
+	}
+}
+
+impl<T> ConstantTimeEq for Overflowing<T>
+where T: IsInteger + ConstantTimeEq
+{
+	fn ct_eq(&self, other: &Self) -> Choice {
+		self.value.ct_eq(&other.value)
+			& (self.has_overflowed as u8).ct_eq(&(other.has_overflowed as u8))
+	}
+}
+
+impl<T: IsInteger> From<Checked<T>> for CtOption<T> {
+	fn from(num: Checked<T>) -> Self {
+		let is_some = Choice::from(num.is_some() as u8);
+		CtOption::new(num.value.unwrap_or(T::ZERO), is_some)
+	}
+}
+
+impl<T: IsInteger> Checked<T> {
+	/// Moves a constant-time optional value into the checked world, poisoning
+	/// it if it is absent.
+	///
+	/// This branches on whether the value is present, and so should only be
+	/// called once that is no longer secret.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use subtle::{Choice, CtOption};
+	/// use surety::Checked;
+	///
+	/// let num = Checked::from_ct_option(CtOption::new(5u8, Choice::from(1)));
+	/// assert_eq!(num + 1, Some(6));
+	/// let none = CtOption::new(5u8, Choice::from(0));
+	/// assert!(Checked::from_ct_option(none).is_none());
+	/// ```
+	pub fn from_ct_option(opt: CtOption<T>) -> Self {
+		Option::<T>::from(opt).into()
+	}
+}
@@ -0,0 +1,184 @@
+//! Database column mappings for `sqlx` and `diesel`.
+//!
+//! Each section of this module is only present when its feature, `sqlx` or
+//! `diesel`, is enabled.
+//!
+//! `Saturating` and `Wrapping` encode and decode exactly as their contained
+//! integer does, for every database and SQL type that the integer supports, so
+//! a `Saturating<i64>` field maps onto a `BIGINT` column and a `Wrapping<i32>`
+//! field onto an `INTEGER` column with no adapter. The overflow policy applies
+//! to the arithmetic done on the value in Rust, and is not recorded in the
+//! database.
+
+#[cfg(feature = "sqlx")]
+mod sqlx {
+	use ::sqlx::{
+		database::Database,
+		encode::IsNull,
+		error::BoxDynError,
+		Decode,
+		Encode,
+		Type,
+	};
+	use funty::IsInteger;
+
+	use crate::{
+		Saturating,
+		Wrapping,
+	};
+
+	macro_rules! sqlx {
+		($($w:ident),+ $(,)?) => { $(
+			impl<T, DB> Type<DB> for $w<T>
+			where
+				T: IsInteger + Type<DB>,
+				DB: Database,
+			{
+				fn type_info() -> DB::TypeInfo {
+					T::type_info()
+				}
+
+				fn compatible(ty: &DB::TypeInfo) -> bool {
+					T::compatible(ty)
+				}
+			}
+
+			impl<'q, T, DB> Encode<'q, DB> for $w<T>
+			where
+				T: IsInteger + Encode<'q, DB>,
+				DB: Database,
+			{
+				fn encode_by_ref(
+					&self,
+					buf: &mut DB::ArgumentBuffer,
+				) -> Result<IsNull, BoxDynError> {
+					self.value.encode_by_ref(buf)
+				}
+
+				fn produces(&self) -> Option<DB::TypeInfo> {
+					self.value.produces()
+				}
+
+				fn size_hint(&self) -> usize {
+					self.value.size_hint()
+				}
+			}
+
+			impl<'r, T, DB> Decode<'r, DB> for $w<T>
+			where
+				T: IsInteger + Decode<'r, DB>,
+				DB: Database,
+			{
+				fn decode(value: DB::ValueRef<'r>) -> Result<Self, BoxDynError> {
+					T::decode(value).map(Self::new)
+				}
+			}
+		)+ };
+	}
+
+	sqlx!(Saturating, Wrapping);
+}
+
+#[cfg(feature = "diesel")]
+mod diesel {
+	use ::diesel::{
+		backend::Backend,
+		deserialize::{
+			self,
+			FromSql,
+			Queryable,
+		},
+		expression::{
+			AsExpression,
+			TypedExpressionType,
+		},
+		serialize::{
+			self,
+			Output,
+			ToSql,
+		},
+		sql_types::{
+			SingleValue,
+			SqlType,
+		},
+	};
+	use funty::IsInteger;
+
+	use crate::{
+		Saturating,
+		Wrapping,
+	};
+
+	macro_rules! diesel {
+		($($w:ident),+ $(,)?) => { $(
+			impl<ST, T> AsExpression<ST> for $w<T>
+			where
+				T: IsInteger + AsExpression<ST>,
+				ST: SqlType + TypedExpressionType,
+			{
+				type Expression = T::Expression;
+
+				fn as_expression(self) -> Self::Expression {
+					self.value.as_expression()
+				}
+			}
+
+			impl<ST, T> AsExpression<ST> for &$w<T>
+			where
+				T: IsInteger + AsExpression<ST>,
+				ST: SqlType + TypedExpressionType,
+			{
+				type Expression = T::Expression;
+
+				fn as_expression(self) -> Self::Expression {
+					self.value.as_expression()
+				}
+			}
+
+			impl<ST, T, DB> ToSql<ST, DB> for $w<T>
+			where
+				T: IsInteger + ToSql<ST, DB>,
+				DB: Backend,
+			{
+				fn to_sql<'b>(
+					&'b self,
+					out: &mut Output<'b, '_, DB>,
+				) -> serialize::Result {
+					self.value.to_sql(out)
+				}
+			}
+
+			impl<ST, T, DB> FromSql<ST, DB> for $w<T>
+			where
+				T: IsInteger + FromSql<ST, DB>,
+				DB: Backend,
+			{
+				fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+					T::from_sql(bytes).map(Self::new)
+				}
+
+				fn from_nullable_sql(
+					bytes: Option<DB::RawValue<'_>>,
+				) -> deserialize::Result<Self> {
+					T::from_nullable_sql(bytes).map(Self::new)
+				}
+			}
+
+			impl<ST, T, DB> Queryable<ST, DB> for $w<T>
+			where
+				T: IsInteger,
+				ST: SingleValue,
+				DB: Backend,
+				Self: FromSql<ST, DB>,
+			{
+				type Row = Self;
+
+				fn build(row: Self::Row) -> deserialize::Result<Self> {
+					Ok(row)
+				}
+			}
+		)+ };
+	}
+
+	diesel!(Saturating, Wrapping);
+}
@@ -0,0 +1,380 @@
+use core::{
+	convert::TryFrom,
+	fmt::{
+		self,
+		Display,
+		Formatter,
+	},
+	ops::{
+		Add,
+		AddAssign,
+		Div,
+		DivAssign,
+		Mul,
+		MulAssign,
+		Sub,
+		SubAssign,
+	},
+	str::FromStr,
+};
+
+use funty::IsInteger;
+
+use crate::{
+	bits,
+	shift::Magnitude,
+	FixedPolicy,
+	MixedCmp,
+	RangePolicy,
+	Widen,
+};
+
+/** A decimal fixed-point number with `SCALE` fractional digits.
+
+The first parameter is one of the wrapper types, `Checked`, `Saturating`, or
+`Wrapping`, around the integer that holds the number’s digits, and it selects
+what happens when a result does not fit in that integer. The number represented
+is the integer divided by `10^SCALE`, so `Decimal<Checked<i64>, 2>` counts whole
+cents and can never drift the way binary fractions do.
+
+As with [`Fixed`], every operation is computed in the integer’s [`Widen`] type
+and the overflow policy is applied once, when the result is brought back to the
+narrow integer. Multiplication and division round their exact result to the
+nearest representable number, and a result exactly halfway between two numbers
+rounds to the one whose last digit is even. This banker’s rounding does not bias
+a long series of operations upwards or downwards.
+
+A `Decimal` is written and parsed as a decimal literal with exactly `SCALE`
+fractional digits when it is displayed, and up to `SCALE` of them when it is
+parsed. A poisoned `Checked` decimal is displayed as `overflow`.
+
+# Examples
+
+```rust
+use surety::{Checked, Decimal, Saturating};
+
+# #[cfg(not(feature = "no-128"))] {
+type Usd = Decimal<Checked<i64>, 2>;
+let price: Usd = "19.99".parse().unwrap();
+let total = price * Usd::from_int(3);
+assert_eq!(total.to_string(), "59.97");
+assert_eq!((Usd::from_int(10) / Usd::from_int(3)).to_string(), "3.33");
+# }
+
+//  0.125 and 0.375 are both halfway, and round to the even digit.
+type Cents = Decimal<Saturating<u16>, 2>;
+let half: Cents = "0.5".parse().unwrap();
+let eighth = half * Cents::from_bits(25);
+assert_eq!(eighth.to_string(), "0.12");
+let three = half * Cents::from_bits(75);
+assert_eq!(three.to_string(), "0.38");
+assert_eq!((Cents::from_int(600) * Cents::from_int(2)).to_string(), "655.35");
+
+# #[cfg(not(feature = "no-128"))] {
+# type Usd = Decimal<Checked<i64>, 2>;
+assert!("1.234".parse::<Usd>().is_err());
+assert!((Usd::from_int(1) / Usd::from_int(0)).value().is_none());
+# }
+```
+
+[`Fixed`]: crate::Fixed
+[`Widen`]: crate::Widen
+**/
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Decimal<W: FixedPolicy, const SCALE: u32> {
+	/// The contained wrapper, holding the number multiplied by `10^SCALE`.
+	value: W,
+}
+
+impl<W: FixedPolicy, const SCALE: u32> Decimal<W, SCALE> {
+	/// Creates a decimal number from its raw digits, which are the number
+	/// multiplied by `10^SCALE`.
+	///
+	/// # Panics
+	///
+	/// This panics if `10^SCALE` does not fit in the integer.
+	pub fn from_bits(bits: W::Int) -> Self {
+		//  Rejects a scale which cannot be represented.
+		Self::unit();
+		Self {
+			value: W::narrow(bits.widen()),
+		}
+	}
+
+	/// Creates a decimal number with an integer value, applying the overflow
+	/// policy if it does not fit.
+	pub fn from_int(value: W::Int) -> Self {
+		Self {
+			value: W::narrow(value.widen() * Self::unit()),
+		}
+	}
+
+	/// Gets the contained wrapper, holding the raw digits.
+	pub fn value(&self) -> W {
+		self.value
+	}
+
+	/// Removes the contained wrapper, holding the raw digits.
+	pub fn into_inner(self) -> W {
+		self.value
+	}
+
+	/// Computes `10^SCALE` in the wide type.
+	///
+	/// # Panics
+	///
+	/// This panics if `10^SCALE` does not fit in the narrow integer.
+	fn unit() -> Wide<W> {
+		let unit = W::Int::try_from(10u8)
+			.ok()
+			.and_then(|ten| ten.checked_pow(SCALE));
+		match unit {
+			Some(unit) => unit.widen(),
+			None => panic!("The scale factor must fit in the integer type"),
+		}
+	}
+
+	/// Applies an operation in the wide type, and narrows its result. `None`
+	/// marks a division by zero.
+	fn combine(
+		self,
+		rhs: Self,
+		op: impl FnOnce(Wide<W>, Wide<W>) -> Option<Wide<W>>,
+	) -> Self
+	{
+		let (lhs, rhs) = match (self.value.get(), rhs.value.get()) {
+			(Ok(lhs), Ok(rhs)) => (lhs.widen(), rhs.widen()),
+			(Err(value), _) | (_, Err(value)) => return Self { value },
+		};
+		Self {
+			value: match op(lhs, rhs) {
+				Some(wide) => W::narrow(wide),
+				None => W::div_by_zero(),
+			},
+		}
+	}
+}
+
+/// The wide type of a policy’s integer.
+type Wide<W> = <<W as RangePolicy>::Int as Widen>::Wide;
+
+/// Divides `num` by `den`, rounding to the nearest integer, and to the even
+/// integer when `num / den` is exactly halfway between two.
+///
+/// The remainder, doubled, must fit in `I`.
+fn div_round<I: IsInteger>(num: I, den: I) -> I {
+	let zero = I::ZERO;
+	let one = bits::one::<I>();
+	let quot = num / den;
+	let rem = num % den;
+	if rem == zero {
+		return quot;
+	}
+	//  The remainder takes the sign of `num`. Compare magnitudes.
+	let twice = if rem < zero { zero - rem - rem } else { rem + rem };
+	let den_mag = if den < zero { zero - den } else { den };
+	let away = twice > den_mag || (twice == den_mag && quot % (one + one) != zero);
+	match (away, (num < zero) != (den < zero)) {
+		(false, _) => quot,
+		(true, false) => quot + one,
+		(true, true) => quot - one,
+	}
+}
+
+impl<W: FixedPolicy, const SCALE: u32> Add<Self> for Decimal<W, SCALE> {
+	type Output = Self;
+
+	fn add(self, rhs: Self) -> Self::Output {
+		self.combine(rhs, |lhs, rhs| Some(lhs + rhs))
+	}
+}
+
+impl<W: FixedPolicy, const SCALE: u32> Sub<Self> for Decimal<W, SCALE> {
+	type Output = Self;
+
+	fn sub(self, rhs: Self) -> Self::Output {
+		self.combine(rhs, |lhs, rhs| Some(lhs - rhs))
+	}
+}
+
+impl<W: FixedPolicy, const SCALE: u32> Mul<Self> for Decimal<W, SCALE> {
+	type Output = Self;
+
+	fn mul(self, rhs: Self) -> Self::Output {
+		self.combine(rhs, |lhs, rhs| Some(div_round(lhs * rhs, Self::unit())))
+	}
+}
+
+impl<W: FixedPolicy, const SCALE: u32> Div<Self> for Decimal<W, SCALE> {
+	type Output = Self;
+
+	fn div(self, rhs: Self) -> Self::Output {
+		self.combine(rhs, |lhs, rhs| {
+			if rhs == Wide::<W>::ZERO {
+				None
+			}
+			else {
+				Some(div_round(lhs * Self::unit(), rhs))
+			}
+		})
+	}
+}
+
+macro_rules! op {
+	($($trait:ident $func:ident $assign:ident $assign_func:ident);+ $(;)?) => { $(
+		impl<W: FixedPolicy, const SCALE: u32> $trait<&Self> for Decimal<W, SCALE> {
+			type Output = Self;
+
+			fn $func(self, rhs: &Self) -> Self::Output {
+				$trait::$func(self, *rhs)
+			}
+		}
+
+		impl<W: FixedPolicy, const SCALE: u32> $assign<Self> for Decimal<W, SCALE> {
+			fn $assign_func(&mut self, rhs: Self) {
+				*self = $trait::$func(*self, rhs)
+			}
+		}
+
+		impl<W: FixedPolicy, const SCALE: u32> $assign<&Self> for Decimal<W, SCALE> {
+			fn $assign_func(&mut self, rhs: &Self) {
+				*self = $trait::$func(*self, *rhs)
+			}
+		}
+	)+ };
+}
+
+op! {
+	Add add AddAssign add_assign;
+	Sub sub SubAssign sub_assign;
+	Mul mul MulAssign mul_assign;
+	Div div DivAssign div_assign;
+}
+
+impl<W, const SCALE: u32> Display for Decimal<W, SCALE>
+where
+	W: FixedPolicy,
+	W::Int: MixedCmp,
+{
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		let bits = match self.value.get() {
+			Ok(bits) => bits,
+			Err(_) => return fmt.write_str("overflow"),
+		};
+		let (neg, magnitude) = bits.sign_magnitude();
+		let unit = Magnitude::pow(10, SCALE);
+		let sign = if neg { "-" } else { "" };
+		if SCALE == 0 {
+			return write!(fmt, "{}{}", sign, magnitude);
+		}
+		write!(
+			fmt,
+			"{}{}.{:0width$}",
+			sign,
+			magnitude / unit,
+			magnitude % unit,
+			width = SCALE as usize,
+		)
+	}
+}
+
+/// Parses a decimal literal, with an optional sign and at most `SCALE`
+/// fractional digits, and applies the overflow policy if it does not fit.
+///
+/// A literal which does not fit even in the integer’s [`Widen`] type, including
+/// a negative literal for an unsigned integer, is an error.
+///
+/// [`Widen`]: crate::Widen
+impl<W: FixedPolicy, const SCALE: u32> FromStr for Decimal<W, SCALE> {
+	type Err = ParseDecimalError;
+
+	fn from_str(text: &str) -> Result<Self, Self::Err> {
+		let invalid = ParseDecimalError {
+			kind: ParseKind::Invalid,
+		};
+		let range = ParseDecimalError {
+			kind: ParseKind::Range,
+		};
+
+		let (neg, text) = match text.as_bytes().first() {
+			Some(b'-') => (true, &text[1 ..]),
+			Some(b'+') => (false, &text[1 ..]),
+			_ => (false, text),
+		};
+		let (int, frac) = match text.find('.') {
+			Some(dot) => (&text[.. dot], &text[dot + 1 ..]),
+			None => (text, ""),
+		};
+		if int.is_empty() && frac.is_empty() {
+			return Err(invalid);
+		}
+		if frac.len() > SCALE as usize {
+			return Err(ParseDecimalError {
+				kind: ParseKind::Precision,
+			});
+		}
+
+		Self::unit();
+		let ten = Wide::<W>::try_from(10u8).map_err(|_| range)?;
+		let padding = SCALE - frac.len() as u32;
+		let mut magnitude = Wide::<W>::ZERO;
+		for digit in int.bytes().chain(frac.bytes()) {
+			if !digit.is_ascii_digit() {
+				return Err(invalid);
+			}
+			let digit = Wide::<W>::try_from(digit - b'0').map_err(|_| range)?;
+			magnitude = magnitude
+				.checked_mul(ten)
+				.and_then(|m| m.checked_add(digit))
+				.ok_or(range)?;
+		}
+		magnitude = ten
+			.checked_pow(padding)
+			.and_then(|pad| magnitude.checked_mul(pad))
+			.ok_or(range)?;
+		let bits = if neg {
+			Wide::<W>::ZERO.checked_sub(magnitude).ok_or(range)?
+		}
+		else {
+			magnitude
+		};
+		Ok(Self {
+			value: W::narrow(bits),
+		})
+	}
+}
+
+/** The error produced when a string is not a valid [`Decimal`] literal.
+
+[`Decimal`]: crate::Decimal
+**/
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct ParseDecimalError {
+	/// The reason the literal was rejected.
+	kind: ParseKind,
+}
+
+/// The reasons a decimal literal can be rejected.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum ParseKind {
+	/// The text is not a decimal literal.
+	Invalid,
+	/// The literal has more fractional digits than the scale.
+	Precision,
+	/// The literal does not fit in the wide integer.
+	Range,
+}
+
+impl Display for ParseDecimalError {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.pad(match self.kind {
+			ParseKind::Invalid => "invalid decimal literal",
+			ParseKind::Precision => "too many fractional digits",
+			ParseKind::Range => "decimal literal out of range",
+		})
+	}
+}
+
+impl core::error::Error for ParseDecimalError {
+}
@@ -0,0 +1,57 @@
+//! Packed saturating arithmetic on the ARM DSP extension.
+//!
+//! The `QADD16` and `QSUB16` instructions add or subtract two pairs of `i16`
+//! lanes held in one register, saturating each lane independently. LLVM already
+//! selects `QADD` and `QSUB` for scalar saturating arithmetic on targets with
+//! the extension, but it does not pack neighboring elements of a slice, so
+//! these kernels do that by hand.
+//!
+//! This module is only compiled for 32-bit ARM when the `arm-dsp` feature is
+//! enabled. The DSP extension is not visible to `#[cfg]` on stable Rust, so the
+//! feature is a promise that the target has it, as the `thumbv7em` targets do.
+
+use core::arch::asm;
+
+macro_rules! packed {
+	($($name:ident $insn:literal $scalar:ident);+ $(;)?) => { $(
+		pub(crate) fn $name(dst: &mut [i16], src: &[i16]) {
+			//  Trimming both slices to the same length lines up their
+			//  unpaired elements.
+			let len = dst.len().min(src.len());
+			let (dst, src) = (&mut dst[.. len], &src[.. len]);
+			let mut dst_pairs = dst.chunks_exact_mut(2);
+			let mut src_pairs = src.chunks_exact(2);
+			for (d, s) in (&mut dst_pairs).zip(&mut src_pairs) {
+				let mut lhs = pack(d);
+				let rhs = pack(s);
+				//  SAFETY: the instruction only reads and writes the named
+				//  registers, and the `arm-dsp` feature asserts that the target
+				//  implements it.
+				unsafe {
+					asm!(
+						concat!($insn, " {0}, {0}, {1}"),
+						inout(reg) lhs,
+						in(reg) rhs,
+						options(pure, nomem, nostack),
+					);
+				}
+				d[0] = lhs as i16;
+				d[1] = (lhs >> 16) as i16;
+			}
+			let tail = dst_pairs.into_remainder().iter_mut();
+			for (d, s) in tail.zip(src_pairs.remainder()) {
+				*d = d.$scalar(*s);
+			}
+		}
+	)+ };
+}
+
+packed! {
+	add_i16 "qadd16" saturating_add;
+	sub_i16 "qsub16" saturating_sub;
+}
+
+/// Packs two `i16` lanes into one register, with the first in the low half.
+fn pack(pair: &[i16]) -> u32 {
+	(pair[0] as u16 as u32) | ((pair[1] as u16 as u32) << 16)
+}
@@ -0,0 +1,256 @@
+use core::ops::{
+	Add,
+	AddAssign,
+	Div,
+	DivAssign,
+	Mul,
+	MulAssign,
+	Neg,
+	Rem,
+	RemAssign,
+	Shl,
+	ShlAssign,
+	Shr,
+	ShrAssign,
+	Sub,
+	SubAssign,
+};
+
+use funty::{
+	IsInteger,
+	IsSigned,
+};
+
+use crate::{
+	Overflowing,
+	ShiftAmount,
+};
+
+/** Overflow-detecting arithmetic which reports both the history and the most
+recent operation.
+
+This type performs the same wrapping arithmetic as [`Overflowing`], and keeps
+the same sticky flag, which is set by the first overflow and stays set until it
+is cleared. It also keeps a second flag which records whether the most recent
+operation, alone, overflowed, and which every operation replaces. This is the
+carry or overflow bit of a processor status register, for emulators that need
+it per instruction, while the sticky flag still summarizes a whole block.
+
+When the right operand is also a `DualOverflowing`, its sticky flag is merged
+into the result, as `Overflowing` does, but its last-operation flag is not.
+
+# Examples
+
+```rust
+use surety::DualOverflowing;
+
+let acc = DualOverflowing::new(250u8) + 10;
+assert_eq!(acc, 4);
+assert!(acc.last_overflowed());
+assert!(acc.has_overflowed());
+
+let acc = acc + 1;
+assert!(!acc.last_overflowed());
+assert!(acc.has_overflowed());
+```
+
+[`Overflowing`]: crate::Overflowing
+**/
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct DualOverflowing<T: IsInteger> {
+	/// The contained integer.
+	value: T,
+	/// Marks whether an overflow has occurred since the flags were cleared.
+	sticky: bool,
+	/// Marks whether the most recent operation overflowed.
+	last: bool,
+}
+
+impl<T: IsInteger> DualOverflowing<T> {
+	/// Wraps an integer for overflow-detecting arithmetic, with both flags
+	/// cleared.
+	pub const fn new(value: T) -> Self {
+		Self {
+			value,
+			sticky: false,
+			last: false,
+		}
+	}
+
+	/// Gets the contained integer, regardless of the overflow flags.
+	pub const fn value(&self) -> T {
+		self.value
+	}
+
+	/// Unwraps the contained integer, discarding the overflow flags.
+	pub const fn into_inner(self) -> T {
+		self.value
+	}
+
+	/// Tests whether an overflow has occurred since the flags were cleared.
+	pub const fn has_overflowed(&self) -> bool {
+		self.sticky
+	}
+
+	/// Tests whether the most recent operation overflowed.
+	pub const fn last_overflowed(&self) -> bool {
+		self.last
+	}
+
+	/// Clears both overflow flags, keeping the integer.
+	pub fn clear_overflow(&mut self) {
+		self.sticky = false;
+		self.last = false;
+	}
+
+	/// Switches to overflow-detecting arithmetic with only the sticky flag.
+	pub const fn to_overflowing(self) -> Overflowing<T> {
+		Overflowing::from_parts(self.value, self.sticky)
+	}
+
+	/// Performs one operation in `Overflowing`, and records its flag as both
+	/// the last-operation flag and a contribution to the sticky flag.
+	fn step(self, op: impl FnOnce(Overflowing<T>) -> Overflowing<T>) -> Self {
+		let (value, last) = op(Overflowing::new(self.value)).into_parts();
+		Self {
+			value,
+			sticky: self.sticky | last,
+			last,
+		}
+	}
+
+	/// Merges the sticky flag of another operand into this one.
+	fn merge(mut self, sticky: bool) -> Self {
+		self.sticky |= sticky;
+		self
+	}
+}
+
+impl<T: IsInteger> PartialEq<T> for DualOverflowing<T> {
+	fn eq(&self, other: &T) -> bool {
+		self.value.eq(other)
+	}
+}
+
+impl<T: IsInteger> From<T> for DualOverflowing<T> {
+	fn from(value: T) -> Self {
+		Self::new(value)
+	}
+}
+
+/// The sticky flag is kept, and the last-operation flag is cleared.
+impl<T: IsInteger> From<Overflowing<T>> for DualOverflowing<T> {
+	fn from(num: Overflowing<T>) -> Self {
+		let (value, sticky) = num.into_parts();
+		Self {
+			value,
+			sticky,
+			last: false,
+		}
+	}
+}
+
+impl<T: IsInteger> From<DualOverflowing<T>> for Overflowing<T> {
+	fn from(num: DualOverflowing<T>) -> Self {
+		num.to_overflowing()
+	}
+}
+
+macro_rules! op {
+	($($trait:ident $func:ident $assign:ident $assign_func:ident);+ $(;)?) => { $(
+		impl<T: IsInteger> $trait<T> for DualOverflowing<T> {
+			type Output = Self;
+
+			fn $func(self, rhs: T) -> Self::Output {
+				self.step(|val| $trait::$func(val, rhs))
+			}
+		}
+
+		impl<T: IsInteger> $trait<&T> for DualOverflowing<T> {
+			type Output = Self;
+
+			fn $func(self, rhs: &T) -> Self::Output {
+				$trait::$func(self, *rhs)
+			}
+		}
+
+		impl<T: IsInteger> $trait<Self> for DualOverflowing<T> {
+			type Output = Self;
+
+			fn $func(self, rhs: Self) -> Self::Output {
+				$trait::$func(self, rhs.value).merge(rhs.sticky)
+			}
+		}
+
+		impl<T: IsInteger> $trait<&Self> for DualOverflowing<T> {
+			type Output = Self;
+
+			fn $func(self, rhs: &Self) -> Self::Output {
+				$trait::$func(self, *rhs)
+			}
+		}
+
+		impl<T: IsInteger> $assign<T> for DualOverflowing<T> {
+			fn $assign_func(&mut self, rhs: T) {
+				*self = $trait::$func(*self, rhs)
+			}
+		}
+
+		impl<T: IsInteger> $assign<&T> for DualOverflowing<T> {
+			fn $assign_func(&mut self, rhs: &T) {
+				*self = $trait::$func(*self, *rhs)
+			}
+		}
+
+		impl<T: IsInteger> $assign<Self> for DualOverflowing<T> {
+			fn $assign_func(&mut self, rhs: Self) {
+				*self = $trait::$func(*self, rhs)
+			}
+		}
+
+		impl<T: IsInteger> $assign<&Self> for DualOverflowing<T> {
+			fn $assign_func(&mut self, rhs: &Self) {
+				*self = $trait::$func(*self, *rhs)
+			}
+		}
+	)+ };
+}
+
+op! {
+	Add add AddAssign add_assign;
+	Sub sub SubAssign sub_assign;
+	Mul mul MulAssign mul_assign;
+	Div div DivAssign div_assign;
+	Rem rem RemAssign rem_assign;
+}
+
+impl<T: IsSigned> Neg for DualOverflowing<T> {
+	type Output = Self;
+
+	fn neg(self) -> Self::Output {
+		self.step(Neg::neg)
+	}
+}
+
+macro_rules! shift {
+	($($trait:ident $func:ident $assign:ident $assign_func:ident);+ $(;)?) => { $(
+		impl<T: IsInteger, U: ShiftAmount> $trait<U> for DualOverflowing<T> {
+			type Output = Self;
+
+			fn $func(self, rhs: U) -> Self::Output {
+				self.step(|val| $trait::$func(val, rhs))
+			}
+		}
+
+		impl<T: IsInteger, U: ShiftAmount> $assign<U> for DualOverflowing<T> {
+			fn $assign_func(&mut self, rhs: U) {
+				*self = $trait::$func(*self, rhs)
+			}
+		}
+	)+ };
+}
+
+shift! {
+	Shl shl ShlAssign shl_assign;
+	Shr shr ShrAssign shr_assign;
+}
@@ -0,0 +1,53 @@
+//! The error type of arithmetic that has overflowed.
+
+use core::fmt::{
+	self,
+	Display,
+	Formatter,
+};
+
+/** The error produced when checked arithmetic has overflowed.
+
+`Checked` does not record which operation poisoned it, or why, so this error
+carries no further information. It is produced by [`Checked::ok_or_overflow`],
+and is the default error type of [`surety::Result`].
+
+[`Checked::ok_or_overflow`]: crate::Checked::ok_or_overflow
+[`surety::Result`]: crate::Result
+**/
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ArithmeticError;
+
+impl Display for ArithmeticError {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		fmt.pad("arithmetic overflow")
+	}
+}
+
+/// This composes with `?` into `Box<dyn Error>`, and with error-reporting
+/// crates such as `anyhow`, in both `std` and `no_std` programs.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::error::Error;
+/// use surety::Checked;
+///
+/// fn scale(x: u8) -> Result<u8, Box<dyn Error>> {
+///     Ok((Checked::new(x) * 4).ok_or_overflow()?)
+/// }
+///
+/// assert_eq!(scale(10).unwrap(), 40);
+/// assert_eq!(scale(100).unwrap_err().to_string(), "arithmetic overflow");
+/// ```
+impl core::error::Error for ArithmeticError {
+}
+
+/** A `Result` whose error defaults to [`ArithmeticError`].
+
+The error parameter can still be named, so this alias does not disturb code
+which glob-imports the crate and uses the two-parameter `Result`.
+
+[`ArithmeticError`]: crate::ArithmeticError
+**/
+pub type Result<T, E = ArithmeticError> = core::result::Result<T, E>;
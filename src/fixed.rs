@@ -0,0 +1,288 @@
+use core::{
+	mem,
+	ops::{
+		Add,
+		AddAssign,
+		Div,
+		DivAssign,
+		Mul,
+		MulAssign,
+		Sub,
+		SubAssign,
+	},
+};
+
+use funty::IsInteger as _;
+
+use crate::{
+	Checked,
+	RangePolicy,
+	Saturating,
+	Widen,
+	Wrapping,
+};
+
+/** A binary fixed-point number with `FRAC` fractional bits.
+
+The first parameter is one of the wrapper types, `Checked`, `Saturating`, or
+`Wrapping`, around the integer that holds the number’s bits, and it selects what
+happens when a result does not fit in that integer. The number represented is
+the integer divided by `2^FRAC`.
+
+Every operation is computed in the integer’s [`Widen`] type, where it cannot
+overflow, and the overflow policy is applied once when the result is brought
+back to the narrow integer. In particular, multiplication forms the full
+double-width product before rescaling it, so a product that fits is always
+exact up to the discarded fractional bits, even when the intermediate would
+not fit in the narrow integer. As with the integer operators, the rescaling
+shift of multiplication rounds towards negative infinity, and division rounds
+towards zero.
+
+# Examples
+
+```rust
+use surety::{Checked, Fixed, Saturating};
+
+//  Q4.4: four integer bits and four fractional bits.
+type Q4 = Fixed<Saturating<u8>, 4>;
+let half = Q4::from_bits(0b1000);
+let three = Q4::from_int(3);
+assert_eq!((three * half).value(), 0b1_1000);
+assert_eq!((three / half).value(), 0b110_0000);
+assert_eq!((three * three * three).value(), 255);
+
+type Q8 = Fixed<Checked<i16>, 8>;
+assert!((Q8::from_int(100) * Q8::from_int(2)).value().is_none());
+assert!((Q8::from_int(1) / Q8::from_int(0)).value().is_none());
+
+//  Q0.8: every bit is fractional, so `-0.5 / -2^-8` is far out of range.
+type Q0 = Fixed<Checked<i8>, 8>;
+assert!((Q0::from_bits(-128) / Q0::from_bits(-1)).value().is_none());
+let q0 = Fixed::<Saturating<i8>, 8>::from_bits(-128);
+assert_eq!((q0 / Fixed::from_bits(-1)).value(), i8::MAX);
+```
+
+[`Widen`]: crate::Widen
+**/
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Fixed<W: FixedPolicy, const FRAC: u32> {
+	/// The contained wrapper, holding the number multiplied by `2^FRAC`.
+	value: W,
+}
+
+impl<W: FixedPolicy, const FRAC: u32> Fixed<W, FRAC> {
+	/// Rejects a `FRAC` which exceeds the width of the integer. This is
+	/// evaluated when a constructor is instantiated, so the error is reported
+	/// at build time.
+	const FRAC_FITS: () = assert!(
+		FRAC as usize <= mem::size_of::<W::Int>() * 8,
+		"The fractional bits must fit in the integer type",
+	);
+
+	/// Creates a fixed-point number from its raw bits.
+	///
+	/// A `FRAC` which exceeds the width of the integer fails to compile:
+	///
+	/// ```rust,compile_fail
+	/// use surety::{Fixed, Wrapping};
+	///
+	/// let _ = Fixed::<Wrapping<u8>, 9>::from_bits(1);
+	/// ```
+	pub fn from_bits(bits: W::Int) -> Self {
+		let () = Self::FRAC_FITS;
+		Self {
+			value: W::narrow(bits.widen()),
+		}
+	}
+
+	/// Creates a fixed-point number with an integer value, applying the
+	/// overflow policy if it does not fit.
+	pub fn from_int(value: W::Int) -> Self {
+		Self::from_bits(W::Int::default()).combine(
+			Ok(value),
+			|_, value| Ok(value << FRAC),
+		)
+	}
+
+	/// Gets the contained wrapper, holding the raw bits.
+	pub fn value(&self) -> W {
+		self.value
+	}
+
+	/// Removes the contained wrapper, holding the raw bits.
+	pub fn into_inner(self) -> W {
+		self.value
+	}
+
+	/// Applies an operation in the wide type, and narrows its result. `Err`
+	/// holds a result which the policy has already produced, such as for a
+	/// division by zero.
+	fn combine(
+		self,
+		rhs: Result<W::Int, W>,
+		op: impl FnOnce(Wide<W>, Wide<W>) -> Result<Wide<W>, W>,
+	) -> Self
+	{
+		let (lhs, rhs) = match (self.value.get(), rhs) {
+			(Ok(lhs), Ok(rhs)) => (lhs.widen(), rhs.widen()),
+			(Err(value), _) | (_, Err(value)) => return Self { value },
+		};
+		Self {
+			value: match op(lhs, rhs) {
+				Ok(wide) => W::narrow(wide),
+				Err(value) => value,
+			},
+		}
+	}
+}
+
+/** An overflow policy which can be applied to a [`Fixed`] number.
+
+This trait is implemented by `Checked`, `Saturating`, and `Wrapping`, over the
+integers that implement [`Widen`], and cannot be implemented outside this
+crate.
+
+[`Fixed`]: crate::Fixed
+[`Widen`]: crate::Widen
+**/
+pub trait FixedPolicy: RangePolicy {
+	/// Narrows a result from the wide type.
+	#[doc(hidden)]
+	fn narrow(wide: Wide<Self>) -> Self;
+
+	/// Produces the result of a division by zero.
+	#[doc(hidden)]
+	fn div_by_zero() -> Self;
+
+	/// Produces the result of a division whose quotient, `2^(2N-1)` for an
+	/// `N`-bit integer, overflows even the wide type.
+	#[doc(hidden)]
+	fn div_overflow() -> Self;
+}
+
+/// The wide type of a policy’s integer.
+type Wide<W> = <<W as RangePolicy>::Int as Widen>::Wide;
+
+impl<T: Widen> FixedPolicy for Checked<T> {
+	fn narrow(wide: T::Wide) -> Self {
+		T::narrow(wide).into()
+	}
+
+	fn div_by_zero() -> Self {
+		Self { value: None }
+	}
+
+	fn div_overflow() -> Self {
+		Self { value: None }
+	}
+}
+
+impl<T: Widen> FixedPolicy for Saturating<T> {
+	fn narrow(wide: T::Wide) -> Self {
+		T::narrow(wide)
+			.unwrap_or_else(|| {
+				if wide < T::Wide::default() {
+					T::min_value()
+				}
+				else {
+					T::max_value()
+				}
+			})
+			.into()
+	}
+
+	fn div_by_zero() -> Self {
+		panic!("attempt to divide by zero")
+	}
+
+	fn div_overflow() -> Self {
+		T::max_value().into()
+	}
+}
+
+impl<T: Widen> FixedPolicy for Wrapping<T> {
+	fn narrow(wide: T::Wide) -> Self {
+		T::truncate(wide).into()
+	}
+
+	fn div_by_zero() -> Self {
+		panic!("attempt to divide by zero")
+	}
+
+	fn div_overflow() -> Self {
+		//  `2^(2N-1)` is a multiple of `2^N`, so it truncates to zero.
+		T::default().into()
+	}
+}
+
+impl<W: FixedPolicy, const FRAC: u32> Add<Self> for Fixed<W, FRAC> {
+	type Output = Self;
+
+	fn add(self, rhs: Self) -> Self::Output {
+		self.combine(rhs.value.get(), |lhs, rhs| Ok(lhs + rhs))
+	}
+}
+
+impl<W: FixedPolicy, const FRAC: u32> Sub<Self> for Fixed<W, FRAC> {
+	type Output = Self;
+
+	fn sub(self, rhs: Self) -> Self::Output {
+		self.combine(rhs.value.get(), |lhs, rhs| Ok(lhs - rhs))
+	}
+}
+
+impl<W: FixedPolicy, const FRAC: u32> Mul<Self> for Fixed<W, FRAC> {
+	type Output = Self;
+
+	fn mul(self, rhs: Self) -> Self::Output {
+		self.combine(rhs.value.get(), |lhs, rhs| Ok((lhs * rhs) >> FRAC))
+	}
+}
+
+impl<W: FixedPolicy, const FRAC: u32> Div<Self> for Fixed<W, FRAC> {
+	type Output = Self;
+
+	fn div(self, rhs: Self) -> Self::Output {
+		self.combine(rhs.value.get(), |lhs, rhs| {
+			if rhs == Wide::<W>::default() {
+				return Err(W::div_by_zero());
+			}
+			//  When `FRAC` is the full width of a signed integer, `MIN / -1`
+			//  shifts the dividend to the wide `MIN`, and the quotient
+			//  overflows.
+			(lhs << FRAC).checked_div(rhs).ok_or_else(W::div_overflow)
+		})
+	}
+}
+
+macro_rules! op {
+	($($trait:ident $func:ident $assign:ident $assign_func:ident);+ $(;)?) => { $(
+		impl<W: FixedPolicy, const FRAC: u32> $trait<&Self> for Fixed<W, FRAC> {
+			type Output = Self;
+
+			fn $func(self, rhs: &Self) -> Self::Output {
+				$trait::$func(self, *rhs)
+			}
+		}
+
+		impl<W: FixedPolicy, const FRAC: u32> $assign<Self> for Fixed<W, FRAC> {
+			fn $assign_func(&mut self, rhs: Self) {
+				*self = $trait::$func(*self, rhs)
+			}
+		}
+
+		impl<W: FixedPolicy, const FRAC: u32> $assign<&Self> for Fixed<W, FRAC> {
+			fn $assign_func(&mut self, rhs: &Self) {
+				*self = $trait::$func(*self, *rhs)
+			}
+		}
+	)+ };
+}
+
+op! {
+	Add add AddAssign add_assign;
+	Sub sub SubAssign sub_assign;
+	Mul mul MulAssign mul_assign;
+	Div div DivAssign div_assign;
+}
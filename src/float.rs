@@ -0,0 +1,418 @@
+use core::{
+	cmp::Ordering,
+	iter::Sum,
+	ops::{
+		Add,
+		AddAssign,
+		Deref,
+		Div,
+		DivAssign,
+		Mul,
+		MulAssign,
+		Neg,
+		Rem,
+		RemAssign,
+		Sub,
+		SubAssign,
+	},
+};
+
+use funty::IsFloat;
+
+/** Marks a floating-point number for checked arithmetic.
+
+This extends the poisoning behavior of [`Checked`] to the floats: as soon as an
+operation produces NaN or an infinity, the number enters the poisoned state,
+and it remains there until it is reset to a fresh value. Constructing a
+`CheckedFloat` from a number which is not finite also poisons it.
+
+This type provides an `Option`-like API in addition to its arithmetic.
+
+# Examples
+
+```rust
+use surety::CheckedFloat;
+
+let num = CheckedFloat::new(1.0f64);
+assert_eq!(num / 4.0, Some(0.25));
+assert!((num / 0.0).is_none());
+assert!((num * 0.0 / 0.0).is_none());
+assert!((CheckedFloat::new(f32::MAX) * 2.0).is_none());
+assert!(CheckedFloat::new(f32::NAN).is_none());
+```
+
+[`Checked`]: crate::Checked
+**/
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct CheckedFloat<F: IsFloat> {
+	/// The contained number.
+	///
+	/// This is `Some` while every operation has produced a finite result. Once
+	/// a result is NaN or infinite, this is set to `None` until explicitly reset
+	/// to a fresh value.
+	value: Option<F>,
+}
+
+impl<F: IsFloat> CheckedFloat<F> {
+	/// Wraps a number for checked arithmetic. The result is poisoned if the
+	/// number is not finite.
+	pub fn new(value: F) -> Self {
+		Some(value).into()
+	}
+
+	/// Gets the contained number, if it is valid.
+	pub fn value(&self) -> Option<F> {
+		self.value
+	}
+
+	/// Unwraps the contained number, if it is valid.
+	pub fn into_inner(self) -> Option<F> {
+		self.value
+	}
+
+	/// Replaces the value in place with the result of a function applied to
+	/// it.
+	pub fn update(&mut self, func: impl FnOnce(Self) -> Self) {
+		*self = func(*self);
+	}
+
+	/// Replaces the value in place with the result of a function applied to
+	/// it, only if the result is valid.
+	///
+	/// Returns whether the new value was stored.
+	pub fn try_update(&mut self, func: impl FnOnce(Self) -> Self) -> bool {
+		let next = func(*self);
+		if next.is_some() {
+			*self = next;
+		}
+		next.is_some()
+	}
+
+	/// Tests if the number is valid.
+	pub fn is_some(&self) -> bool {
+		self.value.is_some()
+	}
+
+	/// Tests if the number has been poisoned.
+	pub fn is_none(&self) -> bool {
+		self.value.is_none()
+	}
+
+	/// Borrows the number, if valid.
+	pub fn as_ref(&self) -> Option<&F> {
+		self.value.as_ref()
+	}
+
+	/// Unwraps the bare number, panicking with `msg` if absent.
+	pub fn expect(self, msg: &str) -> F {
+		self.value.expect(msg)
+	}
+
+	/// Unwraps the bare number, panicking if absent.
+	pub fn unwrap(self) -> F {
+		self.value.unwrap()
+	}
+
+	/// Unwraps the bare number, substituting a default value if absent.
+	pub fn unwrap_or(self, default: F) -> F {
+		self.value.unwrap_or(default)
+	}
+
+	/// Unwraps the bare number, or computes a default value if absent.
+	pub fn unwrap_or_else(self, func: impl FnOnce() -> F) -> F {
+		self.value.unwrap_or_else(func)
+	}
+
+	/// Transforms the number, if valid. The result is poisoned if the function
+	/// produces a number that is not finite.
+	pub fn map<U: IsFloat>(self, func: impl FnOnce(F) -> U) -> CheckedFloat<U> {
+		self.value.map(func).into()
+	}
+
+	/// Transforms the `CheckedFloat<F>` into a `Result<F, E>`, producing
+	/// `Ok(num)` if the number is valid and `Err(err)` if it is not.
+	pub fn ok_or<E>(self, err: E) -> Result<F, E> {
+		self.value.ok_or(err)
+	}
+
+	/// Transforms the `CheckedFloat<F>` into a `Result<F, E>`, producing
+	/// `Ok(num)` if the number is valid and computing `Err(func())` if it is
+	/// not.
+	pub fn ok_or_else<E>(self, func: impl FnOnce() -> E) -> Result<F, E> {
+		self.value.ok_or_else(func)
+	}
+
+	/// Passes the number into a new fallible computation, if valid.
+	pub fn and_then<U: IsFloat>(
+		self,
+		func: impl FnOnce(F) -> Option<U>,
+	) -> CheckedFloat<U>
+	{
+		self.value.and_then(func).into()
+	}
+
+	/// Poisons the number if it fails a test.
+	pub fn filter(self, func: impl FnOnce(&F) -> bool) -> Self {
+		self.value.filter(func).into()
+	}
+
+	/// If the number is poisoned, replaces it with another.
+	pub fn or(self, other: Self) -> Self {
+		if self.is_some() { self } else { other }
+	}
+
+	/// Takes the number, leaving a poisoned `CheckedFloat` in its place.
+	pub fn take(&mut self) -> Self {
+		Self {
+			value: self.value.take(),
+		}
+	}
+
+	/// Applies an operation to two numbers, and poisons its result if it is
+	/// not finite.
+	fn combine(self, rhs: Self, func: impl FnOnce(F, F) -> F) -> Self {
+		self.and_then(|lhs| Some(func(lhs, rhs.value?)))
+	}
+}
+
+impl<F: IsFloat> PartialEq<Option<F>> for CheckedFloat<F> {
+	fn eq(&self, other: &Option<F>) -> bool {
+		self.value.eq(other)
+	}
+}
+
+impl<F: IsFloat> PartialOrd<Option<F>> for CheckedFloat<F> {
+	fn partial_cmp(&self, other: &Option<F>) -> Option<Ordering> {
+		self.value.partial_cmp(other)
+	}
+}
+
+impl<F: IsFloat> From<F> for CheckedFloat<F> {
+	fn from(value: F) -> Self {
+		Self::new(value)
+	}
+}
+
+impl<F: IsFloat> From<Option<F>> for CheckedFloat<F> {
+	fn from(value: Option<F>) -> Self {
+		Self {
+			value: value.filter(|value| value.is_finite()),
+		}
+	}
+}
+
+impl<F: IsFloat> Sum<Self> for CheckedFloat<F> {
+	fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+		iter.fold(F::default().into(), |acc, next| acc + next)
+	}
+}
+
+impl<'a, F: 'a + IsFloat> Sum<&'a Self> for CheckedFloat<F> {
+	fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+		iter.copied().sum()
+	}
+}
+
+impl<F: IsFloat + Neg<Output = F>> Neg for CheckedFloat<F> {
+	type Output = Self;
+
+	fn neg(self) -> Self::Output {
+		Self {
+			value: self.value.map(Neg::neg),
+		}
+	}
+}
+
+/** Marks a floating-point number for saturating arithmetic.
+
+Where the primitive floats overflow to infinity, a `SaturatingFloat` clamps the
+result of every operation to the finite range `MIN ..= MAX`, so that an
+infinity cannot arise and propagate through later computations. Division by
+zero therefore produces `MAX` or `MIN`, according to the signs of its operands.
+
+NaN has no direction in which to clamp, and is not altered. Operations which
+produce NaN from finite operands, such as `0.0 / 0.0`, continue to do so.
+
+# Examples
+
+```rust
+use surety::SaturatingFloat;
+
+let big = SaturatingFloat::new(f32::MAX);
+assert_eq!(big * 2.0, f32::MAX);
+assert_eq!(-big * 2.0, f32::MIN);
+assert_eq!(SaturatingFloat::new(1.0f64) / 0.0, f64::MAX);
+assert_eq!(SaturatingFloat::new(f64::NEG_INFINITY), f64::MIN);
+```
+**/
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct SaturatingFloat<F: IsFloat> {
+	/// The contained number, which is never infinite.
+	value: F,
+}
+
+impl<F: IsFloat> SaturatingFloat<F> {
+	/// Wraps a number for saturating arithmetic, clamping an infinity to the
+	/// finite value of the same sign.
+	pub fn new(value: F) -> Self {
+		let value = if value == F::INFINITY {
+			F::MAX
+		}
+		else if value == F::NEG_INFINITY {
+			F::MIN
+		}
+		else {
+			value
+		};
+		Self { value }
+	}
+
+	/// Gets the contained number.
+	pub fn value(&self) -> F {
+		self.value
+	}
+
+	/// Unwraps the contained number.
+	pub fn into_inner(self) -> F {
+		self.value
+	}
+
+	/// Replaces the value in place with the result of a function applied to
+	/// it.
+	pub fn update(&mut self, func: impl FnOnce(Self) -> Self) {
+		*self = func(*self);
+	}
+
+	/// Applies an operation to two numbers, and clamps its result.
+	fn combine(self, rhs: Self, func: impl FnOnce(F, F) -> F) -> Self {
+		Self::new(func(self.value, rhs.value))
+	}
+}
+
+impl<F: IsFloat> PartialEq<F> for SaturatingFloat<F> {
+	fn eq(&self, other: &F) -> bool {
+		self.value.eq(other)
+	}
+}
+
+impl<F: IsFloat> PartialOrd<F> for SaturatingFloat<F> {
+	fn partial_cmp(&self, other: &F) -> Option<Ordering> {
+		self.value.partial_cmp(other)
+	}
+}
+
+impl<F: IsFloat> AsRef<F> for SaturatingFloat<F> {
+	fn as_ref(&self) -> &F {
+		&self.value
+	}
+}
+
+impl<F: IsFloat> Deref for SaturatingFloat<F> {
+	type Target = F;
+
+	fn deref(&self) -> &F {
+		&self.value
+	}
+}
+
+impl<F: IsFloat> From<F> for SaturatingFloat<F> {
+	fn from(value: F) -> Self {
+		Self::new(value)
+	}
+}
+
+impl<F: IsFloat> Sum<Self> for SaturatingFloat<F> {
+	fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+		iter.fold(Self::default(), |acc, next| acc + next)
+	}
+}
+
+impl<'a, F: 'a + IsFloat> Sum<&'a Self> for SaturatingFloat<F> {
+	fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+		iter.copied().sum()
+	}
+}
+
+impl<F: IsFloat + Neg<Output = F>> Neg for SaturatingFloat<F> {
+	type Output = Self;
+
+	fn neg(self) -> Self::Output {
+		//  The finite range is symmetric, so this cannot leave it.
+		Self { value: -self.value }
+	}
+}
+
+macro_rules! op {
+	($($trait:ident $func:ident $assign:ident $assign_func:ident);+ $(;)?) => {
+		op!(@ CheckedFloat $($trait $func $assign $assign_func;)+);
+		op!(@ SaturatingFloat $($trait $func $assign $assign_func;)+);
+	};
+
+	(@ $w:ident $($trait:ident $func:ident $assign:ident $assign_func:ident;)+) => { $(
+		impl<F: IsFloat> $trait<Self> for $w<F> {
+			type Output = Self;
+
+			fn $func(self, rhs: Self) -> Self::Output {
+				self.combine(rhs, $trait::$func)
+			}
+		}
+
+		impl<F: IsFloat> $trait<&Self> for $w<F> {
+			type Output = Self;
+
+			fn $func(self, rhs: &Self) -> Self::Output {
+				$trait::$func(self, *rhs)
+			}
+		}
+
+		impl<F: IsFloat> $trait<F> for $w<F> {
+			type Output = Self;
+
+			fn $func(self, rhs: F) -> Self::Output {
+				$trait::$func(self, Self::from(rhs))
+			}
+		}
+
+		impl<F: IsFloat> $trait<&F> for $w<F> {
+			type Output = Self;
+
+			fn $func(self, rhs: &F) -> Self::Output {
+				$trait::$func(self, *rhs)
+			}
+		}
+
+		impl<F: IsFloat> $assign<Self> for $w<F> {
+			fn $assign_func(&mut self, rhs: Self) {
+				*self = $trait::$func(*self, rhs)
+			}
+		}
+
+		impl<F: IsFloat> $assign<&Self> for $w<F> {
+			fn $assign_func(&mut self, rhs: &Self) {
+				*self = $trait::$func(*self, rhs)
+			}
+		}
+
+		impl<F: IsFloat> $assign<F> for $w<F> {
+			fn $assign_func(&mut self, rhs: F) {
+				*self = $trait::$func(*self, rhs)
+			}
+		}
+
+		impl<F: IsFloat> $assign<&F> for $w<F> {
+			fn $assign_func(&mut self, rhs: &F) {
+				*self = $trait::$func(*self, rhs)
+			}
+		}
+	)+ };
+}
+
+op! {
+	Add add AddAssign add_assign;
+	Sub sub SubAssign sub_assign;
+	Mul mul MulAssign mul_assign;
+	Div div DivAssign div_assign;
+	Rem rem RemAssign rem_assign;
+}
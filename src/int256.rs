@@ -0,0 +1,1288 @@
+use core::{
+	cmp::Ordering,
+	convert::{
+		TryFrom,
+		TryInto,
+	},
+	fmt::{
+		self,
+		Binary,
+		Debug,
+		Display,
+		Formatter,
+		LowerHex,
+		Octal,
+		UpperHex,
+	},
+	iter::{
+		Product,
+		Sum,
+	},
+	num::{
+		ParseIntError,
+		TryFromIntError,
+	},
+	ops::{
+		Add,
+		AddAssign,
+		BitAnd,
+		BitAndAssign,
+		BitOr,
+		BitOrAssign,
+		BitXor,
+		BitXorAssign,
+		Div,
+		DivAssign,
+		Mul,
+		MulAssign,
+		Neg,
+		Not,
+		Rem,
+		RemAssign,
+		Shl,
+		ShlAssign,
+		Shr,
+		ShrAssign,
+		Sub,
+		SubAssign,
+	},
+	str::{
+		self,
+		FromStr,
+	},
+};
+
+use funty::{
+	IsInteger,
+	IsNumber,
+	IsSigned,
+	IsUnsigned,
+};
+
+use crate::limbs;
+
+/** The 256-bit unsigned integer.
+
+This type implements the same integer interface as the fundamental integers,
+so it can be used in any of the wrapper types: `Checked<u256>`,
+`Wrapping<u256>`, and so on. Its operators behave as the fundamentals’ do:
+overflow panics when debug assertions are enabled, and wraps otherwise.
+
+The arithmetic methods are provided by the `funty::IsInteger` and
+`funty::IsUnsigned` traits.
+
+# Examples
+
+```rust
+use surety::{u256, Checked, Ensure};
+
+let big = u256::from(u128::max_value()) * u256::from(u128::max_value());
+assert_eq!(
+    big.to_string(),
+    "115792089237316195423570985008687907852589419931798687112530834793049593217025",
+);
+
+let one = u256::from(1u8);
+assert!((u256::MAX.checked() + one).is_none());
+assert_eq!(u256::MAX.wrapping() + one, u256::ZERO);
+```
+**/
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+pub struct u256 {
+	/// The 64-bit words of the number, least significant first.
+	limbs: Limbs,
+}
+
+/** The 256-bit signed integer.
+
+This type implements the same integer interface as the fundamental integers,
+so it can be used in any of the wrapper types: `Checked<i256>`,
+`Wrapping<i256>`, and so on. Its operators behave as the fundamentals’ do:
+overflow panics when debug assertions are enabled, and wraps otherwise.
+
+The arithmetic methods are provided by the `funty::IsInteger` and
+`funty::IsSigned` traits.
+
+# Examples
+
+```rust
+use surety::{i256, Ensure, Saturating};
+
+let min = i256::from(i128::min_value());
+assert_eq!(
+    (min * min).to_string(),
+    "28948022309329048855892746252171976963317496166410141009864396001978282409984",
+);
+
+let floor = (i256::MIN + i256::from(1)).saturating() - i256::from(2);
+assert_eq!(floor, i256::MIN);
+```
+**/
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+pub struct i256 {
+	/// The 64-bit words of the two’s-complement number, least significant
+	/// first.
+	limbs: Limbs,
+}
+
+/// The 64-bit words of a 256-bit number, least significant first.
+type Limbs = [u64; 4];
+
+/// The number of bits in a 256-bit number.
+const BITS: u32 = 256;
+
+macro_rules! int256 {
+	($($t:ident => $signed:expr, $doc:literal);+ $(;)?) => { $(
+		impl $t {
+			/// The smallest value of this type.
+			pub const MIN: Self = Self {
+				limbs: if $signed { [0, 0, 0, 1 << 63] } else { [0; 4] },
+			};
+
+			/// The largest value of this type.
+			pub const MAX: Self = Self {
+				limbs: if $signed {
+					[!0, !0, !0, !0 >> 1]
+				}
+				else {
+					[!0; 4]
+				},
+			};
+
+			/// The value zero.
+			pub const ZERO: Self = Self { limbs: [0; 4] };
+
+			/// The value one.
+			const ONE: Self = Self { limbs: [1, 0, 0, 0] };
+
+			/// Whether this type is signed.
+			const SIGNED: bool = $signed;
+
+			#[doc = $doc]
+			pub const fn from_limbs(limbs: [u64; 4]) -> Self {
+				Self { limbs }
+			}
+
+			/// Converts the number into its 64-bit words, least significant
+			/// first.
+			pub const fn to_limbs(self) -> [u64; 4] {
+				self.limbs
+			}
+
+			/// Tests if the number is below zero.
+			fn is_neg(self) -> bool {
+				Self::SIGNED && self.limbs[3] >> 63 == 1
+			}
+
+			/// Splits the number into its sign and its magnitude.
+			fn magnitude(self) -> (bool, Limbs) {
+				if self.is_neg() {
+					(true, neg(self.limbs))
+				}
+				else {
+					(false, self.limbs)
+				}
+			}
+
+			/// Builds a number from a sign and a magnitude, wrapping if it
+			/// does not fit.
+			fn from_magnitude(negative: bool, limbs: Limbs) -> Self {
+				Self {
+					limbs: if negative { neg(limbs) } else { limbs },
+				}
+			}
+
+			/// Divides, truncating towards zero. The divisor must not be zero,
+			/// and the overflowing case wraps.
+			fn div_rem(self, rhs: Self) -> (Self, Self) {
+				let (lneg, lmag) = self.magnitude();
+				let (rneg, rmag) = rhs.magnitude();
+				let (quot, rem) = div_rem(lmag, rmag);
+				(
+					Self::from_magnitude(lneg != rneg, quot),
+					Self::from_magnitude(lneg, rem),
+				)
+			}
+
+			/// Parses a number, as the fundamental integers do.
+			fn parse(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+				assert!(
+					(2 ..= 36).contains(&radix),
+					"from_str_radix_int: must lie in the range `[2, 36]` - \
+					 found {}",
+					radix,
+				);
+				let (negative, digits) = match src.as_bytes().first() {
+					None => return Err(errors::empty()),
+					Some(b'+') => (false, &src[1 ..]),
+					Some(b'-') if Self::SIGNED => (true, &src[1 ..]),
+					Some(_) => (false, src),
+				};
+				if digits.is_empty() {
+					return Err(errors::invalid_digit());
+				}
+				let limit = if negative {
+					neg(Self::MIN.limbs)
+				}
+				else {
+					Self::MAX.limbs
+				};
+				let mut mag = [0; 4];
+				for ch in digits.chars() {
+					let digit =
+						ch.to_digit(radix).ok_or_else(errors::invalid_digit)?;
+					let (next, carry) = mul_add_small(mag, radix as u64, digit as u64);
+					if carry || cmp(next, limit) == Ordering::Greater {
+						return Err(if negative {
+							errors::neg_overflow()
+						}
+						else {
+							errors::pos_overflow()
+						});
+					}
+					mag = next;
+				}
+				Ok(Self::from_magnitude(negative, mag))
+			}
+		}
+
+		impl PartialOrd for $t {
+			fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+				Some(self.cmp(other))
+			}
+		}
+
+		impl Ord for $t {
+			fn cmp(&self, other: &Self) -> Ordering {
+				match (self.is_neg(), other.is_neg()) {
+					(true, false) => Ordering::Less,
+					(false, true) => Ordering::Greater,
+					_ => cmp(self.limbs, other.limbs),
+				}
+			}
+		}
+
+		impl Debug for $t {
+			fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+				Display::fmt(self, fmt)
+			}
+		}
+
+		impl Display for $t {
+			fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+				let (negative, mag) = self.magnitude();
+				format(fmt, !negative, mag, 10, false, "")
+			}
+		}
+
+		impl Binary for $t {
+			fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+				format(fmt, true, self.limbs, 2, false, "0b")
+			}
+		}
+
+		impl Octal for $t {
+			fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+				format(fmt, true, self.limbs, 8, false, "0o")
+			}
+		}
+
+		impl LowerHex for $t {
+			fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+				format(fmt, true, self.limbs, 16, false, "0x")
+			}
+		}
+
+		impl UpperHex for $t {
+			fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+				format(fmt, true, self.limbs, 16, true, "0x")
+			}
+		}
+
+		impl FromStr for $t {
+			type Err = ParseIntError;
+
+			fn from_str(src: &str) -> Result<Self, Self::Err> {
+				Self::parse(src, 10)
+			}
+		}
+
+		impl IsNumber for $t {
+			type Bytes = [u8; 32];
+
+			fn to_be_bytes(self) -> Self::Bytes {
+				let mut out = self.to_le_bytes();
+				out.reverse();
+				out
+			}
+
+			fn to_le_bytes(self) -> Self::Bytes {
+				let mut out = [0; 32];
+				for (chunk, limb) in out.chunks_mut(8).zip(self.limbs.iter()) {
+					chunk.copy_from_slice(&limb.to_le_bytes());
+				}
+				out
+			}
+
+			fn to_ne_bytes(self) -> Self::Bytes {
+				if cfg!(target_endian = "big") {
+					self.to_be_bytes()
+				}
+				else {
+					self.to_le_bytes()
+				}
+			}
+
+			fn from_be_bytes(mut bytes: Self::Bytes) -> Self {
+				bytes.reverse();
+				Self::from_le_bytes(bytes)
+			}
+
+			fn from_le_bytes(bytes: Self::Bytes) -> Self {
+				let mut limbs = [0; 4];
+				for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks(8)) {
+					let mut word = [0; 8];
+					word.copy_from_slice(chunk);
+					*limb = u64::from_le_bytes(word);
+				}
+				Self { limbs }
+			}
+
+			fn from_ne_bytes(bytes: Self::Bytes) -> Self {
+				if cfg!(target_endian = "big") {
+					Self::from_be_bytes(bytes)
+				}
+				else {
+					Self::from_le_bytes(bytes)
+				}
+			}
+		}
+
+		impl IsInteger for $t {
+			const ZERO: Self = Self::ZERO;
+			const MIN: Self = Self::MIN;
+			const MAX: Self = Self::MAX;
+
+			fn min_value() -> Self {
+				Self::MIN
+			}
+
+			fn max_value() -> Self {
+				Self::MAX
+			}
+
+			fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+				Self::parse(src, radix)
+			}
+
+			fn count_ones(self) -> u32 {
+				self.limbs.iter().map(|limb| limb.count_ones()).sum()
+			}
+
+			fn count_zeros(self) -> u32 {
+				BITS - self.count_ones()
+			}
+
+			fn leading_zeros(self) -> u32 {
+				leading_zeros(self.limbs)
+			}
+
+			fn trailing_zeros(self) -> u32 {
+				let mut out = 0;
+				for limb in self.limbs.iter() {
+					out += limb.trailing_zeros();
+					if *limb != 0 {
+						break;
+					}
+				}
+				out
+			}
+
+			fn leading_ones(self) -> u32 {
+				(!self).leading_zeros()
+			}
+
+			fn trailing_ones(self) -> u32 {
+				(!self).trailing_zeros()
+			}
+
+			fn rotate_left(self, n: u32) -> Self {
+				let n = n % BITS;
+				if n == 0 {
+					return self;
+				}
+				Self {
+					limbs: or(
+						limbs::wrapping_shl(self.limbs, n),
+						limbs::wrapping_shr(self.limbs, BITS - n),
+					),
+				}
+			}
+
+			fn rotate_right(self, n: u32) -> Self {
+				self.rotate_left(BITS - n % BITS)
+			}
+
+			fn swap_bytes(self) -> Self {
+				Self::from_le_bytes(self.to_be_bytes())
+			}
+
+			fn reverse_bits(self) -> Self {
+				let [a, b, c, d] = self.limbs;
+				Self {
+					limbs: [
+						d.reverse_bits(),
+						c.reverse_bits(),
+						b.reverse_bits(),
+						a.reverse_bits(),
+					],
+				}
+			}
+
+			fn from_be(self) -> Self {
+				self.to_be()
+			}
+
+			fn from_le(self) -> Self {
+				self.to_le()
+			}
+
+			fn to_be(self) -> Self {
+				if cfg!(target_endian = "big") {
+					self
+				}
+				else {
+					self.swap_bytes()
+				}
+			}
+
+			fn to_le(self) -> Self {
+				if cfg!(target_endian = "big") {
+					self.swap_bytes()
+				}
+				else {
+					self
+				}
+			}
+
+			fn checked_add(self, rhs: Self) -> Option<Self> {
+				checked(self.overflowing_add(rhs))
+			}
+
+			fn checked_sub(self, rhs: Self) -> Option<Self> {
+				checked(self.overflowing_sub(rhs))
+			}
+
+			fn checked_mul(self, rhs: Self) -> Option<Self> {
+				checked(self.overflowing_mul(rhs))
+			}
+
+			fn checked_div(self, rhs: Self) -> Option<Self> {
+				if rhs == Self::ZERO {
+					return None;
+				}
+				checked(self.overflowing_div(rhs))
+			}
+
+			fn checked_div_euclid(self, rhs: Self) -> Option<Self> {
+				if rhs == Self::ZERO {
+					return None;
+				}
+				checked(self.overflowing_div_euclid(rhs))
+			}
+
+			fn checked_rem(self, rhs: Self) -> Option<Self> {
+				if rhs == Self::ZERO {
+					return None;
+				}
+				checked(self.overflowing_rem(rhs))
+			}
+
+			fn checked_rem_euclid(self, rhs: Self) -> Option<Self> {
+				if rhs == Self::ZERO {
+					return None;
+				}
+				checked(self.overflowing_rem_euclid(rhs))
+			}
+
+			fn checked_neg(self) -> Option<Self> {
+				checked(self.overflowing_neg())
+			}
+
+			fn checked_shl(self, rhs: u32) -> Option<Self> {
+				checked(self.overflowing_shl(rhs))
+			}
+
+			fn checked_shr(self, rhs: u32) -> Option<Self> {
+				checked(self.overflowing_shr(rhs))
+			}
+
+			fn checked_pow(self, rhs: u32) -> Option<Self> {
+				checked(self.overflowing_pow(rhs))
+			}
+
+			fn saturating_add(self, rhs: Self) -> Self {
+				self.checked_add(rhs).unwrap_or_else(|| {
+					if rhs.is_neg() { Self::MIN } else { Self::MAX }
+				})
+			}
+
+			fn saturating_sub(self, rhs: Self) -> Self {
+				self.checked_sub(rhs).unwrap_or_else(|| {
+					if Self::SIGNED && rhs.is_neg() {
+						Self::MAX
+					}
+					else {
+						Self::MIN
+					}
+				})
+			}
+
+			fn saturating_mul(self, rhs: Self) -> Self {
+				self.checked_mul(rhs).unwrap_or_else(|| {
+					if self.is_neg() != rhs.is_neg() {
+						Self::MIN
+					}
+					else {
+						Self::MAX
+					}
+				})
+			}
+
+			fn saturating_pow(self, rhs: u32) -> Self {
+				self.checked_pow(rhs).unwrap_or_else(|| {
+					if self.is_neg() && rhs % 2 == 1 {
+						Self::MIN
+					}
+					else {
+						Self::MAX
+					}
+				})
+			}
+
+			fn wrapping_add(self, rhs: Self) -> Self {
+				self.overflowing_add(rhs).0
+			}
+
+			fn wrapping_sub(self, rhs: Self) -> Self {
+				self.overflowing_sub(rhs).0
+			}
+
+			fn wrapping_mul(self, rhs: Self) -> Self {
+				self.overflowing_mul(rhs).0
+			}
+
+			fn wrapping_div(self, rhs: Self) -> Self {
+				self.overflowing_div(rhs).0
+			}
+
+			fn wrapping_div_euclid(self, rhs: Self) -> Self {
+				self.overflowing_div_euclid(rhs).0
+			}
+
+			fn wrapping_rem(self, rhs: Self) -> Self {
+				self.overflowing_rem(rhs).0
+			}
+
+			fn wrapping_rem_euclid(self, rhs: Self) -> Self {
+				self.overflowing_rem_euclid(rhs).0
+			}
+
+			fn wrapping_neg(self) -> Self {
+				self.overflowing_neg().0
+			}
+
+			fn wrapping_shl(self, rhs: u32) -> Self {
+				self.overflowing_shl(rhs).0
+			}
+
+			fn wrapping_shr(self, rhs: u32) -> Self {
+				self.overflowing_shr(rhs).0
+			}
+
+			fn wrapping_pow(self, rhs: u32) -> Self {
+				self.overflowing_pow(rhs).0
+			}
+
+			fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+				let (limbs, carry) = limbs::overflowing_add(self.limbs, rhs.limbs);
+				let out = Self { limbs };
+				if Self::SIGNED {
+					let ovf = self.is_neg() == rhs.is_neg()
+						&& out.is_neg() != self.is_neg();
+					(out, ovf)
+				}
+				else {
+					(out, carry)
+				}
+			}
+
+			fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+				let (limbs, borrow) = limbs::overflowing_sub(self.limbs, rhs.limbs);
+				let out = Self { limbs };
+				if Self::SIGNED {
+					let ovf = self.is_neg() != rhs.is_neg()
+						&& out.is_neg() != self.is_neg();
+					(out, ovf)
+				}
+				else {
+					(out, borrow)
+				}
+			}
+
+			fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+				let out = Self {
+					limbs: limbs::overflowing_mul(self.limbs, rhs.limbs).0,
+				};
+				let (lneg, lmag) = self.magnitude();
+				let (rneg, rmag) = rhs.magnitude();
+				let (mag, carry) = limbs::overflowing_mul(lmag, rmag);
+				let limit = if lneg != rneg {
+					neg(Self::MIN.limbs)
+				}
+				else {
+					Self::MAX.limbs
+				};
+				(out, carry || cmp(mag, limit) == Ordering::Greater)
+			}
+
+			fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+				if rhs == Self::ZERO {
+					panic!("attempt to divide by zero");
+				}
+				let ovf = self.is_neg() && self == Self::MIN && rhs == !Self::ZERO;
+				(self.div_rem(rhs).0, ovf)
+			}
+
+			fn overflowing_div_euclid(self, rhs: Self) -> (Self, bool) {
+				let (quot, ovf) = self.overflowing_div(rhs);
+				let rem = self.div_rem(rhs).1;
+				if !rem.is_neg() {
+					(quot, ovf)
+				}
+				else if rhs.is_neg() {
+					(quot.wrapping_add(Self::ONE), ovf)
+				}
+				else {
+					(quot.wrapping_sub(Self::ONE), ovf)
+				}
+			}
+
+			fn overflowing_rem(self, rhs: Self) -> (Self, bool) {
+				if rhs == Self::ZERO {
+					panic!(
+						"attempt to calculate the remainder with a divisor of \
+						 zero"
+					);
+				}
+				let ovf = self.is_neg() && self == Self::MIN && rhs == !Self::ZERO;
+				(self.div_rem(rhs).1, ovf)
+			}
+
+			fn overflowing_rem_euclid(self, rhs: Self) -> (Self, bool) {
+				let (rem, ovf) = self.overflowing_rem(rhs);
+				if !rem.is_neg() {
+					(rem, ovf)
+				}
+				else if rhs.is_neg() {
+					(rem.wrapping_sub(rhs), ovf)
+				}
+				else {
+					(rem.wrapping_add(rhs), ovf)
+				}
+			}
+
+			fn overflowing_neg(self) -> (Self, bool) {
+				let out = Self {
+					limbs: neg(self.limbs),
+				};
+				if Self::SIGNED {
+					(out, self == Self::MIN)
+				}
+				else {
+					(out, self != Self::ZERO)
+				}
+			}
+
+			fn overflowing_shl(self, rhs: u32) -> (Self, bool) {
+				let (limbs, ovf) = limbs::overflowing_shl(self.limbs, rhs);
+				(Self { limbs }, ovf)
+			}
+
+			fn overflowing_shr(self, rhs: u32) -> (Self, bool) {
+				if self.is_neg() {
+					let (limbs, ovf) = limbs::overflowing_shr(not(self.limbs), rhs);
+					(Self { limbs: not(limbs) }, ovf)
+				}
+				else {
+					let (limbs, ovf) = limbs::overflowing_shr(self.limbs, rhs);
+					(Self { limbs }, ovf)
+				}
+			}
+
+			fn overflowing_pow(self, mut exp: u32) -> (Self, bool) {
+				if exp == 0 {
+					return (Self::ONE, false);
+				}
+				let (mut base, mut acc, mut ovf) = (self, Self::ONE, false);
+				while exp > 1 {
+					if exp & 1 == 1 {
+						let (next, o) = acc.overflowing_mul(base);
+						acc = next;
+						ovf |= o;
+					}
+					exp /= 2;
+					let (next, o) = base.overflowing_mul(base);
+					base = next;
+					ovf |= o;
+				}
+				let (out, o) = acc.overflowing_mul(base);
+				(out, ovf | o)
+			}
+
+			fn pow(self, rhs: u32) -> Self {
+				debug(self.overflowing_pow(rhs), "attempt to multiply with overflow")
+			}
+
+			fn div_euclid(self, rhs: Self) -> Self {
+				let (out, ovf) = self.overflowing_div_euclid(rhs);
+				assert!(!ovf, "attempt to divide with overflow");
+				out
+			}
+
+			fn rem_euclid(self, rhs: Self) -> Self {
+				let (out, ovf) = self.overflowing_rem_euclid(rhs);
+				assert!(!ovf, "attempt to calculate the remainder with overflow");
+				out
+			}
+		}
+
+		impl Not for $t {
+			type Output = Self;
+
+			fn not(self) -> Self::Output {
+				Self {
+					limbs: not(self.limbs),
+				}
+			}
+		}
+
+		impl Sum<Self> for $t {
+			fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+				iter.fold(Self::ZERO, |a, b| a + b)
+			}
+		}
+
+		impl<'a> Sum<&'a Self> for $t {
+			fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+				iter.fold(Self::ZERO, |a, b| a + b)
+			}
+		}
+
+		impl Product<Self> for $t {
+			fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+				iter.fold(Self::ONE, |a, b| a * b)
+			}
+		}
+
+		impl<'a> Product<&'a Self> for $t {
+			fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+				iter.fold(Self::ONE, |a, b| a * b)
+			}
+		}
+
+		op!($t;
+			Add add AddAssign add_assign => |a: $t, b| {
+				debug(a.overflowing_add(b), "attempt to add with overflow")
+			};
+			Sub sub SubAssign sub_assign => |a: $t, b| {
+				debug(a.overflowing_sub(b), "attempt to subtract with overflow")
+			};
+			Mul mul MulAssign mul_assign => |a: $t, b| {
+				debug(a.overflowing_mul(b), "attempt to multiply with overflow")
+			};
+			Div div DivAssign div_assign => |a: $t, b| {
+				let (out, ovf) = a.overflowing_div(b);
+				assert!(!ovf, "attempt to divide with overflow");
+				out
+			};
+			Rem rem RemAssign rem_assign => |a: $t, b| {
+				let (out, ovf) = a.overflowing_rem(b);
+				assert!(!ovf, "attempt to calculate the remainder with overflow");
+				out
+			};
+			BitAnd bitand BitAndAssign bitand_assign => |a: $t, b: $t| {
+				$t { limbs: and(a.limbs, b.limbs) }
+			};
+			BitOr bitor BitOrAssign bitor_assign => |a: $t, b: $t| {
+				$t { limbs: or(a.limbs, b.limbs) }
+			};
+			BitXor bitxor BitXorAssign bitxor_assign => |a: $t, b: $t| {
+				$t { limbs: xor(a.limbs, b.limbs) }
+			};
+		);
+
+		shift!($t => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+	)+ };
+}
+
+/// Implements an operator trait, and its assigning form, for a 256-bit integer
+/// on both values and references.
+macro_rules! op {
+	($t:ident; $($trait:ident $func:ident $assign:ident $assign_func:ident => $body:expr;)+) => { $(
+		impl $trait<Self> for $t {
+			type Output = Self;
+
+			fn $func(self, rhs: Self) -> Self::Output {
+				($body)(self, rhs)
+			}
+		}
+
+		impl $trait<&Self> for $t {
+			type Output = Self;
+
+			fn $func(self, rhs: &Self) -> Self::Output {
+				($body)(self, *rhs)
+			}
+		}
+
+		impl $assign<Self> for $t {
+			fn $assign_func(&mut self, rhs: Self) {
+				*self = $trait::$func(*self, rhs);
+			}
+		}
+
+		impl $assign<&Self> for $t {
+			fn $assign_func(&mut self, rhs: &Self) {
+				*self = $trait::$func(*self, *rhs);
+			}
+		}
+	)+ };
+}
+
+/// Implements the shift operators for a 256-bit integer, with a shift amount
+/// of each fundamental integer.
+macro_rules! shift {
+	($t:ident => $($s:ty),+ $(,)?) => { $(
+		impl Shl<$s> for $t {
+			type Output = Self;
+
+			fn shl(self, rhs: $s) -> Self::Output {
+				let (out, _) = self.overflowing_shl(rhs as u32);
+				debug((out, too_far(rhs)), "attempt to shift left with overflow")
+			}
+		}
+
+		impl Shl<&$s> for $t {
+			type Output = Self;
+
+			fn shl(self, rhs: &$s) -> Self::Output {
+				self << *rhs
+			}
+		}
+
+		impl ShlAssign<$s> for $t {
+			fn shl_assign(&mut self, rhs: $s) {
+				*self = *self << rhs;
+			}
+		}
+
+		impl ShlAssign<&$s> for $t {
+			fn shl_assign(&mut self, rhs: &$s) {
+				*self = *self << *rhs;
+			}
+		}
+
+		impl Shr<$s> for $t {
+			type Output = Self;
+
+			fn shr(self, rhs: $s) -> Self::Output {
+				let (out, _) = self.overflowing_shr(rhs as u32);
+				debug((out, too_far(rhs)), "attempt to shift right with overflow")
+			}
+		}
+
+		impl Shr<&$s> for $t {
+			type Output = Self;
+
+			fn shr(self, rhs: &$s) -> Self::Output {
+				self >> *rhs
+			}
+		}
+
+		impl ShrAssign<$s> for $t {
+			fn shr_assign(&mut self, rhs: $s) {
+				*self = *self >> rhs;
+			}
+		}
+
+		impl ShrAssign<&$s> for $t {
+			fn shr_assign(&mut self, rhs: &$s) {
+				*self = *self >> *rhs;
+			}
+		}
+	)+ };
+}
+
+int256! {
+	u256 => false, "Creates a number from its 64-bit words, least significant first.";
+	i256 => true, "Creates a number from its 64-bit two’s-complement words, least significant first.";
+}
+
+impl IsUnsigned for u256 {
+	fn is_power_of_two(self) -> bool {
+		self.count_ones() == 1
+	}
+
+	fn next_power_of_two(self) -> Self {
+		debug(
+			self.checked_next_power_of_two()
+				.map_or((Self::ZERO, true), |out| (out, false)),
+			"attempt to add with overflow",
+		)
+	}
+
+	fn checked_next_power_of_two(self) -> Option<Self> {
+		if self <= Self::ONE {
+			return Some(Self::ONE);
+		}
+		let bits = BITS - (self - Self::ONE).leading_zeros();
+		Self::ONE.checked_shl(bits)
+	}
+}
+
+impl Neg for i256 {
+	type Output = Self;
+
+	fn neg(self) -> Self::Output {
+		debug(self.overflowing_neg(), "attempt to negate with overflow")
+	}
+}
+
+impl IsSigned for i256 {
+	fn checked_abs(self) -> Option<Self> {
+		checked(self.overflowing_abs())
+	}
+
+	fn wrapping_abs(self) -> Self {
+		self.overflowing_abs().0
+	}
+
+	fn overflowing_abs(self) -> (Self, bool) {
+		if self.is_neg() {
+			self.overflowing_neg()
+		}
+		else {
+			(self, false)
+		}
+	}
+
+	fn abs(self) -> Self {
+		debug(self.overflowing_abs(), "attempt to negate with overflow")
+	}
+
+	fn signum(self) -> Self {
+		match self.cmp(&Self::ZERO) {
+			Ordering::Less => !Self::ZERO,
+			Ordering::Equal => Self::ZERO,
+			Ordering::Greater => Self::ONE,
+		}
+	}
+
+	fn is_positive(self) -> bool {
+		self > Self::ZERO
+	}
+
+	fn is_negative(self) -> bool {
+		self.is_neg()
+	}
+}
+
+macro_rules! convert {
+	($($unsigned:ty),+; $($signed:ty),+ $(;)?) => {
+		$(
+			impl From<$unsigned> for u256 {
+				fn from(value: $unsigned) -> Self {
+					let value = value as u128;
+					Self {
+						limbs: [value as u64, (value >> 64) as u64, 0, 0],
+					}
+				}
+			}
+
+			impl From<$unsigned> for i256 {
+				fn from(value: $unsigned) -> Self {
+					Self {
+						limbs: u256::from(value).limbs,
+					}
+				}
+			}
+
+			impl TryFrom<u256> for $unsigned {
+				type Error = TryFromIntError;
+
+				fn try_from(value: u256) -> Result<Self, Self::Error> {
+					let [lo, hi, a, b] = value.limbs;
+					if a != 0 || b != 0 {
+						return Err(errors::try_from());
+					}
+					Self::try_from(lo as u128 | (hi as u128) << 64)
+						.map_err(|_| errors::try_from())
+				}
+			}
+
+			impl TryFrom<i256> for $unsigned {
+				type Error = TryFromIntError;
+
+				fn try_from(value: i256) -> Result<Self, Self::Error> {
+					Self::try_from(u256::try_from(value)?)
+				}
+			}
+		)+
+		$(
+			impl TryFrom<$signed> for u256 {
+				type Error = TryFromIntError;
+
+				fn try_from(value: $signed) -> Result<Self, Self::Error> {
+					u256::try_from(i256::from(value))
+				}
+			}
+
+			impl From<$signed> for i256 {
+				fn from(value: $signed) -> Self {
+					let value = value as i128;
+					let ext = if value < 0 { !0 } else { 0 };
+					Self {
+						limbs: [value as u64, (value >> 64) as u64, ext, ext],
+					}
+				}
+			}
+
+			impl TryFrom<u256> for $signed {
+				type Error = TryFromIntError;
+
+				fn try_from(value: u256) -> Result<Self, Self::Error> {
+					Self::try_from(i256::try_from(value)?)
+				}
+			}
+
+			impl TryFrom<i256> for $signed {
+				type Error = TryFromIntError;
+
+				fn try_from(value: i256) -> Result<Self, Self::Error> {
+					let [lo, hi, a, b] = value.limbs;
+					let ext = if hi >> 63 == 1 { !0 } else { 0 };
+					if a != ext || b != ext {
+						return Err(errors::try_from());
+					}
+					Self::try_from((lo as u128 | (hi as u128) << 64) as i128)
+						.map_err(|_| errors::try_from())
+				}
+			}
+		)+
+	};
+}
+
+convert! {
+	u8, u16, u32, u64, u128, usize;
+	i8, i16, i32, i64, i128, isize;
+}
+
+impl TryFrom<i256> for u256 {
+	type Error = TryFromIntError;
+
+	fn try_from(value: i256) -> Result<Self, Self::Error> {
+		if value.is_neg() {
+			return Err(errors::try_from());
+		}
+		Ok(Self {
+			limbs: value.limbs,
+		})
+	}
+}
+
+impl TryFrom<u256> for i256 {
+	type Error = TryFromIntError;
+
+	fn try_from(value: u256) -> Result<Self, Self::Error> {
+		let out = Self {
+			limbs: value.limbs,
+		};
+		if out.is_neg() {
+			return Err(errors::try_from());
+		}
+		Ok(out)
+	}
+}
+
+/// Produces the error values of the standard library’s integer conversions,
+/// which cannot be constructed directly.
+pub(crate) mod errors {
+	use core::{
+		convert::TryFrom,
+		num::{
+			ParseIntError,
+			TryFromIntError,
+		},
+	};
+
+	pub fn try_from() -> TryFromIntError {
+		u8::try_from(-1i8).unwrap_err()
+	}
+
+	pub fn empty() -> ParseIntError {
+		"".parse::<u8>().unwrap_err()
+	}
+
+	pub fn invalid_digit() -> ParseIntError {
+		"-".parse::<u8>().unwrap_err()
+	}
+
+	pub fn pos_overflow() -> ParseIntError {
+		"256".parse::<u8>().unwrap_err()
+	}
+
+	pub fn neg_overflow() -> ParseIntError {
+		"-129".parse::<i8>().unwrap_err()
+	}
+}
+
+/// Converts an overflowing result into a checked result.
+pub(crate) fn checked<T>((value, ovf): (T, bool)) -> Option<T> {
+	if ovf { None } else { Some(value) }
+}
+
+/// Applies the fundamental integers’ operator behavior to an overflowing
+/// result: a panic when debug assertions are enabled, and wrapping otherwise.
+pub(crate) fn debug<T>((value, ovf): (T, bool), msg: &str) -> T {
+	if cfg!(debug_assertions) && ovf {
+		panic!("{}", msg);
+	}
+	value
+}
+
+/// Tests if a shift amount is outside the width of the number.
+fn too_far<T: TryInto<u32>>(amount: T) -> bool {
+	amount.try_into().map_or(true, |amount| amount >= BITS)
+}
+
+/// Writes the digits of a magnitude in some radix.
+fn format(
+	fmt: &mut Formatter,
+	nonneg: bool,
+	mut mag: Limbs,
+	radix: u64,
+	upper: bool,
+	prefix: &str,
+) -> fmt::Result
+{
+	let mut buf = [0u8; BITS as usize];
+	let mut idx = buf.len();
+	loop {
+		let (quot, rem) = div_rem_small(mag, radix);
+		let digit = rem as u8;
+		idx -= 1;
+		buf[idx] = match digit {
+			0 ..= 9 => b'0' + digit,
+			_ if upper => b'A' + digit - 10,
+			_ => b'a' + digit - 10,
+		};
+		mag = quot;
+		if mag == [0; 4] {
+			break;
+		}
+	}
+	match str::from_utf8(&buf[idx ..]) {
+		Ok(digits) => fmt.pad_integral(nonneg, prefix, digits),
+		Err(_) => unreachable!("Digits are ASCII"),
+	}
+}
+
+/// Multiplies a number by a word, and adds a word, producing whether the
+/// result overflowed.
+fn mul_add_small(a: Limbs, m: u64, mut carry: u64) -> (Limbs, bool) {
+	let mut out = [0; 4];
+	for (out, a) in out.iter_mut().zip(a.iter()) {
+		let (lo, hi) = limbs::carrying_mul(*a, m, carry);
+		*out = lo;
+		carry = hi;
+	}
+	(out, carry != 0)
+}
+
+/// Divides a number by a nonzero word, producing the quotient and remainder.
+fn div_rem_small(a: Limbs, d: u64) -> (Limbs, u64) {
+	let mut out = [0; 4];
+	let mut rem = 0u128;
+	for (out, a) in out.iter_mut().zip(a.iter()).rev() {
+		let cur = rem << 64 | *a as u128;
+		*out = (cur / d as u128) as u64;
+		rem = cur % d as u128;
+	}
+	(out, rem as u64)
+}
+
+/// Divides two unsigned numbers. The divisor must not be zero.
+fn div_rem(a: Limbs, b: Limbs) -> (Limbs, Limbs) {
+	let (mut quot, mut rem) = ([0; 4], [0; 4]);
+	for bit in (0 .. BITS - leading_zeros(a)).rev() {
+		let top = rem[3] >> 63 == 1;
+		rem = limbs::wrapping_shl(rem, 1);
+		rem[0] |= a[bit as usize / 64] >> (bit % 64) & 1;
+		if top || cmp(rem, b) != Ordering::Less {
+			rem = limbs::wrapping_sub(rem, b);
+			quot[bit as usize / 64] |= 1 << (bit % 64);
+		}
+	}
+	(quot, rem)
+}
+
+/// Negates a number in two’s complement.
+fn neg(a: Limbs) -> Limbs {
+	limbs::wrapping_sub([0; 4], a)
+}
+
+/// Compares two unsigned numbers.
+fn cmp(a: Limbs, b: Limbs) -> Ordering {
+	a.iter().rev().cmp(b.iter().rev())
+}
+
+/// Counts the zero bits above the most significant one bit.
+fn leading_zeros(a: Limbs) -> u32 {
+	let mut out = 0;
+	for limb in a.iter().rev() {
+		out += limb.leading_zeros();
+		if *limb != 0 {
+			break;
+		}
+	}
+	out
+}
+
+/// Combines each word of two numbers.
+fn zip(a: Limbs, b: Limbs, func: impl Fn(u64, u64) -> u64) -> Limbs {
+	[
+		func(a[0], b[0]),
+		func(a[1], b[1]),
+		func(a[2], b[2]),
+		func(a[3], b[3]),
+	]
+}
+
+fn and(a: Limbs, b: Limbs) -> Limbs {
+	zip(a, b, |a, b| a & b)
+}
+
+fn or(a: Limbs, b: Limbs) -> Limbs {
+	zip(a, b, |a, b| a | b)
+}
+
+fn xor(a: Limbs, b: Limbs) -> Limbs {
+	zip(a, b, |a, b| a ^ b)
+}
+
+fn not(a: Limbs) -> Limbs {
+	zip(a, a, |a, _| !a)
+}
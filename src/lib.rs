@@ -47,7 +47,7 @@ let num = 120i8.overflowing();
 let ovf = num + 10;
 //  120 -> 127 -> -128 -> -126
 assert_eq!(ovf, -126);
-assert!(ovf.has_overflowed);
+assert!(ovf.has_overflowed());
 ```
 
 In addition, you can saturate at the minimum and maximum values, without
@@ -57,7 +57,7 @@ wrapping around to the other edge:
 # use surety::*;
 # let reverse = 110i8.wrapping();
 //  get the value, and mark it as saturating at the boundary
-let sat = reverse.value.saturating();
+let sat = reverse.value().saturating();
 
 let max = sat + 20;
 assert_eq!(max, i8::max_value());
@@ -89,29 +89,264 @@ assert!((invalid - 2).is_none());
 let reset = invalid.or_insert(0);
 assert_eq!(reset, Some(0));
 ```
+
+# Mixing Policies
+
+Two different wrappers over the same integer can be combined with `+`, `-`, and
+`*`. The result takes the stronger policy: `Checked` dominates every other
+wrapper, and `Saturating` and `Overflowing` each dominate `Wrapping`. An
+`Overflowing` whose flag is set poisons a `Checked` result. `Saturating` and
+`Overflowing` do not combine.
+
+```rust
+# use surety::*;
+let hash = 0x40u8.wrapping() * 5;
+let total = 10u8.checked() + hash;
+assert_eq!(total, Some(74));
+assert!((total + 250u8.saturating()).is_none());
+```
 !*/
 
 #![no_std]
 
+#[macro_use]
+mod macros;
+
+#[doc(hidden)]
+pub mod __private;
+mod accumulator;
+mod align;
+pub mod atomic;
+#[cfg(feature = "az")]
+mod az;
+#[cfg(feature = "num-bigint")]
+mod bigint;
+mod bitint;
+mod bits;
+mod cast;
 mod checked;
+mod compare;
+mod convert;
+mod cross;
+#[cfg(feature = "ct")]
+pub mod ct;
+#[cfg(any(feature = "diesel", feature = "sqlx"))]
+mod db;
+mod decimal;
+mod dual;
+#[cfg(all(feature = "arm-dsp", target_arch = "arm"))]
+mod dsp;
+mod error;
+mod fixed;
+mod float;
+mod int256;
+pub mod limbs;
+pub mod mix;
+mod mixed;
+mod modular;
+mod nonzero;
+#[cfg(feature = "num-traits")]
+mod num;
+pub mod ops;
 mod overflowing;
+#[cfg(feature = "rayon")]
+pub mod par;
+pub mod poly;
+pub mod prelude;
+pub mod prng;
+pub mod ptr;
+pub mod quant;
+#[cfg(feature = "rand")]
+pub mod rand;
+mod ranged;
+mod round;
 mod saturating;
+pub mod scale;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod shift;
+mod sign;
+pub mod slice;
+pub mod stats;
+mod strict;
+mod text;
+mod total;
+#[cfg(feature = "verify")]
+pub mod verify;
+mod widen;
 mod wrapping;
 
 pub use self::{
-	checked::Checked,
+	accumulator::Accumulator,
+	align::Align,
+	bitint::{
+		Int,
+		UInt,
+	},
+	cast::{
+		CastFrom,
+		CheckedCast,
+		SaturatingCast,
+		ToFloat,
+		WrappingCast,
+	},
+	checked::{
+		Checked,
+		DisplayOr,
+		OverflowLast,
+	},
+	compare::MixedCmp,
+	decimal::{
+		Decimal,
+		ParseDecimalError,
+	},
+	dual::DualOverflowing,
+	error::{
+		ArithmeticError,
+		Result,
+	},
+	fixed::{
+		Fixed,
+		FixedPolicy,
+	},
+	float::{
+		CheckedFloat,
+		SaturatingFloat,
+	},
+	int256::{
+		i256,
+		u256,
+	},
+	modular::{
+		Modular,
+		PowMod,
+	},
+	nonzero::{
+		CheckedNonZero,
+		NonZeroInteger,
+		SaturatingNonZero,
+	},
 	overflowing::Overflowing,
+	ranged::{
+		RangePolicy,
+		Ranged,
+	},
+	round::Ties,
 	saturating::Saturating,
+	shift::ShiftAmount,
+	sign::{
+		ToSigned,
+		ToUnsigned,
+	},
+	total::{
+		TotalDiv,
+		TotalOps,
+	},
+	widen::{
+		Promote,
+		Promoted,
+		Widen,
+	},
 	wrapping::Wrapping,
 };
 
+#[cfg(feature = "macros")]
+pub use surety_macros::{
+	checked_fn,
+	Surety,
+};
+
+/** The integer interface on which every wrapper is generic.
+
+Any type which implements this trait can be used in `Checked`, `Overflowing`,
+`Saturating`, and `Wrapping`. It requires the `checked_*`, `overflowing_*`,
+`saturating_*`, and `wrapping_*` arithmetic methods to which the wrappers defer,
+and the [`Number`] supertrait. It is implemented for all of the fundamental
+integers, and for [`u256`] and [`i256`], which are examples of implementing it
+for a type outside the standard library.
+
+This is `funty::IsInteger` itself, re-exported, and not a trait that this crate
+defines. Downstream crates can name and implement it without adding `funty` to
+their own manifests, but an implementation is written against the definition in
+`funty` 1.1, with all of its supertraits, and a new major version of `funty`
+would be a breaking change to this crate as well.
+**/
+#[doc(inline)]
+pub use funty::IsInteger as Integer;
+/** The numeric interface beneath [`Integer`].
+
+This is `funty::IsNumber`, re-exported alongside [`Integer`].
+**/
+#[doc(inline)]
+pub use funty::IsNumber as Number;
+/** The additional interface of the signed integers, such as `abs` and `signum`.
+
+This is `funty::IsSigned`, re-exported alongside [`Integer`].
+**/
+#[doc(inline)]
+pub use funty::IsSigned as Signed;
+/** The additional interface of the unsigned integers, such as
+`is_power_of_two`.
+
+This is `funty::IsUnsigned`, re-exported alongside [`Integer`].
+**/
+#[doc(inline)]
+pub use funty::IsUnsigned as Unsigned;
+
 use funty::IsInteger;
 
+/** The overflow policy selected for the whole program by a cargo feature.
+
+Application code written against this alias can switch every computation to
+another policy by changing one feature, such as checking every operation in a
+debugging or certification build and wrapping in a production build.
+
+- `default-checked` selects `Checked`.
+- `default-saturating` selects `Saturating`.
+- `default-wrapping` selects `Wrapping`.
+
+Cargo features must be additive, because a feature enabled by any crate in a
+build is enabled for all of them. If more than one of these is enabled, the
+strongest policy wins, as it does when wrappers are mixed: `Checked`, then
+`Saturating`, then `Wrapping`. When none is enabled, the policy is `Checked`.
+
+The alias is not named `Int`, because [`Int`] is already the signed integer of
+any width. An application can import it under that name instead.
+
+# Examples
+
+```rust
+use surety::DefaultPolicy as Int;
+
+let total = Int::new(200u8) + 50;
+assert_eq!(total, 250);
+```
+**/
+#[cfg(any(
+	feature = "default-checked",
+	not(any(feature = "default-saturating", feature = "default-wrapping")),
+))]
+pub type DefaultPolicy<T> = Checked<T>;
+
+/// The overflow policy selected for the whole program by a cargo feature:
+/// `Saturating`, as `default-saturating` selects.
+#[cfg(all(feature = "default-saturating", not(feature = "default-checked")))]
+pub type DefaultPolicy<T> = Saturating<T>;
+
+/// The overflow policy selected for the whole program by a cargo feature:
+/// `Wrapping`, as `default-wrapping` selects.
+#[cfg(all(
+	feature = "default-wrapping",
+	not(any(feature = "default-checked", feature = "default-saturating")),
+))]
+pub type DefaultPolicy<T> = Wrapping<T>;
+
 /** Extension method to attach `surety` constructors to the integers.
 
-This trait is only implementable on, and implemented by, the Rust fundamental
-integers. It provides typecast wrappers which select a specific arithmetic
-behavior on overflow.
+This trait is implemented for every [`Integer`]: the Rust fundamental integers,
+[`u256`] and [`i256`], and any downstream type which implements `Integer`. It
+provides typecast wrappers which select a specific arithmetic behavior on
+overflow.
 **/
 pub trait Ensure: IsInteger {
 	/// Selects checked-overflow arithmetic.
@@ -144,3 +379,30 @@ impl<T: IsInteger> Ensure for T {
 		self.into()
 	}
 }
+
+/** Extension method to attach the `Checked` constructor to `Option`.
+
+An `Option` of an integer is the natural result of a lookup, a parse, or a
+prior checked step, and a `None` is treated as an already-poisoned value.
+
+# Examples
+
+```rust
+use surety::EnsureOption as _;
+
+let values = [250u8, 10];
+let sum = values.first().copied().checked() + values[1];
+assert!(sum.is_none());
+assert!((None::<u8>.checked() + 1).is_none());
+```
+**/
+pub trait EnsureOption<T: IsInteger> {
+	/// Selects checked-overflow arithmetic, treating `None` as poisoned.
+	fn checked(self) -> Checked<T>;
+}
+
+impl<T: IsInteger> EnsureOption<T> for Option<T> {
+	fn checked(self) -> Checked<T> {
+		self.into()
+	}
+}
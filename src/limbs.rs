@@ -0,0 +1,249 @@
+/*! Arithmetic kernels over multi-word unsigned integers.
+
+A number is held in an array of `N` 64-bit words, or limbs, with the least
+significant limb first, and represents a `64 × N`-bit unsigned integer. Each
+operation has an `overflowing_` form, which wraps and reports whether the result
+did not fit, and `wrapping_` and `checked_` forms built on it.
+
+The operations are built from the single-word [`carrying_add`],
+[`borrowing_sub`], and [`carrying_mul`] primitives, which are also provided for
+callers composing their own kernels.
+
+# Examples
+
+```rust
+use surety::limbs;
+
+let max = [u64::max_value(); 2];
+let one = [1, 0];
+
+assert_eq!(limbs::wrapping_add(max, one), [0, 0]);
+assert!(limbs::checked_add(max, one).is_none());
+assert_eq!(limbs::checked_sub(one, one), Some([0, 0]));
+
+//  (2^64 + 1) * 2^63 fits in 128 bits, but (2^64 + 1) * 2^64 does not.
+assert_eq!(limbs::checked_mul([1, 1], [1 << 63, 0]), Some([1 << 63, 1 << 63]));
+assert!(limbs::checked_mul([1, 1], [0, 1]).is_none());
+
+assert_eq!(limbs::wrapping_shl([1 << 63, 0], 1), [0, 1]);
+assert!(limbs::checked_shr([0, 1], 128).is_none());
+```
+
+[`borrowing_sub`]: crate::limbs::borrowing_sub
+[`carrying_add`]: crate::limbs::carrying_add
+[`carrying_mul`]: crate::limbs::carrying_mul
+!*/
+
+/// Adds two words and an incoming carry, producing the sum and the outgoing
+/// carry.
+pub const fn carrying_add(lhs: u64, rhs: u64, carry: bool) -> (u64, bool) {
+	let (sum, c1) = lhs.overflowing_add(rhs);
+	let (sum, c2) = sum.overflowing_add(carry as u64);
+	(sum, c1 | c2)
+}
+
+/// Subtracts a word and an incoming borrow from a word, producing the
+/// difference and the outgoing borrow.
+pub const fn borrowing_sub(lhs: u64, rhs: u64, borrow: bool) -> (u64, bool) {
+	let (diff, b1) = lhs.overflowing_sub(rhs);
+	let (diff, b2) = diff.overflowing_sub(borrow as u64);
+	(diff, b1 | b2)
+}
+
+/// Multiplies two words and adds an incoming carry word, producing the low and
+/// high words of the result. This cannot overflow.
+pub const fn carrying_mul(lhs: u64, rhs: u64, carry: u64) -> (u64, u64) {
+	let wide = lhs as u128 * rhs as u128 + carry as u128;
+	(wide as u64, (wide >> 64) as u64)
+}
+
+/// Adds two numbers, producing the wrapped sum and whether it overflowed.
+pub fn overflowing_add<const N: usize>(
+	lhs: [u64; N],
+	rhs: [u64; N],
+) -> ([u64; N], bool)
+{
+	let mut out = [0; N];
+	let mut carry = false;
+	for ((out, lhs), rhs) in out.iter_mut().zip(lhs.iter()).zip(rhs.iter()) {
+		let (sum, next) = carrying_add(*lhs, *rhs, carry);
+		*out = sum;
+		carry = next;
+	}
+	(out, carry)
+}
+
+/// Adds two numbers, wrapping at the boundary of the array.
+pub fn wrapping_add<const N: usize>(lhs: [u64; N], rhs: [u64; N]) -> [u64; N] {
+	overflowing_add(lhs, rhs).0
+}
+
+/// Adds two numbers, producing `None` if the sum does not fit.
+pub fn checked_add<const N: usize>(
+	lhs: [u64; N],
+	rhs: [u64; N],
+) -> Option<[u64; N]>
+{
+	checked(overflowing_add(lhs, rhs))
+}
+
+/// Subtracts two numbers, producing the wrapped difference and whether it
+/// overflowed below zero.
+pub fn overflowing_sub<const N: usize>(
+	lhs: [u64; N],
+	rhs: [u64; N],
+) -> ([u64; N], bool)
+{
+	let mut out = [0; N];
+	let mut borrow = false;
+	for ((out, lhs), rhs) in out.iter_mut().zip(lhs.iter()).zip(rhs.iter()) {
+		let (diff, next) = borrowing_sub(*lhs, *rhs, borrow);
+		*out = diff;
+		borrow = next;
+	}
+	(out, borrow)
+}
+
+/// Subtracts two numbers, wrapping at the boundary of the array.
+pub fn wrapping_sub<const N: usize>(lhs: [u64; N], rhs: [u64; N]) -> [u64; N] {
+	overflowing_sub(lhs, rhs).0
+}
+
+/// Subtracts two numbers, producing `None` if the difference is below zero.
+pub fn checked_sub<const N: usize>(
+	lhs: [u64; N],
+	rhs: [u64; N],
+) -> Option<[u64; N]>
+{
+	checked(overflowing_sub(lhs, rhs))
+}
+
+/// Multiplies two numbers, producing the low `N` words of the product and
+/// whether any of the high `N` words are nonzero.
+pub fn overflowing_mul<const N: usize>(
+	lhs: [u64; N],
+	rhs: [u64; N],
+) -> ([u64; N], bool)
+{
+	let mut out = [0; N];
+	let mut ovf = false;
+	for (idx, lhs) in lhs.iter().enumerate() {
+		let mut carry = 0;
+		for (out, rhs) in out[idx ..].iter_mut().zip(rhs.iter()) {
+			let (lo, hi) = carrying_mul(*lhs, *rhs, carry);
+			let (lo, c) = lo.overflowing_add(*out);
+			*out = lo;
+			carry = hi + c as u64;
+		}
+		//  Partial products that land above the array are all lost.
+		ovf |= carry != 0
+			|| (*lhs != 0 && rhs[N - idx ..].iter().any(|&rhs| rhs != 0));
+	}
+	(out, ovf)
+}
+
+/// Multiplies two numbers, wrapping at the boundary of the array.
+pub fn wrapping_mul<const N: usize>(lhs: [u64; N], rhs: [u64; N]) -> [u64; N] {
+	overflowing_mul(lhs, rhs).0
+}
+
+/// Multiplies two numbers, producing `None` if the product does not fit.
+pub fn checked_mul<const N: usize>(
+	lhs: [u64; N],
+	rhs: [u64; N],
+) -> Option<[u64; N]>
+{
+	checked(overflowing_mul(lhs, rhs))
+}
+
+/// Shifts a number towards its most significant end, filling with zeros.
+///
+/// As with the fundamental integers, the shift amount is reduced modulo the
+/// width of the number, and the overflow flag reports whether it had to be.
+pub fn overflowing_shl<const N: usize>(
+	value: [u64; N],
+	amount: u32,
+) -> ([u64; N], bool)
+{
+	let (amount, ovf) = mask::<N>(amount);
+	let (words, bits) = (amount / 64, amount % 64);
+	let mut out = [0; N];
+	for idx in words .. N {
+		out[idx] = value[idx - words] << bits;
+		if bits != 0 && idx > words {
+			out[idx] |= value[idx - words - 1] >> (64 - bits);
+		}
+	}
+	(out, ovf)
+}
+
+/// Shifts a number towards its most significant end, reducing the shift amount
+/// modulo the width of the number.
+pub fn wrapping_shl<const N: usize>(value: [u64; N], amount: u32) -> [u64; N] {
+	overflowing_shl(value, amount).0
+}
+
+/// Shifts a number towards its most significant end, producing `None` if the
+/// shift amount is not less than the width of the number.
+pub fn checked_shl<const N: usize>(
+	value: [u64; N],
+	amount: u32,
+) -> Option<[u64; N]>
+{
+	checked(overflowing_shl(value, amount))
+}
+
+/// Shifts a number towards its least significant end, filling with zeros.
+///
+/// As with the fundamental integers, the shift amount is reduced modulo the
+/// width of the number, and the overflow flag reports whether it had to be.
+pub fn overflowing_shr<const N: usize>(
+	value: [u64; N],
+	amount: u32,
+) -> ([u64; N], bool)
+{
+	let (amount, ovf) = mask::<N>(amount);
+	let (words, bits) = (amount / 64, amount % 64);
+	let mut out = [0; N];
+	for idx in 0 .. N - words {
+		out[idx] = value[idx + words] >> bits;
+		if bits != 0 && idx + words + 1 < N {
+			out[idx] |= value[idx + words + 1] << (64 - bits);
+		}
+	}
+	(out, ovf)
+}
+
+/// Shifts a number towards its least significant end, reducing the shift
+/// amount modulo the width of the number.
+pub fn wrapping_shr<const N: usize>(value: [u64; N], amount: u32) -> [u64; N] {
+	overflowing_shr(value, amount).0
+}
+
+/// Shifts a number towards its least significant end, producing `None` if the
+/// shift amount is not less than the width of the number.
+pub fn checked_shr<const N: usize>(
+	value: [u64; N],
+	amount: u32,
+) -> Option<[u64; N]>
+{
+	checked(overflowing_shr(value, amount))
+}
+
+/// Reduces a shift amount modulo the width of an `N`-word number, and reports
+/// whether that changed it.
+fn mask<const N: usize>(amount: u32) -> (usize, bool) {
+	let bits = N * 64;
+	let amount = amount as usize;
+	if bits == 0 {
+		(0, true)
+	}
+	else {
+		(amount % bits, amount >= bits)
+	}
+}
+
+/// Converts an overflowing result into a checked result.
+fn checked<T>((value, ovf): (T, bool)) -> Option<T> {
+	if ovf { None } else { Some(value) }
+}
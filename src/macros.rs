@@ -0,0 +1,199 @@
+//! Constructor macros for the wrapper types.
+
+/** Wraps an integer for checked arithmetic.
+
+This expands to a call of the `const` constructor [`Checked::new`], so it can be
+used in `const` and `static` items. The integer type can be given either as a
+suffix on a literal, or as a second argument.
+
+# Examples
+
+```rust
+use surety::{checked, Checked};
+
+const START: Checked<u8> = checked!(250);
+assert!((START + checked!(10, u8)).is_none());
+```
+
+[`Checked::new`]: crate::Checked::new
+**/
+#[macro_export]
+macro_rules! checked {
+	($val:expr $(,)?) => {
+		$crate::Checked::new($val)
+	};
+	($val:expr, $t:ty $(,)?) => {
+		$crate::Checked::<$t>::new($val)
+	};
+}
+
+/** Wraps an integer for overflow-detecting arithmetic.
+
+This expands to a call of the `const` constructor [`Overflowing::new`], so it
+can be used in `const` and `static` items. The integer type can be given either
+as a suffix on a literal, or as a second argument.
+
+# Examples
+
+```rust
+use surety::{overflowing, Overflowing};
+
+const START: Overflowing<u8> = overflowing!(250);
+let sum = START + overflowing!(10, u8);
+assert_eq!(sum, 4);
+assert!(sum.has_overflowed());
+```
+
+[`Overflowing::new`]: crate::Overflowing::new
+**/
+#[macro_export]
+macro_rules! overflowing {
+	($val:expr $(,)?) => {
+		$crate::Overflowing::new($val)
+	};
+	($val:expr, $t:ty $(,)?) => {
+		$crate::Overflowing::<$t>::new($val)
+	};
+}
+
+/** Wraps an integer for saturating arithmetic.
+
+This expands to a call of the `const` constructor [`Saturating::new`], so it can
+be used in `const` and `static` items. The integer type can be given either as a
+suffix on a literal, or as a second argument.
+
+# Examples
+
+```rust
+use surety::{saturating, Saturating};
+
+const START: Saturating<u8> = saturating!(250);
+assert_eq!(START + saturating!(10, u8), 255);
+```
+
+[`Saturating::new`]: crate::Saturating::new
+**/
+#[macro_export]
+macro_rules! saturating {
+	($val:expr $(,)?) => {
+		$crate::Saturating::new($val)
+	};
+	($val:expr, $t:ty $(,)?) => {
+		$crate::Saturating::<$t>::new($val)
+	};
+}
+
+/** Wraps an integer for wrapping arithmetic.
+
+This expands to a call of the `const` constructor [`Wrapping::new`], so it can
+be used in `const` and `static` items. The integer type can be given either as a
+suffix on a literal, or as a second argument.
+
+# Examples
+
+```rust
+use surety::{wrapping, Wrapping};
+
+const TABLE: [Wrapping<u8>; 3] = [wrapping!(0xFFu8), wrapping!(1), wrapping!(0)];
+assert_eq!(TABLE[0] + TABLE[1], TABLE[2]);
+assert_eq!(wrapping!(200, u8) * 2, 144);
+```
+
+[`Wrapping::new`]: crate::Wrapping::new
+**/
+#[macro_export]
+macro_rules! wrapping {
+	($val:expr $(,)?) => {
+		$crate::Wrapping::new($val)
+	};
+	($val:expr, $t:ty $(,)?) => {
+		$crate::Wrapping::<$t>::new($val)
+	};
+}
+
+/** Rewrites an arithmetic expression to use an overflow policy.
+
+The expression is written as ordinary Rust arithmetic, prefixed with the name of
+the policy to use: `checked`, `overflowing`, `saturating`, or `wrapping`. Every
+operand in the expression is wrapped in the named policy, so every operator in
+it uses that policy’s arithmetic, and the expression produces the wrapper type.
+
+Operands may be identifiers, literals, or parenthesized subexpressions, which
+are themselves rewritten. Any other operand, such as a field access, cast, or
+function call, can be placed in braces, and is wrapped without being rewritten.
+Method calls written after an operand are called on the wrapped value.
+
+# Examples
+
+```rust
+use surety::surety;
+
+let (a, b, c, d) = (200u8, 2u8, 30u8, 3u8);
+
+let sum = surety!(checked: a * b + c / d);
+assert!(sum.is_none());
+
+let sum = surety!(saturating: a * b + c);
+assert_eq!(sum, 255);
+
+let sum = surety!(wrapping: (a + { b * 50 }) * 2 - d.pow(2));
+assert_eq!(sum, 79);
+```
+**/
+#[macro_export]
+macro_rules! surety {
+	($policy:ident : $($expr:tt)+) => {
+		$crate::__surety_expr!(@$policy [] $($expr)+)
+	};
+}
+
+/// Implementation detail of `surety!`.
+///
+/// This munches the expression one token at a time, wrapping each operand and
+/// passing through each operator, and recursing into parenthesized groups.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __surety_expr {
+	(@$policy:ident [$($out:tt)*]) => {
+		$($out)*
+	};
+	(@$policy:ident [$($out:tt)*] ($($inner:tt)+) $($rest:tt)*) => {
+		$crate::__surety_expr!(@$policy [
+			$($out)* ($crate::__surety_expr!(@$policy [] $($inner)+))
+		] $($rest)*)
+	};
+	(
+		@$policy:ident [$($out:tt)*]
+		. $method:ident ($($args:tt)*) $($rest:tt)*
+	) => {
+		$crate::__surety_expr!(@$policy [
+			$($out)* . $method($($args)*)
+		] $($rest)*)
+	};
+	(@$policy:ident [$($out:tt)*] + $($rest:tt)*) => {
+		$crate::__surety_expr!(@$policy [$($out)* +] $($rest)*)
+	};
+	(@$policy:ident [$($out:tt)*] - $($rest:tt)*) => {
+		$crate::__surety_expr!(@$policy [$($out)* -] $($rest)*)
+	};
+	(@$policy:ident [$($out:tt)*] * $($rest:tt)*) => {
+		$crate::__surety_expr!(@$policy [$($out)* *] $($rest)*)
+	};
+	(@$policy:ident [$($out:tt)*] / $($rest:tt)*) => {
+		$crate::__surety_expr!(@$policy [$($out)* /] $($rest)*)
+	};
+	(@$policy:ident [$($out:tt)*] % $($rest:tt)*) => {
+		$crate::__surety_expr!(@$policy [$($out)* %] $($rest)*)
+	};
+	(@$policy:ident [$($out:tt)*] << $($rest:tt)*) => {
+		$crate::__surety_expr!(@$policy [$($out)* <<] $($rest)*)
+	};
+	(@$policy:ident [$($out:tt)*] >> $($rest:tt)*) => {
+		$crate::__surety_expr!(@$policy [$($out)* >>] $($rest)*)
+	};
+	(@$policy:ident [$($out:tt)*] $operand:tt $($rest:tt)*) => {
+		$crate::__surety_expr!(@$policy [
+			$($out)* $crate::Ensure::$policy($operand)
+		] $($rest)*)
+	};
+}
@@ -0,0 +1,89 @@
+/*! Hash mixing functions, built on wrapping arithmetic.
+
+Non-cryptographic hashers combine their input with multiplications that are
+meant to overflow, which is exactly the arithmetic that [`Wrapping`] names.
+These functions are the standard building blocks of such hashers, written over
+`Wrapping` so that every intentional overflow is visible in their types:
+
+- [`fmix32`] and [`fmix64`] are the MurmurHash3 finalizers, which make every
+  output bit depend on every input bit.
+- [`fx32`] and [`fx64`] add one word to an FxHash state, as the Rust compiler’s
+  hasher does.
+- [`folded_mul`] multiplies into the double-width product and folds its halves
+  together with xor, as wyhash and foldhash do.
+
+# Examples
+
+```rust
+use surety::{mix, Wrapping};
+
+assert_eq!(mix::fmix64(Wrapping::new(1)), 0xb456_bcfc_34c2_cb2c);
+assert_eq!(mix::fmix32(Wrapping::new(1)), 0x514e_28b7);
+assert_eq!(mix::fmix64(Wrapping::new(0)), 0);
+
+let hash = [1, 2, 3].iter().fold(Wrapping::new(0), |hash, &word| {
+    mix::fx64(hash, word)
+});
+assert_eq!(hash, 0xfdcb_2688_e076_0126);
+
+let pi = Wrapping::new(0x243f_6a88_85a3_08d3);
+assert_eq!(mix::folded_mul(pi, 0x1319_8a2e_0370_7344), 0xbc13_060e_2d1a_ac79);
+```
+
+[`Wrapping`]: crate::Wrapping
+!*/
+
+use funty::IsInteger;
+
+use crate::Wrapping;
+
+/// The FxHash multiplier for 32-bit states.
+const FX32: u32 = 0x9e37_79b9;
+
+/// The FxHash multiplier for 64-bit states.
+const FX64: u64 = 0x517c_c1b7_2722_0a95;
+
+/// Applies the MurmurHash3 32-bit finalizer.
+///
+/// This is a bijection, so distinct inputs always produce distinct outputs, and
+/// it maps zero to zero.
+pub fn fmix32(hash: Wrapping<u32>) -> Wrapping<u32> {
+	let hash = xorshift(hash, 16) * 0x85eb_ca6b;
+	let hash = xorshift(hash, 13) * 0xc2b2_ae35;
+	xorshift(hash, 16)
+}
+
+/// Applies the MurmurHash3 64-bit finalizer.
+///
+/// This is a bijection, so distinct inputs always produce distinct outputs, and
+/// it maps zero to zero.
+pub fn fmix64(hash: Wrapping<u64>) -> Wrapping<u64> {
+	let hash = xorshift(hash, 33) * 0xff51_afd7_ed55_8ccd;
+	let hash = xorshift(hash, 33) * 0xc4ce_b9fe_1a85_ec53;
+	xorshift(hash, 33)
+}
+
+/// Adds one word to a 32-bit FxHash state.
+pub fn fx32(hash: Wrapping<u32>, word: u32) -> Wrapping<u32> {
+	Wrapping::new(hash.value.rotate_left(5) ^ word) * FX32
+}
+
+/// Adds one word to a 64-bit FxHash state.
+pub fn fx64(hash: Wrapping<u64>, word: u64) -> Wrapping<u64> {
+	Wrapping::new(hash.value.rotate_left(5) ^ word) * FX64
+}
+
+/// Multiplies into the full 128-bit product, and returns the xor of its high
+/// and low halves.
+///
+/// Unlike a wrapping multiplication, which discards the high half, this keeps
+/// the influence of every bit of both operands.
+pub fn folded_mul(lhs: Wrapping<u64>, rhs: u64) -> Wrapping<u64> {
+	let full = lhs.value as u128 * rhs as u128;
+	Wrapping::new(full as u64 ^ (full >> 64) as u64)
+}
+
+/// Xors an integer with itself shifted right by `n` bits.
+fn xorshift<T: IsInteger>(hash: Wrapping<T>, n: u32) -> Wrapping<T> {
+	Wrapping::new(hash.value ^ (hash.value >> n))
+}
@@ -0,0 +1,72 @@
+//! Arithmetic between two different wrappers over the same integer.
+//!
+//! The result takes the stronger of the two policies: `Checked` dominates
+//! every other wrapper, `Saturating` dominates `Wrapping`, and `Overflowing`
+//! dominates `Wrapping`. The weaker operand is converted into the stronger
+//! wrapper through `From`, and the stronger wrapper’s operator is applied, so
+//! an overflowed `Overflowing` poisons a `Checked` result.
+//!
+//! `Saturating` and `Overflowing` do not combine, as neither can represent the
+//! state of the other.
+
+use core::ops::{
+	Add,
+	AddAssign,
+	Mul,
+	MulAssign,
+	Sub,
+	SubAssign,
+};
+
+use funty::IsInteger;
+
+use crate::{
+	Checked,
+	Overflowing,
+	Saturating,
+	Wrapping,
+};
+
+macro_rules! mixed {
+	($($strong:ident > $weak:ident);+ $(;)?) => { $(
+		mixed!(@ $strong > $weak =>
+			Add add AddAssign add_assign;
+			Sub sub SubAssign sub_assign;
+			Mul mul MulAssign mul_assign;
+		);
+	)+ };
+
+	(@ $strong:ident > $weak:ident => $(
+		$op:ident $func:ident $op_assign:ident $func_assign:ident;
+	)+) => { $(
+		impl<T: IsInteger> $op<$weak<T>> for $strong<T> {
+			type Output = Self;
+
+			fn $func(self, rhs: $weak<T>) -> Self::Output {
+				self.$func(Self::from(rhs))
+			}
+		}
+
+		impl<T: IsInteger> $op<$strong<T>> for $weak<T> {
+			type Output = $strong<T>;
+
+			fn $func(self, rhs: $strong<T>) -> Self::Output {
+				$strong::from(self).$func(rhs)
+			}
+		}
+
+		impl<T: IsInteger> $op_assign<$weak<T>> for $strong<T> {
+			fn $func_assign(&mut self, rhs: $weak<T>) {
+				*self = (*self).$func(rhs);
+			}
+		}
+	)+ };
+}
+
+mixed! {
+	Checked > Overflowing;
+	Checked > Saturating;
+	Checked > Wrapping;
+	Saturating > Wrapping;
+	Overflowing > Wrapping;
+}
@@ -0,0 +1,346 @@
+use core::{
+	cmp::Ordering,
+	convert::TryFrom,
+	ops::{
+		Add,
+		AddAssign,
+		Mul,
+		MulAssign,
+		Neg,
+		Sub,
+		SubAssign,
+	},
+};
+
+use funty::IsUnsigned;
+
+use crate::{
+	Widen,
+	Wrapping,
+};
+
+/** Performs arithmetic modulo `N`.
+
+This type encloses an unsigned integer in the range `0 .. N`, and reduces the
+result of every arithmetic operation back into that range. Where `Wrapping` is
+arithmetic modulo a power of two fixed by the width of the integer, `Modular`
+accepts any modulus up to one more than the integer’s maximum value.
+
+Each operation is performed in the integer’s [`Widen`] type, where it cannot
+overflow, before being reduced and truncated back to the narrow integer.
+
+# Examples
+
+```rust
+use surety::Modular;
+
+type Hour = Modular<u8, 12>;
+assert_eq!(Hour::new(9) + 5, 2);
+assert_eq!(Hour::new(2) - 5, 9);
+
+type Residue = Modular<u32, 1_000_000_007>;
+let x = Residue::new(123_456_789);
+assert_eq!(x * x.inverse().unwrap(), 1);
+assert_eq!(Residue::new(2).pow_mod(30), 73_741_817);
+```
+
+[`Widen`]: crate::Widen
+**/
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Modular<T: IsUnsigned + Widen, const N: u128> {
+	/// The contained integer, which is always less than `N`.
+	value: T,
+}
+
+impl<T: IsUnsigned + Widen, const N: u128> Modular<T, N> {
+	/// Rejects a modulus which is zero, or whose residues do not all fit in
+	/// `T`. This is evaluated when a constructor is instantiated, so the error
+	/// is reported at build time.
+	const MODULUS_FITS: () = assert!(
+		N != 0 && N - 1 <= T::BOUNDS.1 as u128,
+		"The modulus must be nonzero, and its residues must fit in the \
+		 integer type",
+	);
+
+	/// Reduces an integer modulo `N`.
+	///
+	/// A modulus which is zero, or whose residues do not all fit in `T`, fails
+	/// to compile:
+	///
+	/// ```rust,compile_fail
+	/// use surety::Modular;
+	///
+	/// let _ = Modular::<u8, 0>::new(1);
+	/// ```
+	pub fn new(value: T) -> Self {
+		let () = Self::MODULUS_FITS;
+		Self {
+			value: T::truncate(value.widen() % Self::modulus()),
+		}
+	}
+
+	/// Gets the contained integer.
+	pub fn value(&self) -> T {
+		self.value
+	}
+
+	/// Removes the contained integer.
+	pub fn into_inner(self) -> T {
+		self.value
+	}
+
+	/// Raises the number to a power, reducing modulo `N` after each step.
+	pub fn pow_mod(self, mut exp: u32) -> Self {
+		let mut base = self;
+		let mut out = Self::new(match T::try_from(1u8) {
+			Ok(one) => one,
+			Err(_) => unreachable!("All integers can represent one"),
+		});
+		while exp != 0 {
+			if exp & 1 != 0 {
+				out *= base;
+			}
+			base *= base;
+			exp >>= 1;
+		}
+		out
+	}
+
+	/// Finds the multiplicative inverse of the number, if it exists.
+	///
+	/// The inverse exists when the number and `N` are coprime. It is the
+	/// number which, multiplied by `self`, produces `1`.
+	pub fn inverse(self) -> Option<Self> {
+		let (mut old_r, mut r) = (Self::wide(self.value), N as i128);
+		let (mut old_s, mut s) = (1i128, 0i128);
+		while r != 0 {
+			let quot = old_r / r;
+			let (next_r, next_s) = (old_r - quot * r, old_s - quot * s);
+			old_r = r;
+			old_s = s;
+			r = next_r;
+			s = next_s;
+		}
+		if old_r != 1 {
+			return None;
+		}
+		let inv = old_s.rem_euclid(N as i128);
+		T::try_from(inv).ok().map(|value| Self { value })
+	}
+
+	/// Produces the modulus in the wide type.
+	fn modulus() -> T::Wide {
+		match T::Wide::try_from(N) {
+			Ok(n) => n,
+			Err(_) => unreachable!("The modulus fits in the wide type"),
+		}
+	}
+
+	/// Converts a residue to `i128`. Residues are at most `u64::MAX`.
+	fn wide(value: T) -> i128 {
+		match value.try_into() {
+			Ok(value) => value,
+			Err(_) => unreachable!("`Widen` integers fit in `i128`"),
+		}
+	}
+}
+
+impl<T: IsUnsigned + Widen, const N: u128> PartialEq<T> for Modular<T, N> {
+	fn eq(&self, other: &T) -> bool {
+		self.value.eq(other)
+	}
+}
+
+impl<T: IsUnsigned + Widen, const N: u128> PartialOrd<T> for Modular<T, N> {
+	fn partial_cmp(&self, other: &T) -> Option<Ordering> {
+		self.value.partial_cmp(other)
+	}
+}
+
+impl<T: IsUnsigned + Widen, const N: u128> AsRef<T> for Modular<T, N> {
+	fn as_ref(&self) -> &T {
+		&self.value
+	}
+}
+
+impl<T: IsUnsigned + Widen, const N: u128> Default for Modular<T, N> {
+	/// Produces zero, rejecting an invalid modulus as [`Modular::new`] does.
+	fn default() -> Self {
+		Self::new(T::default())
+	}
+}
+
+impl<T: IsUnsigned + Widen, const N: u128> From<T> for Modular<T, N> {
+	fn from(value: T) -> Self {
+		Self::new(value)
+	}
+}
+
+impl<T: IsUnsigned + Widen, const N: u128> Add<Self> for Modular<T, N> {
+	type Output = Self;
+
+	fn add(self, rhs: Self) -> Self::Output {
+		let sum = self.value.widen() + rhs.value.widen();
+		Self {
+			value: T::truncate(sum % Self::modulus()),
+		}
+	}
+}
+
+impl<T: IsUnsigned + Widen, const N: u128> Sub<Self> for Modular<T, N> {
+	type Output = Self;
+
+	fn sub(self, rhs: Self) -> Self::Output {
+		self + -rhs
+	}
+}
+
+impl<T: IsUnsigned + Widen, const N: u128> Mul<Self> for Modular<T, N> {
+	type Output = Self;
+
+	fn mul(self, rhs: Self) -> Self::Output {
+		let prod = self.value.widen() * rhs.value.widen();
+		Self {
+			value: T::truncate(prod % Self::modulus()),
+		}
+	}
+}
+
+/// Produces the additive inverse, so that `x + -x` is zero.
+impl<T: IsUnsigned + Widen, const N: u128> Neg for Modular<T, N> {
+	type Output = Self;
+
+	fn neg(self) -> Self::Output {
+		let modulus = Self::modulus();
+		let value = self.value.widen();
+		Self {
+			value: T::truncate((modulus - value) % modulus),
+		}
+	}
+}
+
+macro_rules! op {
+	($($trait:ident $func:ident $assign:ident $assign_func:ident);+ $(;)?) => { $(
+		impl<T: IsUnsigned + Widen, const N: u128> $trait<&Self> for Modular<T, N> {
+			type Output = Self;
+
+			fn $func(self, rhs: &Self) -> Self::Output {
+				$trait::$func(self, *rhs)
+			}
+		}
+
+		impl<T: IsUnsigned + Widen, const N: u128> $trait<T> for Modular<T, N> {
+			type Output = Self;
+
+			fn $func(self, rhs: T) -> Self::Output {
+				$trait::$func(self, Self::new(rhs))
+			}
+		}
+
+		impl<T: IsUnsigned + Widen, const N: u128> $trait<&T> for Modular<T, N> {
+			type Output = Self;
+
+			fn $func(self, rhs: &T) -> Self::Output {
+				$trait::$func(self, Self::new(*rhs))
+			}
+		}
+
+		impl<T: IsUnsigned + Widen, const N: u128> $assign<Self> for Modular<T, N> {
+			fn $assign_func(&mut self, rhs: Self) {
+				*self = $trait::$func(*self, rhs)
+			}
+		}
+
+		impl<T: IsUnsigned + Widen, const N: u128> $assign<&Self> for Modular<T, N> {
+			fn $assign_func(&mut self, rhs: &Self) {
+				*self = $trait::$func(*self, rhs)
+			}
+		}
+
+		impl<T: IsUnsigned + Widen, const N: u128> $assign<T> for Modular<T, N> {
+			fn $assign_func(&mut self, rhs: T) {
+				*self = $trait::$func(*self, rhs)
+			}
+		}
+
+		impl<T: IsUnsigned + Widen, const N: u128> $assign<&T> for Modular<T, N> {
+			fn $assign_func(&mut self, rhs: &T) {
+				*self = $trait::$func(*self, rhs)
+			}
+		}
+	)+ };
+}
+
+op! {
+	Add add AddAssign add_assign;
+	Sub sub SubAssign sub_assign;
+	Mul mul MulAssign mul_assign;
+}
+
+/** Modular exponentiation of the unsigned integers, with a modulus chosen at
+run time.
+
+Each product is formed in the integer’s [`Widen`] type before it is reduced,
+so no intermediate result can overflow, as it would in a chain of `*` and `%`
+in the narrow type. This trait is implemented for all unsigned integers except
+`u128`, and `Wrapping` has a matching [`Wrapping::pow_mod`] method.
+
+# Panics
+
+Each method panics if `modulus` is zero.
+
+# Examples
+
+```rust
+use surety::{PowMod as _, Wrapping};
+
+# #[cfg(not(feature = "no-128"))] {
+assert_eq!(3u64.pow_mod(200, 1_000_000_007), 136_318_165);
+assert_eq!(u64::MAX.pow_mod(u64::MAX, u64::MAX - 1), 1);
+# }
+assert_eq!(7u8.pow_mod(0, 1), 0);
+assert_eq!(Wrapping::new(2u32).pow_mod(10, 1000), 24);
+```
+
+[`Widen`]: crate::Widen
+[`Wrapping::pow_mod`]: crate::Wrapping::pow_mod
+**/
+pub trait PowMod: IsUnsigned + Widen {
+	/// Raises the integer to the power `exp`, modulo `modulus`.
+	fn pow_mod(self, exp: Self, modulus: Self) -> Self;
+}
+
+impl<T: IsUnsigned + Widen> PowMod for T {
+	#[track_caller]
+	fn pow_mod(self, mut exp: Self, modulus: Self) -> Self {
+		let modulus = modulus.widen();
+		let one = match T::Wide::try_from(1u8) {
+			Ok(one) => one,
+			Err(_) => unreachable!("All integers can represent one"),
+		};
+		let mut base = self.widen() % modulus;
+		let mut out = one % modulus;
+		while exp != T::ZERO {
+			if exp & T::truncate(one) != T::ZERO {
+				out = out * base % modulus;
+			}
+			base = base * base % modulus;
+			exp >>= 1;
+		}
+		T::truncate(out)
+	}
+}
+
+impl<T: PowMod> Wrapping<T> {
+	/// Raises the value to the power `exp`, modulo `modulus`, forming each
+	/// product in the wide type so that none can overflow.
+	///
+	/// # Panics
+	///
+	/// This panics if `modulus` is zero.
+	#[track_caller]
+	pub fn pow_mod(self, exp: T, modulus: T) -> Self {
+		self.value.pow_mod(exp, modulus).into()
+	}
+}
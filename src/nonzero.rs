@@ -0,0 +1,484 @@
+use core::{
+	cmp::Ordering,
+	num::{
+		NonZeroI16,
+		NonZeroI32,
+		NonZeroI64,
+		NonZeroI8,
+		NonZeroIsize,
+		NonZeroU16,
+		NonZeroU32,
+		NonZeroU64,
+		NonZeroU8,
+		NonZeroUsize,
+	},
+	ops::{
+		Add,
+		AddAssign,
+		Div,
+		DivAssign,
+		Mul,
+		MulAssign,
+		Rem,
+		RemAssign,
+		Sub,
+		SubAssign,
+	},
+};
+#[cfg(not(feature = "no-128"))]
+use core::num::{
+	NonZeroI128,
+	NonZeroU128,
+};
+
+use funty::IsUnsigned;
+
+use crate::{
+	Checked,
+	Overflowing,
+	Saturating,
+	Wrapping,
+};
+
+/** Associates a `NonZero` integer with the integer it restricts.
+
+This trait is implemented for the unsigned `NonZero` integers of the standard
+library, and is used by [`CheckedNonZero`] and [`SaturatingNonZero`] to perform
+arithmetic on the underlying integer.
+
+[`CheckedNonZero`]: crate::CheckedNonZero
+[`SaturatingNonZero`]: crate::SaturatingNonZero
+**/
+pub trait NonZeroInteger: Copy + Eq + Ord + core::hash::Hash + core::fmt::Debug {
+	/// The integer that this type restricts.
+	type Int: IsUnsigned;
+
+	/// The value one.
+	const ONE: Self;
+
+	/// Restricts an integer, if it is not zero.
+	fn new(value: Self::Int) -> Option<Self>;
+
+	/// Gets the integer.
+	fn get(self) -> Self::Int;
+}
+
+/** Performs checked arithmetic on a `NonZero` integer.
+
+This behaves as [`Checked`] does, except that a result of zero is also
+considered invalid, and poisons the number. As the `NonZero` integers have a
+niche for `None`, a `CheckedNonZero` is the same size as its integer.
+
+# Examples
+
+```rust
+use core::num::NonZeroU32;
+use surety::CheckedNonZero;
+
+let id = CheckedNonZero::new(NonZeroU32::new(2).unwrap());
+assert_eq!((id - 1).get(), Some(1));
+assert!((id - 2).is_none());
+assert_eq!(core::mem::size_of_val(&id), 4);
+
+let mut id = id;
+id.set(None);
+assert_eq!(id.as_option(), &None);
+```
+
+[`Checked`]: crate::Checked
+**/
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct CheckedNonZero<N: NonZeroInteger> {
+	/// The contained number, or `None` if it has been poisoned.
+	pub(crate) value: Option<N>,
+}
+
+impl<N: NonZeroInteger> CheckedNonZero<N> {
+	/// Wraps a `NonZero` integer for checked arithmetic.
+	pub const fn new(value: N) -> Self {
+		Self { value: Some(value) }
+	}
+
+	/// Gets the contained number, if it is valid.
+	pub const fn value(&self) -> Option<N> {
+		self.value
+	}
+
+	/// Removes the contained number, if it is valid.
+	pub const fn into_inner(self) -> Option<N> {
+		self.value
+	}
+
+	/// Borrows the contained number, which is `None` if it has been poisoned.
+	pub const fn as_option(&self) -> &Option<N> {
+		&self.value
+	}
+
+	/// Replaces the contained number. `None` poisons the value.
+	pub fn set(&mut self, value: Option<N>) {
+		self.value = value;
+	}
+
+	/// Gets the integer, if it is valid.
+	pub fn get(self) -> Option<N::Int> {
+		self.value.map(N::get)
+	}
+
+	/// Tests if the number is valid.
+	pub fn is_some(&self) -> bool {
+		self.value.is_some()
+	}
+
+	/// Tests if the number has been poisoned.
+	pub fn is_none(&self) -> bool {
+		self.value.is_none()
+	}
+
+	/// Produces the integer as the right-hand operand of an operation.
+	fn operand(self) -> Option<N::Int> {
+		self.get()
+	}
+
+	/// Applies a checked operation to the integer, and poisons the number if
+	/// it fails or produces zero.
+	fn apply(
+		self,
+		rhs: Option<N::Int>,
+		func: impl FnOnce(N::Int, N::Int) -> Option<N::Int>,
+	) -> Self
+	{
+		let value = self
+			.get()
+			.and_then(|lhs| func(lhs, rhs?))
+			.and_then(N::new);
+		Self { value }
+	}
+}
+
+impl<N: NonZeroInteger> PartialEq<N> for CheckedNonZero<N> {
+	fn eq(&self, other: &N) -> bool {
+		self.value == Some(*other)
+	}
+}
+
+impl<N: NonZeroInteger> From<N> for CheckedNonZero<N> {
+	fn from(value: N) -> Self {
+		Self::new(value)
+	}
+}
+
+impl<N: NonZeroInteger> From<Option<N>> for CheckedNonZero<N> {
+	fn from(value: Option<N>) -> Self {
+		Self { value }
+	}
+}
+
+/** Performs saturating arithmetic on a `NonZero` integer.
+
+This behaves as [`Saturating`] does, except that the lower bound is one rather
+than zero: any result which would be zero is clamped to one.
+
+# Examples
+
+```rust
+use core::num::NonZeroU8;
+use surety::SaturatingNonZero;
+
+let count = SaturatingNonZero::new(NonZeroU8::new(3).unwrap());
+assert_eq!((count - 10).get(), 1);
+assert_eq!((count * 100).get(), 255);
+assert_eq!((count / 4).get(), 1);
+```
+
+[`Saturating`]: crate::Saturating
+**/
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct SaturatingNonZero<N: NonZeroInteger> {
+	/// The contained number.
+	pub(crate) value: N,
+}
+
+impl<N: NonZeroInteger> SaturatingNonZero<N> {
+	/// Wraps a `NonZero` integer for saturating arithmetic.
+	pub const fn new(value: N) -> Self {
+		Self { value }
+	}
+
+	/// Gets the contained number.
+	pub const fn value(&self) -> N {
+		self.value
+	}
+
+	/// Removes the contained number.
+	pub const fn into_inner(self) -> N {
+		self.value
+	}
+
+	/// Replaces the contained number.
+	pub fn set(&mut self, value: N) {
+		self.value = value;
+	}
+
+	/// Gets the integer.
+	pub fn get(self) -> N::Int {
+		self.value.get()
+	}
+
+	/// Produces the integer as the right-hand operand of an operation.
+	fn operand(self) -> Option<N::Int> {
+		Some(self.get())
+	}
+
+	/// Applies a saturating operation to the integer, and clamps a result of
+	/// zero to one.
+	fn apply(
+		self,
+		rhs: Option<N::Int>,
+		func: impl FnOnce(N::Int, N::Int) -> Option<N::Int>,
+	) -> Self
+	{
+		let rhs = rhs.expect("attempt to divide by zero");
+		let value = func(self.get(), rhs).and_then(N::new).unwrap_or(N::ONE);
+		Self { value }
+	}
+}
+
+impl<N: NonZeroInteger> PartialEq<N> for SaturatingNonZero<N> {
+	fn eq(&self, other: &N) -> bool {
+		self.value.eq(other)
+	}
+}
+
+impl<N: NonZeroInteger> PartialOrd<N> for SaturatingNonZero<N> {
+	fn partial_cmp(&self, other: &N) -> Option<Ordering> {
+		self.value.partial_cmp(other)
+	}
+}
+
+impl<N: NonZeroInteger> From<N> for SaturatingNonZero<N> {
+	fn from(value: N) -> Self {
+		Self::new(value)
+	}
+}
+
+macro_rules! nonzero {
+	($($nz:ty => $int:ty),+ $(,)?) => { $(
+		impl NonZeroInteger for $nz {
+			type Int = $int;
+
+			const ONE: Self = match Self::new(1) {
+				Some(one) => one,
+				None => unreachable!(),
+			};
+
+			fn new(value: $int) -> Option<Self> {
+				Self::new(value)
+			}
+
+			fn get(self) -> $int {
+				self.get()
+			}
+		}
+
+		op!($nz, $int => {
+			Add add AddAssign add_assign
+				=> |a: $int, b| a.checked_add(b), |a: $int, b| Some(a.saturating_add(b)), Some;
+			Sub sub SubAssign sub_assign
+				=> |a: $int, b| a.checked_sub(b), |a: $int, b| Some(a.saturating_sub(b)), Some;
+			Mul mul MulAssign mul_assign
+				=> |a: $int, b| a.checked_mul(b), |a: $int, b| Some(a.saturating_mul(b)), Some;
+			Div div DivAssign div_assign
+				=> |a: $int, b| a.checked_div(b), |a: $int, b| a.checked_div(b), divisor;
+		});
+	)+ };
+}
+
+macro_rules! op {
+	($nz:ty, $int:ty => { $(
+		$trait:ident $func:ident $assign:ident $assign_func:ident
+		=> $checked:expr, $saturating:expr, $rhs:expr;
+	)+ }) => { $(
+		op!(@ CheckedNonZero, $nz, $int, $trait $func $assign $assign_func => $checked, $rhs);
+		op!(@ SaturatingNonZero, $nz, $int, $trait $func $assign $assign_func => $saturating, $rhs);
+	)+ };
+
+	(@ $w:ident, $nz:ty, $int:ty, $trait:ident $func:ident $assign:ident $assign_func:ident => $op:expr, $rhs:expr) => {
+		impl $trait<Self> for $w<$nz> {
+			type Output = Self;
+
+			fn $func(self, rhs: Self) -> Self::Output {
+				self.apply(rhs.operand(), $op)
+			}
+		}
+
+		impl $trait<$nz> for $w<$nz> {
+			type Output = Self;
+
+			fn $func(self, rhs: $nz) -> Self::Output {
+				self.apply(Some(rhs.get()), $op)
+			}
+		}
+
+		impl $trait<$int> for $w<$nz> {
+			type Output = Self;
+
+			fn $func(self, rhs: $int) -> Self::Output {
+				self.apply(($rhs)(rhs), $op)
+			}
+		}
+
+		op!(@ $w<$nz>, $trait $func $assign $assign_func, Self $nz $int);
+	};
+
+	(@ $w:ty, $trait:ident $func:ident $assign:ident $assign_func:ident, $($rhs:ty)+) => { $(
+		impl $trait<&$rhs> for $w {
+			type Output = Self;
+
+			fn $func(self, rhs: &$rhs) -> Self::Output {
+				$trait::$func(self, *rhs)
+			}
+		}
+
+		impl $assign<$rhs> for $w {
+			fn $assign_func(&mut self, rhs: $rhs) {
+				*self = $trait::$func(*self, rhs)
+			}
+		}
+
+		impl $assign<&$rhs> for $w {
+			fn $assign_func(&mut self, rhs: &$rhs) {
+				*self = $trait::$func(*self, *rhs)
+			}
+		}
+	)+ };
+}
+
+/// Produces a divisor, or `None` if it is zero.
+fn divisor<T: IsUnsigned>(value: T) -> Option<T> {
+	if value == T::default() { None } else { Some(value) }
+}
+
+nonzero! {
+	NonZeroU8 => u8,
+	NonZeroU16 => u16,
+	NonZeroU32 => u32,
+	NonZeroU64 => u64,
+	NonZeroUsize => usize,
+}
+
+#[cfg(not(feature = "no-128"))]
+nonzero! {
+	NonZeroU128 => u128,
+}
+
+//  Divides the wrappers by `NonZero` integers of the same width. As the divisor
+//  is known not to be zero, these operators cannot panic. They otherwise behave
+//  as division by the integer does: `Checked` is poisoned by `MIN / -1`,
+//  `Wrapping` wraps it around, `Overflowing` wraps and flags it, and
+//  `Saturating` clamps it to `MAX`.
+macro_rules! divisor {
+	($($nz:ty => $int:ty),+ $(,)?) => { $(
+		divisor!(@ $nz, $int, Checked Wrapping Overflowing);
+
+		impl Div<$nz> for Saturating<$int> {
+			type Output = Self;
+
+			fn div(self, rhs: $nz) -> Self::Output {
+				self.value.checked_div(rhs.get()).unwrap_or(<$int>::MAX).into()
+			}
+		}
+
+		impl Rem<$nz> for Saturating<$int> {
+			type Output = Self;
+
+			fn rem(self, rhs: $nz) -> Self::Output {
+				self.value.wrapping_rem(rhs.get()).into()
+			}
+		}
+
+		divisor!(@ Saturating<$int>, $nz);
+	)+ };
+
+	(@ $nz:ty, $int:ty, $($w:ident)+) => { $(
+		impl Div<$nz> for $w<$int> {
+			type Output = Self;
+
+			fn div(self, rhs: $nz) -> Self::Output {
+				self / rhs.get()
+			}
+		}
+
+		impl Rem<$nz> for $w<$int> {
+			type Output = Self;
+
+			fn rem(self, rhs: $nz) -> Self::Output {
+				self % rhs.get()
+			}
+		}
+
+		divisor!(@ $w<$int>, $nz);
+	)+ };
+
+	(@ $w:ty, $nz:ty) => {
+		impl Div<&$nz> for $w {
+			type Output = Self;
+
+			fn div(self, rhs: &$nz) -> Self::Output {
+				self / *rhs
+			}
+		}
+
+		impl Rem<&$nz> for $w {
+			type Output = Self;
+
+			fn rem(self, rhs: &$nz) -> Self::Output {
+				self % *rhs
+			}
+		}
+
+		impl DivAssign<$nz> for $w {
+			fn div_assign(&mut self, rhs: $nz) {
+				*self = *self / rhs
+			}
+		}
+
+		impl DivAssign<&$nz> for $w {
+			fn div_assign(&mut self, rhs: &$nz) {
+				*self = *self / rhs
+			}
+		}
+
+		impl RemAssign<$nz> for $w {
+			fn rem_assign(&mut self, rhs: $nz) {
+				*self = *self % rhs
+			}
+		}
+
+		impl RemAssign<&$nz> for $w {
+			fn rem_assign(&mut self, rhs: &$nz) {
+				*self = *self % rhs
+			}
+		}
+	};
+}
+
+divisor! {
+	NonZeroI8 => i8,
+	NonZeroI16 => i16,
+	NonZeroI32 => i32,
+	NonZeroI64 => i64,
+	NonZeroIsize => isize,
+	NonZeroU8 => u8,
+	NonZeroU16 => u16,
+	NonZeroU32 => u32,
+	NonZeroU64 => u64,
+	NonZeroUsize => usize,
+}
+
+#[cfg(not(feature = "no-128"))]
+divisor! {
+	NonZeroI128 => i128,
+	NonZeroU128 => u128,
+}
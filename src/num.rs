@@ -0,0 +1,238 @@
+//! Numeric trait implementations for `num-traits`.
+//!
+//! This module is only present when the `num-traits` feature is enabled.
+//!
+//! `Saturating` and `Wrapping` implement `Zero`, `One`, `Bounded`, `NumCast`,
+//! `ToPrimitive`, and `FromPrimitive` by delegating to their contained integer.
+//! A conversion which does not fit produces `None`, as it does for the integer;
+//! use the crate’s own casts to saturate or wrap instead.
+//!
+//! `Wrapping` also implements `Num` and `PrimInt`, and their supertraits, so it
+//! can be the scalar type of generic numeric code. The `PrimInt` operations
+//! other than `pow` cannot overflow, and `pow` wraps. `Saturating` does not
+//! implement them, because it has no division or remainder.
+//!
+//! ```rust
+//! use num_traits::{FromPrimitive, PrimInt, ToPrimitive};
+//! use surety::{Saturating, Wrapping};
+//!
+//! fn set_bits<T: PrimInt>(data: &[T]) -> u32 {
+//!     data.iter().map(|val| val.count_ones()).sum()
+//! }
+//!
+//! let data = [Wrapping::new(3u8), Wrapping::new(255)];
+//! assert_eq!(set_bits(&data), 10);
+//! assert_eq!(PrimInt::pow(Wrapping::new(3u8), 6), 217);
+//!
+//! assert_eq!(Saturating::<u8>::from_i32(200), Some(Saturating::new(200)));
+//! assert!(Saturating::<u8>::from_i32(300).is_none());
+//! assert!(Saturating::new(200u8).to_i8().is_none());
+//! ```
+
+use funty::IsInteger;
+use num_traits::{
+	Bounded,
+	CheckedAdd,
+	CheckedDiv,
+	CheckedMul,
+	CheckedSub,
+	FromPrimitive,
+	Num,
+	NumCast,
+	One,
+	PrimInt,
+	Saturating as SaturatingOps,
+	ToPrimitive,
+	Zero,
+};
+
+use crate::{
+	bits,
+	Saturating,
+	Wrapping,
+};
+
+macro_rules! num {
+	($($w:ident),+ $(,)?) => { $(
+		impl<T: IsInteger> Zero for $w<T> {
+			fn zero() -> Self {
+				Self::new(T::ZERO)
+			}
+
+			fn is_zero(&self) -> bool {
+				self.value == T::ZERO
+			}
+		}
+
+		impl<T: IsInteger> One for $w<T> {
+			fn one() -> Self {
+				Self::new(bits::one())
+			}
+		}
+
+		impl<T: IsInteger> Bounded for $w<T> {
+			fn min_value() -> Self {
+				Self::new(T::MIN)
+			}
+
+			fn max_value() -> Self {
+				Self::new(T::MAX)
+			}
+		}
+
+		impl<T: IsInteger + NumCast> NumCast for $w<T> {
+			fn from<N: ToPrimitive>(num: N) -> Option<Self> {
+				<T as NumCast>::from(num).map(Self::new)
+			}
+		}
+
+		impl<T: IsInteger + ToPrimitive> ToPrimitive for $w<T> {
+			to_primitive! {
+				to_isize isize, to_i8 i8, to_i16 i16, to_i32 i32, to_i64 i64,
+				to_i128 i128, to_usize usize, to_u8 u8, to_u16 u16, to_u32 u32,
+				to_u64 u64, to_u128 u128, to_f32 f32, to_f64 f64,
+			}
+		}
+
+		impl<T: IsInteger + FromPrimitive> FromPrimitive for $w<T> {
+			from_primitive! {
+				from_isize isize, from_i8 i8, from_i16 i16, from_i32 i32,
+				from_i64 i64, from_i128 i128, from_usize usize, from_u8 u8,
+				from_u16 u16, from_u32 u32, from_u64 u64, from_u128 u128,
+				from_f32 f32, from_f64 f64,
+			}
+		}
+	)+ };
+}
+
+/// Forwards `ToPrimitive` methods to the contained integer.
+macro_rules! to_primitive {
+	($($func:ident $t:ty),+ $(,)?) => { $(
+		fn $func(&self) -> Option<$t> {
+			self.value.$func()
+		}
+	)+ };
+}
+
+/// Forwards `FromPrimitive` methods to the contained integer’s type.
+macro_rules! from_primitive {
+	($($func:ident $t:ty),+ $(,)?) => { $(
+		fn $func(num: $t) -> Option<Self> {
+			T::$func(num).map(Self::new)
+		}
+	)+ };
+}
+
+num!(Saturating, Wrapping);
+
+impl<T: IsInteger + Num> Num for Wrapping<T> {
+	type FromStrRadixErr = T::FromStrRadixErr;
+
+	fn from_str_radix(
+		text: &str,
+		radix: u32,
+	) -> Result<Self, Self::FromStrRadixErr>
+	{
+		<T as Num>::from_str_radix(text, radix).map(Self::new)
+	}
+}
+
+impl<T: IsInteger> CheckedAdd for Wrapping<T> {
+	fn checked_add(&self, rhs: &Self) -> Option<Self> {
+		IsInteger::checked_add(self.value, rhs.value).map(Self::new)
+	}
+}
+
+impl<T: IsInteger> CheckedSub for Wrapping<T> {
+	fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+		IsInteger::checked_sub(self.value, rhs.value).map(Self::new)
+	}
+}
+
+impl<T: IsInteger> CheckedMul for Wrapping<T> {
+	fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+		IsInteger::checked_mul(self.value, rhs.value).map(Self::new)
+	}
+}
+
+impl<T: IsInteger> CheckedDiv for Wrapping<T> {
+	fn checked_div(&self, rhs: &Self) -> Option<Self> {
+		IsInteger::checked_div(self.value, rhs.value).map(Self::new)
+	}
+}
+
+impl<T: IsInteger> SaturatingOps for Wrapping<T> {
+	fn saturating_add(self, rhs: Self) -> Self {
+		Self::new(IsInteger::saturating_add(self.value, rhs.value))
+	}
+
+	fn saturating_sub(self, rhs: Self) -> Self {
+		Self::new(IsInteger::saturating_sub(self.value, rhs.value))
+	}
+}
+
+impl<T: IsInteger + PrimInt> PrimInt for Wrapping<T> {
+	fn count_ones(self) -> u32 {
+		PrimInt::count_ones(self.value)
+	}
+
+	fn count_zeros(self) -> u32 {
+		PrimInt::count_zeros(self.value)
+	}
+
+	fn leading_zeros(self) -> u32 {
+		PrimInt::leading_zeros(self.value)
+	}
+
+	fn trailing_zeros(self) -> u32 {
+		PrimInt::trailing_zeros(self.value)
+	}
+
+	fn rotate_left(self, n: u32) -> Self {
+		Self::new(PrimInt::rotate_left(self.value, n))
+	}
+
+	fn rotate_right(self, n: u32) -> Self {
+		Self::new(PrimInt::rotate_right(self.value, n))
+	}
+
+	fn signed_shl(self, n: u32) -> Self {
+		Self::new(self.value.signed_shl(n))
+	}
+
+	fn signed_shr(self, n: u32) -> Self {
+		Self::new(self.value.signed_shr(n))
+	}
+
+	fn unsigned_shl(self, n: u32) -> Self {
+		Self::new(self.value.unsigned_shl(n))
+	}
+
+	fn unsigned_shr(self, n: u32) -> Self {
+		Self::new(self.value.unsigned_shr(n))
+	}
+
+	fn swap_bytes(self) -> Self {
+		Self::new(PrimInt::swap_bytes(self.value))
+	}
+
+	fn from_be(num: Self) -> Self {
+		Self::new(<T as PrimInt>::from_be(num.value))
+	}
+
+	fn from_le(num: Self) -> Self {
+		Self::new(<T as PrimInt>::from_le(num.value))
+	}
+
+	fn to_be(self) -> Self {
+		Self::new(PrimInt::to_be(self.value))
+	}
+
+	fn to_le(self) -> Self {
+		Self::new(PrimInt::to_le(self.value))
+	}
+
+	fn pow(self, exp: u32) -> Self {
+		Wrapping::pow(self, exp)
+	}
+}
@@ -0,0 +1,261 @@
+/*! Arithmetic on bare integers under an overflow policy named by its wrapper.
+
+Each function takes two bare integers, wraps the left one in the chosen policy,
+and applies the operator, so that folds and other higher-order code can select
+a policy with a type parameter instead of constructing a wrapper per element.
+The integer type is inferred from the arguments, so the policy is named as, for
+example, `ops::add::<Checked<_>>`.
+
+Division and remainder are only available for the policies which implement
+them, which excludes `Saturating`.
+
+# Examples
+
+```rust
+use surety::{ops, Checked, Saturating, Wrapping};
+
+assert!(ops::add::<Checked<_>>(250u8, 10).is_none());
+assert_eq!(ops::mul::<Saturating<_>>(16i8, 16), i8::MAX);
+assert_eq!(ops::sub::<Wrapping<_>>(0u16, 1), u16::MAX);
+
+let data = [3u32, 5, 7];
+let product = data.iter().fold(1u32, |acc, &x| {
+    ops::mul::<Wrapping<_>>(acc, x).value()
+});
+assert_eq!(product, 105);
+assert!(ops::div::<Checked<_>>(1i32, 0).is_none());
+```
+!*/
+
+use core::ops::{
+	Add,
+	Div,
+	Mul,
+	Rem,
+	Sub,
+};
+
+use funty::IsInteger;
+
+use crate::{
+	shift,
+	Checked,
+	MixedCmp,
+	Overflowing,
+	Saturating,
+	Wrapping,
+};
+
+/** An arithmetic operator, selected at run time.
+
+Each wrapper has an `eval` function which applies an `Op` under its own policy,
+for interpreters, calculators, and constant folders which choose the operator
+from their input.
+
+# Examples
+
+```rust
+use surety::{ops::Op, Checked, Saturating};
+
+let program = [(Op::Mul, 10), (Op::Add, 7), (Op::Shl, 4)];
+let run = |start: i16| {
+    program.iter().fold(Checked::new(start), |acc, &(op, rhs)| {
+        Checked::eval(op, acc, rhs.into())
+    })
+};
+assert_eq!(run(5), Some(912));
+assert!(run(5000).is_none());
+
+let sat = Saturating::eval(Op::Shl, Saturating::new(-3i8), Saturating::new(6));
+assert_eq!(sat, i8::MIN);
+```
+**/
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Op {
+	/// Addition, `+`.
+	Add,
+	/// Subtraction, `-`.
+	Sub,
+	/// Multiplication, `*`.
+	Mul,
+	/// Division, `/`.
+	Div,
+	/// Remainder, `%`.
+	Rem,
+	/// Left shift, `<<`. A negative amount shifts right.
+	Shl,
+	/// Right shift, `>>`. A negative amount shifts left.
+	Shr,
+}
+
+/** An overflow policy, named by its wrapper type.
+
+This trait is implemented by `Checked`, `Overflowing`, `Saturating`, and
+`Wrapping`, over every integer, and cannot be implemented outside this crate.
+**/
+pub trait Policy:
+	Copy
+	+ From<Self::Int>
+	+ Add<Self::Int, Output = Self>
+	+ Sub<Self::Int, Output = Self>
+	+ Mul<Self::Int, Output = Self>
+	+ sealed::Sealed
+{
+	/// The wrapped integer.
+	type Int: IsInteger;
+}
+
+macro_rules! policy {
+	($($w:ident),+ $(,)?) => { $(
+		impl<T: IsInteger> Policy for $w<T> {
+			type Int = T;
+		}
+	)+ };
+}
+
+policy!(Checked, Overflowing, Saturating, Wrapping);
+
+/// Adds two integers under the policy `W`.
+pub fn add<W: Policy>(lhs: W::Int, rhs: W::Int) -> W {
+	W::from(lhs) + rhs
+}
+
+/// Subtracts `rhs` from `lhs` under the policy `W`.
+pub fn sub<W: Policy>(lhs: W::Int, rhs: W::Int) -> W {
+	W::from(lhs) - rhs
+}
+
+/// Multiplies two integers under the policy `W`.
+pub fn mul<W: Policy>(lhs: W::Int, rhs: W::Int) -> W {
+	W::from(lhs) * rhs
+}
+
+/// Divides `lhs` by `rhs` under the policy `W`.
+///
+/// # Panics
+///
+/// This panics if `rhs` is zero, unless `W` is `Checked`, which is poisoned
+/// instead.
+pub fn div<W>(lhs: W::Int, rhs: W::Int) -> W
+where W: Policy + Div<<W as Policy>::Int, Output = W> {
+	W::from(lhs) / rhs
+}
+
+/// Computes the remainder of `lhs` divided by `rhs` under the policy `W`.
+///
+/// # Panics
+///
+/// This panics if `rhs` is zero, unless `W` is `Checked`, which is poisoned
+/// instead.
+pub fn rem<W>(lhs: W::Int, rhs: W::Int) -> W
+where W: Policy + Rem<<W as Policy>::Int, Output = W> {
+	W::from(lhs) % rhs
+}
+
+impl<T: MixedCmp> Checked<T> {
+	/// Applies an operator under the checked policy. The result is poisoned if
+	/// either operand is poisoned, or if the operation overflows, divides by
+	/// zero, or shifts by at least the bit width.
+	pub fn eval(op: Op, lhs: Self, rhs: Self) -> Self {
+		match op {
+			Op::Add => lhs + rhs,
+			Op::Sub => lhs - rhs,
+			Op::Mul => lhs * rhs,
+			Op::Div => lhs / rhs,
+			Op::Rem => lhs % rhs,
+			Op::Shl | Op::Shr => lhs.and_then(|val| {
+				let amount = rhs.value?.sign_magnitude();
+				shift::checked(val, amount, op == Op::Shl)
+			}),
+		}
+	}
+}
+
+impl<T: MixedCmp> Overflowing<T> {
+	/// Applies an operator under the overflowing policy. The result is marked
+	/// as overflowed if either operand is, or if the operation overflows.
+	///
+	/// # Panics
+	///
+	/// This panics if `op` is `Div` or `Rem` and `rhs` is zero.
+	pub fn eval(op: Op, lhs: Self, rhs: Self) -> Self {
+		match op {
+			Op::Add => lhs + rhs,
+			Op::Sub => lhs - rhs,
+			Op::Mul => lhs * rhs,
+			Op::Div => lhs / rhs,
+			Op::Rem => lhs % rhs,
+			Op::Shl | Op::Shr => lhs.bin_apply(rhs, |lval, rval| {
+				shift::shift(lval, rval.sign_magnitude(), op == Op::Shl)
+			}),
+		}
+	}
+}
+
+impl<T: MixedCmp> Saturating<T> {
+	/// Applies an operator under the saturating policy.
+	///
+	/// `Saturating` has no division or shift operators of its own, so this
+	/// defines them. Division saturates `MIN / -1` to `MAX`, and the remainder
+	/// never overflows. A left shift which loses any significant bit clamps
+	/// towards the sign of `lhs`, and a right shift by at least the bit width
+	/// produces the sign fill.
+	///
+	/// # Panics
+	///
+	/// This panics if `op` is `Div` or `Rem` and `rhs` is zero.
+	pub fn eval(op: Op, lhs: Self, rhs: Self) -> Self {
+		let (lval, rval) = (lhs.value, rhs.value);
+		match op {
+			Op::Add => lhs + rhs,
+			Op::Sub => lhs - rhs,
+			Op::Mul => lhs * rhs,
+			Op::Div => match lval.overflowing_div(rval) {
+				(_, true) => T::MAX.into(),
+				(value, false) => value.into(),
+			},
+			Op::Rem => lval.wrapping_rem(rval).into(),
+			Op::Shl | Op::Shr => {
+				shift::saturating(lval, rval.sign_magnitude(), op == Op::Shl)
+					.into()
+			},
+		}
+	}
+}
+
+impl<T: MixedCmp> Wrapping<T> {
+	/// Applies an operator under the wrapping policy.
+	///
+	/// # Panics
+	///
+	/// This panics if `op` is `Div` or `Rem` and `rhs` is zero.
+	pub fn eval(op: Op, lhs: Self, rhs: Self) -> Self {
+		match op {
+			Op::Add => lhs + rhs,
+			Op::Sub => lhs - rhs,
+			Op::Mul => lhs * rhs,
+			Op::Div => lhs / rhs,
+			Op::Rem => lhs % rhs,
+			Op::Shl | Op::Shr => {
+				let amount = rhs.value.sign_magnitude();
+				shift::shift(lhs.value, amount, op == Op::Shl).0.into()
+			},
+		}
+	}
+}
+
+mod sealed {
+	pub trait Sealed {}
+
+	impl<T: funty::IsInteger> Sealed for crate::Checked<T> {
+	}
+
+	impl<T: funty::IsInteger> Sealed for crate::Overflowing<T> {
+	}
+
+	impl<T: funty::IsInteger> Sealed for crate::Saturating<T> {
+	}
+
+	impl<T: funty::IsInteger> Sealed for crate::Wrapping<T> {
+	}
+}
@@ -1,6 +1,12 @@
 use core::{
 	cmp::Ordering,
-	convert::TryInto as _,
+	fmt::{
+		self,
+		Display,
+		Formatter,
+	},
+	iter::Sum,
+	mem,
 	ops::{
 		Add,
 		AddAssign,
@@ -25,23 +31,143 @@ use funty::{
 	IsSigned,
 };
 
+use crate::{
+	shift,
+	Checked,
+	Saturating,
+	ShiftAmount,
+	Wrapping,
+};
+
 /** Marks an integer for overflow-detecting arithmetic.
 
 This type encloses a Rust integer, and a marker `bool`. This type performs
 wrapping arithmetic, but overflows are detected and recorded until the value
 is reset. Users can freely continue to do arithmetic after overflow, and may
 choose to examine or ignore the overflow flag as desired.
+
+A negative shift amount shifts in the opposite direction. A shift whose
+magnitude is not less than the bit width of the integer shifts by the magnitude
+modulo the width, as `Wrapping` does, and sets the flag.
+
+# Performance
+
+The arithmetic operators defer to the integer’s `overflowing_*` methods, which
+compile to the processor’s own overflow or carry flag: on x86-64, an addition is
+`add`, then `setb` or `seto`, then an `or` into the sticky flag, and on AArch64
+it is `adds`, then one `csinc` which reads the flag and merges it. There are no
+comparisons or branches. The sticky flag is merged with the non-short-circuiting
+`|`, so chains of operators remain straight-line code. `benches/codegen.sh`
+checks this against the compiler’s output.
+
+# Examples
+
+```rust
+use surety::Overflowing;
+
+let num = Overflowing::new(1u8);
+assert_eq!(num >> 1u32, 0);
+assert!(!(num << 7u32).has_overflowed());
+let over = num << 9u64;
+assert_eq!(over, 2);
+assert!(over.has_overflowed());
+assert_eq!(num << -1i8, 0);
+assert_eq!(Overflowing::new(2u8) >> -2i8, 8);
+```
 **/
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
 pub struct Overflowing<T: IsInteger> {
 	/// The contained integer.
-	pub value: T,
+	pub(crate) value: T,
 	/// Marks whether an overflow has occurred. Once an overflow is detected,
 	/// this flag remains set until explicitly cleared.
-	pub has_overflowed: bool,
+	pub(crate) has_overflowed: bool,
 }
 
 impl<T: IsInteger> Overflowing<T> {
+	/// Wraps an integer for overflow-detecting arithmetic, with the overflow
+	/// flag cleared.
+	pub const fn new(value: T) -> Self {
+		Self {
+			value,
+			has_overflowed: false,
+		}
+	}
+
+	/// Wraps an integer for overflow-detecting arithmetic, with the overflow
+	/// flag set as given.
+	pub const fn from_parts(value: T, has_overflowed: bool) -> Self {
+		Self {
+			value,
+			has_overflowed,
+		}
+	}
+
+	/// Gets the contained integer, regardless of the overflow flag.
+	pub const fn value(&self) -> T {
+		self.value
+	}
+
+	/// Tests whether an overflow has occurred. Once an overflow is detected,
+	/// this flag remains set until explicitly cleared.
+	pub const fn has_overflowed(&self) -> bool {
+		self.has_overflowed
+	}
+
+	/// Unwraps the contained integer, discarding the overflow flag.
+	pub const fn into_inner(self) -> T {
+		self.value
+	}
+
+	/// Splits into the contained integer and the overflow flag.
+	pub const fn into_parts(self) -> (T, bool) {
+		(self.value, self.has_overflowed)
+	}
+
+	/// Replaces the contained integer, leaving the overflow flag unchanged.
+	pub fn set(&mut self, value: T) {
+		self.value = value;
+	}
+
+	/// Switches to checked arithmetic. The result is poisoned if an overflow
+	/// has occurred.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Overflowing;
+	///
+	/// let num = Overflowing::new(250u8) + 10;
+	/// assert!(num.to_checked().is_none());
+	/// assert_eq!(num.to_wrapping(), 4);
+	/// ```
+	pub const fn to_checked(self) -> Checked<T> {
+		if self.has_overflowed {
+			Checked { value: None }
+		}
+		else {
+			Checked::new(self.value)
+		}
+	}
+
+	/// Switches to wrapping arithmetic, from the current wrapped value. The
+	/// overflow flag is discarded.
+	pub const fn to_wrapping(self) -> Wrapping<T> {
+		Wrapping { value: self.value }
+	}
+
+	/// Switches to saturating arithmetic, from the current wrapped value. The
+	/// overflow flag is discarded, and the value is not clamped.
+	pub const fn to_saturating(self) -> Saturating<T> {
+		Saturating { value: self.value }
+	}
+
+	/// Returns the value unchanged. This exists so that generic code can call
+	/// `to_overflowing` on any of the wrappers.
+	pub const fn to_overflowing(self) -> Self {
+		self
+	}
+
 	/// Calculates the quotient of Euclidean division
 	/// `self.value.div_euclid(rhs)`.
 	///
@@ -102,6 +228,141 @@ impl<T: IsInteger> Overflowing<T> {
 		}
 	}
 
+	/// Replaces the value in place with the result of a function applied to
+	/// it.
+	///
+	/// This allows values stored in fields or behind references to be modified
+	/// without moving them out into a temporary.
+	pub fn update(&mut self, func: impl FnOnce(Self) -> Self) {
+		*self = func(*self);
+	}
+
+	/// Clears the overflow flag, keeping the current value.
+	pub fn clear_overflow(&mut self) {
+		self.has_overflowed = false;
+	}
+
+	/// Clears the overflow flag, returning whether it was set.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Overflowing;
+	///
+	/// let mut num = Overflowing::new(255u8) + 1;
+	/// assert!(num.take_overflow());
+	/// assert!(!num.take_overflow());
+	/// assert_eq!(num, 0);
+	/// ```
+	pub fn take_overflow(&mut self) -> bool {
+		mem::replace(&mut self.has_overflowed, false)
+	}
+
+	/// Adds in place, returning whether this addition alone overflowed.
+	///
+	/// The overflow flag is also set, as it is by `+=`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Overflowing;
+	///
+	/// let mut num = Overflowing::new(250u8);
+	/// assert!(num.add_assign_check(10));
+	/// assert!(!num.add_assign_check(1));
+	/// assert_eq!(num, 5);
+	/// assert!(num.has_overflowed());
+	/// ```
+	pub fn add_assign_check(&mut self, rhs: T) -> bool {
+		self.assign_check(|val| val.overflowing_add(rhs))
+	}
+
+	/// Subtracts in place, returning whether this subtraction alone
+	/// overflowed.
+	///
+	/// The overflow flag is also set, as it is by `-=`.
+	pub fn sub_assign_check(&mut self, rhs: T) -> bool {
+		self.assign_check(|val| val.overflowing_sub(rhs))
+	}
+
+	/// Multiplies in place, returning whether this multiplication alone
+	/// overflowed.
+	///
+	/// The overflow flag is also set, as it is by `*=`.
+	pub fn mul_assign_check(&mut self, rhs: T) -> bool {
+		self.assign_check(|val| val.overflowing_mul(rhs))
+	}
+
+	/// Converts into a `Result`, which is `Ok` with the value if no overflow
+	/// has occurred, and `Err` with the wrapped value if one has.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Overflowing;
+	///
+	/// assert_eq!((Overflowing::new(254u8) + 1).into_result(), Ok(255));
+	/// assert_eq!((Overflowing::new(255u8) + 1).into_result(), Err(0));
+	/// ```
+	pub fn into_result(self) -> Result<T, T> {
+		if self.has_overflowed {
+			Err(self.value)
+		}
+		else {
+			Ok(self.value)
+		}
+	}
+
+	/// Converts into a `Checked`, which is poisoned if an overflow has
+	/// occurred.
+	pub fn into_checked(self) -> Checked<T> {
+		self.to_checked()
+	}
+
+	/// Unwraps the integer, panicking with `msg` if an overflow has occurred.
+	///
+	/// # Examples
+	///
+	/// ```rust,should_panic
+	/// use surety::Overflowing;
+	///
+	/// let total = Overflowing::new(200u8) + 100;
+	/// total.expect_no_overflow("the total must fit in a byte");
+	/// ```
+	#[track_caller]
+	pub fn expect_no_overflow(self, msg: &str) -> T {
+		if self.has_overflowed {
+			panic!("{}: wrapped to {}", msg, self.value);
+		}
+		self.value
+	}
+
+	/// Unwraps the integer, panicking if an overflow has occurred.
+	///
+	/// This turns the advisory overflow flag into a hard failure at a chosen
+	/// checkpoint, such as the end of a test or a debug-build assertion.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Overflowing;
+	///
+	/// let total = Overflowing::new(200u8) + 50;
+	/// assert_eq!(total.assert_clean(), 250);
+	/// ```
+	#[track_caller]
+	pub fn assert_clean(self) -> T {
+		self.expect_no_overflow("an arithmetic operation overflowed")
+	}
+
+	/// Transforms the value with a function, keeping the overflow flag.
+	pub fn map<U: IsInteger>(self, func: impl FnOnce(T) -> U) -> Overflowing<U> {
+		Overflowing {
+			value: func(self.value),
+			has_overflowed: self.has_overflowed,
+		}
+	}
+
 	/// Applies an overflowing function to `self.value`.
 	fn apply(self, func: impl FnOnce(T) -> (T, bool)) -> Self {
 		let (value, ovf) = func(self.value);
@@ -111,8 +372,17 @@ impl<T: IsInteger> Overflowing<T> {
 		}
 	}
 
+	/// Applies an overflowing operation in place, returning its own overflow
+	/// flag and merging it into the sticky flag.
+	fn assign_check(&mut self, op: impl FnOnce(T) -> (T, bool)) -> bool {
+		let (value, ovf) = op(self.value);
+		self.value = value;
+		self.has_overflowed |= ovf;
+		ovf
+	}
+
 	/// Applies an overflowing function to `self.value` and `rhs.value`.
-	fn bin_apply<U: IsInteger>(
+	pub(crate) fn bin_apply<U: IsInteger>(
 		self,
 		rhs: Overflowing<U>,
 		func: impl FnOnce(T, U) -> (T, bool),
@@ -138,6 +408,36 @@ impl<T: IsInteger> PartialOrd<T> for Overflowing<T> {
 	}
 }
 
+/// Prints the integer, marking it once the overflow flag is set.
+///
+/// A clean value prints as the bare integer. An overflowed one is followed by
+/// ` (overflowed)`, or by `*` in the alternate form, `{:#}`. Formatting options
+/// such as width and fill apply to the integer alone.
+///
+/// # Examples
+///
+/// ```rust
+/// use surety::Overflowing;
+///
+/// let count = Overflowing::new(250u8);
+/// assert_eq!(format!("{}", count), "250");
+///
+/// let over = count + 10;
+/// assert_eq!(format!("{}", over), "4 (overflowed)");
+/// assert_eq!(format!("{:#}", over), "4*");
+/// assert_eq!(format!("{:>3}|", over), "  4 (overflowed)|");
+/// ```
+impl<T: IsInteger> Display for Overflowing<T> {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		Display::fmt(&self.value, fmt)?;
+		match (self.has_overflowed, fmt.alternate()) {
+			(false, _) => Ok(()),
+			(true, false) => fmt.write_str(" (overflowed)"),
+			(true, true) => fmt.write_str("*"),
+		}
+	}
+}
+
 impl<T: IsInteger> AsRef<T> for Overflowing<T> {
 	fn as_ref(&self) -> &T {
 		&self.value
@@ -168,6 +468,34 @@ impl<T: IsInteger> From<(T, bool)> for Overflowing<T> {
 	}
 }
 
+/// Starts overflow-detecting arithmetic from the current value, with the
+/// overflow flag cleared.
+impl<T: IsInteger> From<Saturating<T>> for Overflowing<T> {
+	fn from(value: Saturating<T>) -> Self {
+		value.to_overflowing()
+	}
+}
+
+/// Starts overflow-detecting arithmetic from the current value, with the
+/// overflow flag cleared.
+impl<T: IsInteger> From<Wrapping<T>> for Overflowing<T> {
+	fn from(value: Wrapping<T>) -> Self {
+		value.to_overflowing()
+	}
+}
+
+impl<T: IsInteger> Sum<Self> for Overflowing<T> {
+	fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+		iter.fold(T::default().into(), |acc, next| acc + next)
+	}
+}
+
+impl<'a, T: 'a + IsInteger> Sum<&'a Self> for Overflowing<T> {
+	fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+		iter.copied().sum()
+	}
+}
+
 impl<T: IsInteger> Add<Self> for Overflowing<T> {
 	type Output = Self;
 
@@ -456,142 +784,122 @@ impl<T: IsInteger> RemAssign<&T> for Overflowing<T> {
 	}
 }
 
-macro_rules! shift {
-	($($t:ty),* $(,)?) => { $(
-		impl<T: IsInteger> Shl<Overflowing<$t>> for Overflowing<T> {
-			type Output = Self;
-
-			fn shl(self, rhs: Overflowing<$t>) -> Self::Output {
-				self.bin_apply(rhs, |lval, rval| {
-					lval.overflowing_shl(
-						rval.try_into()
-							.expect("Could not convert the shift amount to `u32`"),
-					)
-				})
-			}
-		}
-
-		impl<T: IsInteger> Shl<&Overflowing<$t>> for Overflowing<T> {
-			type Output = Self;
-
-			fn shl(self, rhs: &Overflowing<$t>) -> Self::Output {
-				self << *rhs
-			}
-		}
-
-		impl<T: IsInteger> Shl<$t> for Overflowing<T> {
-			type Output = Self;
-
-			fn shl(self, rhs: $t) -> Self::Output {
-				self.apply(|val| {
-					val.overflowing_shl(
-						rhs.try_into()
-							.expect("Could not convert the shift amount to `u32`"),
-					)
-				})
-			}
-		}
+impl<T, U> Shl<Overflowing<U>> for Overflowing<T>
+where
+	T: IsInteger,
+	U: IsInteger + ShiftAmount,
+{
+	type Output = Self;
 
-		impl<T: IsInteger> Shl<&$t> for Overflowing<T> {
-			type Output = Self;
+	fn shl(self, rhs: Overflowing<U>) -> Self::Output {
+		self.bin_apply(rhs, |lval, rval| {
+			shift::shift(lval, rval.shift_magnitude(), true)
+		})
+	}
+}
 
-			fn shl(self, rhs: &$t) -> Self::Output {
-				self << *rhs
-			}
-		}
+impl<T, U> Shl<&Overflowing<U>> for Overflowing<T>
+where
+	T: IsInteger,
+	U: IsInteger + ShiftAmount,
+{
+	type Output = Self;
 
-		impl<T: IsInteger> ShlAssign<Overflowing<$t>> for Overflowing<T> {
-			fn shl_assign(&mut self, rhs: Overflowing<$t>) {
-				*self = *self << rhs
-			}
-		}
+	fn shl(self, rhs: &Overflowing<U>) -> Self::Output {
+		self << *rhs
+	}
+}
 
-		impl<T: IsInteger> ShlAssign<&Overflowing<$t>> for Overflowing<T> {
-			fn shl_assign(&mut self, rhs: &Overflowing<$t>) {
-				*self = *self << rhs
-			}
-		}
+impl<T: IsInteger, U: ShiftAmount> Shl<U> for Overflowing<T> {
+	type Output = Self;
 
-		impl<T: IsInteger> ShlAssign<$t> for Overflowing<T> {
-			fn shl_assign(&mut self, rhs: $t) {
-				*self = *self << rhs
-			}
-		}
+	fn shl(self, rhs: U) -> Self::Output {
+		self.apply(|val| shift::shift(val, rhs.shift_magnitude(), true))
+	}
+}
 
-		impl<T: IsInteger> ShlAssign<&$t> for Overflowing<T> {
-			fn shl_assign(&mut self, rhs: &$t) {
-				*self = *self << rhs
-			}
-		}
+impl<T, U> ShlAssign<Overflowing<U>> for Overflowing<T>
+where
+	T: IsInteger,
+	U: IsInteger + ShiftAmount,
+{
+	fn shl_assign(&mut self, rhs: Overflowing<U>) {
+		*self = *self << rhs
+	}
+}
 
-		impl<T: IsInteger> Shr<Overflowing<$t>> for Overflowing<T> {
-			type Output = Self;
-
-			fn shr(self, rhs: Overflowing<$t>) -> Self::Output {
-				self.bin_apply(rhs, |lval, rval| {
-					lval.overflowing_shr(
-						rval.try_into()
-							.expect("Could not convert the shift amount to `u32`"),
-					)
-				})
-			}
-		}
+impl<T, U> ShlAssign<&Overflowing<U>> for Overflowing<T>
+where
+	T: IsInteger,
+	U: IsInteger + ShiftAmount,
+{
+	fn shl_assign(&mut self, rhs: &Overflowing<U>) {
+		*self = *self << rhs
+	}
+}
 
-		impl<T: IsInteger> Shr<&Overflowing<$t>> for Overflowing<T> {
-			type Output = Self;
+impl<T: IsInteger, U: ShiftAmount> ShlAssign<U> for Overflowing<T> {
+	fn shl_assign(&mut self, rhs: U) {
+		*self = *self << rhs
+	}
+}
 
-			fn shr(self, rhs: &Overflowing<$t>) -> Self::Output {
-				self >> *rhs
-			}
-		}
+impl<T, U> Shr<Overflowing<U>> for Overflowing<T>
+where
+	T: IsInteger,
+	U: IsInteger + ShiftAmount,
+{
+	type Output = Self;
 
-		impl<T: IsInteger> Shr<$t> for Overflowing<T> {
-			type Output = Self;
-
-			fn shr(self, rhs: $t) -> Self::Output {
-				self.apply(|val| {
-					val.overflowing_shl(
-						rhs.try_into()
-							.expect("Could not convert the shift amount to `u32`"),
-					)
-				})
-			}
-		}
+	fn shr(self, rhs: Overflowing<U>) -> Self::Output {
+		self.bin_apply(rhs, |lval, rval| {
+			shift::shift(lval, rval.shift_magnitude(), false)
+		})
+	}
+}
 
-		impl<T: IsInteger> Shr<&$t> for Overflowing<T> {
-			type Output = Self;
+impl<T, U> Shr<&Overflowing<U>> for Overflowing<T>
+where
+	T: IsInteger,
+	U: IsInteger + ShiftAmount,
+{
+	type Output = Self;
 
-			fn shr(self, rhs: &$t) -> Self::Output {
-				self >> *rhs
-			}
-		}
+	fn shr(self, rhs: &Overflowing<U>) -> Self::Output {
+		self >> *rhs
+	}
+}
 
-		impl<T: IsInteger> ShrAssign<Overflowing<$t>> for Overflowing<T> {
-			fn shr_assign(&mut self, rhs: Overflowing<$t>) {
-				*self = *self >> rhs
-			}
-		}
+impl<T: IsInteger, U: ShiftAmount> Shr<U> for Overflowing<T> {
+	type Output = Self;
 
-		impl<T: IsInteger> ShrAssign<&Overflowing<$t>> for Overflowing<T> {
-			fn shr_assign(&mut self, rhs: &Overflowing<$t>) {
-				*self = *self >> rhs
-			}
-		}
+	fn shr(self, rhs: U) -> Self::Output {
+		self.apply(|val| shift::shift(val, rhs.shift_magnitude(), false))
+	}
+}
 
-		impl<T: IsInteger> ShrAssign<$t> for Overflowing<T> {
-			fn shr_assign(&mut self, rhs: $t) {
-				*self = *self >> rhs
-			}
-		}
+impl<T, U> ShrAssign<Overflowing<U>> for Overflowing<T>
+where
+	T: IsInteger,
+	U: IsInteger + ShiftAmount,
+{
+	fn shr_assign(&mut self, rhs: Overflowing<U>) {
+		*self = *self >> rhs
+	}
+}
 
-		impl<T: IsInteger> ShrAssign<&$t> for Overflowing<T> {
-			fn shr_assign(&mut self, rhs: &$t) {
-				*self = *self >> rhs
-			}
-		}
-	)* };
+impl<T, U> ShrAssign<&Overflowing<U>> for Overflowing<T>
+where
+	T: IsInteger,
+	U: IsInteger + ShiftAmount,
+{
+	fn shr_assign(&mut self, rhs: &Overflowing<U>) {
+		*self = *self >> rhs
+	}
 }
 
-shift!(
-	i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
-);
+impl<T: IsInteger, U: ShiftAmount> ShrAssign<U> for Overflowing<T> {
+	fn shr_assign(&mut self, rhs: U) {
+		*self = *self >> rhs
+	}
+}
@@ -0,0 +1,113 @@
+/*! Parallel reductions, built on `rayon`.
+
+This module is only present when the `rayon` feature is enabled.
+
+Sums of bare integers are computed through per-thread [`Accumulator`]s, which
+are merged without loss of precision, so the overflow policy is applied once to
+the true sum and the result does not depend on how `rayon` splits the work.
+
+Parallel iterators which yield the wrapper types can use `rayon`’s own `.sum()`
+method. Partial sums are combined with the wrapper’s own addition, so a
+`Checked` sum that is poisoned on any thread poisons the total, and an
+`Overflowing` sum that overflows on any thread marks the total as overflowed.
+
+That addition is only associative for `Wrapping`, and for `Checked` and
+`Overflowing` over unsigned integers, so only those sums are the same however
+`rayon` splits the work. A `Saturating` sum, or a `Checked` or `Overflowing` sum
+of signed integers, depends on the order in which the partial sums meet: summed
+in order, `[100i8, 100, -100]` saturates to `27`, but split after its first item
+it sums to `100`, and a `Checked` sum is poisoned in the first case but not in
+the second. Sum such items as bare integers, with [`checked_sum`] or
+[`saturating_sum`], to apply the policy once to the true sum instead.
+
+# Examples
+
+```rust
+use rayon::prelude::*;
+use surety::{par::{self, ParallelAccumulate as _}, Checked, Overflowing};
+
+let data = vec![200u8; 1000];
+assert!(par::checked_sum(&data).is_none());
+assert_eq!(par::saturating_sum(&data), 255);
+assert_eq!(data.par_iter().map(|&n| n as u32).checked_sum(), Some(200_000));
+
+let total: Checked<u8> = data.par_iter().map(|&n| Checked::from(n)).sum();
+assert!(total.is_none());
+let total: Overflowing<u8> = data.par_iter().map(|&n| Overflowing::from(n)).sum();
+assert!(total.has_overflowed());
+
+//  The bare-integer sums see the true total, `100`, on every split.
+let signed = [100i8, 100, -100];
+assert_eq!(signed.par_iter().copied().checked_sum(), Some(100));
+assert_eq!(par::saturating_sum(&signed), 100);
+```
+
+[`Accumulator`]: crate::Accumulator
+[`checked_sum`]: ParallelAccumulate::checked_sum
+[`saturating_sum`]: ParallelAccumulate::saturating_sum
+!*/
+
+use rayon::iter::{
+	IntoParallelRefIterator as _,
+	ParallelIterator,
+};
+
+use crate::{
+	Accumulator,
+	Checked,
+	Saturating,
+	Widen,
+};
+
+/// Sums a slice in parallel, producing `None` if the sum does not fit in `T`.
+pub fn checked_sum<T: Widen>(values: &[T]) -> Checked<T> {
+	values.par_iter().copied().checked_sum()
+}
+
+/// Sums a slice in parallel, clamping the sum to the range of `T`.
+pub fn saturating_sum<T: Widen>(values: &[T]) -> Saturating<T> {
+	values.par_iter().copied().saturating_sum()
+}
+
+/** Policy-aware summation of parallel iterators of integers.
+
+This trait is implemented for all parallel iterators that yield integers which
+can be summed in an [`Accumulator`].
+
+[`Accumulator`]: crate::Accumulator
+**/
+pub trait ParallelAccumulate: ParallelIterator
+where Self::Item: Widen {
+	/// Sums all items into a single accumulator.
+	fn accumulate(self) -> Accumulator<Self::Item>;
+
+	/// Sums all items, producing `None` if the sum does not fit in the item
+	/// type.
+	fn checked_sum(self) -> Checked<Self::Item>
+	where Self: Sized {
+		self.accumulate().finish_checked()
+	}
+
+	/// Sums all items, clamping the sum to the range of the item type.
+	fn saturating_sum(self) -> Saturating<Self::Item>
+	where Self: Sized {
+		self.accumulate().finish_saturating()
+	}
+}
+
+impl<I> ParallelAccumulate for I
+where
+	I: ParallelIterator,
+	I::Item: Widen,
+{
+	fn accumulate(self) -> Accumulator<Self::Item> {
+		self.fold(Accumulator::new, |mut acc, value| {
+			acc.push(value);
+			acc
+		})
+		.reduce(Accumulator::new, |mut acc, other| {
+			acc.merge(other);
+			acc
+		})
+	}
+}
@@ -0,0 +1,83 @@
+/*! Polynomial evaluation under each overflow policy.
+
+These functions evaluate a polynomial by Horner’s scheme, multiplying the
+running value by `x` and adding the next coefficient at each step. Each step is
+a fused multiply-add in the [`Widen::Wide`] type, where it cannot overflow, and
+the overflow policy is applied once per step when the result is brought back
+down into the narrow type. A product is therefore never clamped or wrapped
+before its coefficient is added to it.
+
+Coefficients are ordered from the highest degree to the constant term, so
+`[a, b, c]` is `a·x² + b·x + c`. An empty slice is the zero polynomial.
+
+# Examples
+
+```rust
+use surety::poly;
+
+//  x² - 3x + 2 at x = 10
+let coeffs = [1i16, -3, 2];
+assert_eq!(poly::checked_eval(&coeffs, 10), Some(72));
+
+//  x³ at x = 50 overflows `i16`
+let cube = [1i16, 0, 0, 0];
+assert!(poly::checked_eval(&cube, 50).is_none());
+assert_eq!(poly::saturating_eval(&cube, 50), i16::MAX);
+```
+
+[`Widen::Wide`]: crate::Widen::Wide
+!*/
+
+use crate::{
+	Checked,
+	Overflowing,
+	Saturating,
+	Widen,
+	Wrapping,
+};
+
+/// Computes `acc * x + coeff` in the wide type.
+fn step<T: Widen>(acc: T, x: T, coeff: T) -> T::Wide {
+	acc.widen() * x.widen() + coeff.widen()
+}
+
+/// Evaluates a polynomial, producing `None` if any step overflows.
+pub fn checked_eval<T: Widen>(coeffs: &[T], x: T) -> Checked<T> {
+	coeffs
+		.iter()
+		.try_fold(T::ZERO, |acc, &coeff| T::narrow(step(acc, x, coeff)))
+		.into()
+}
+
+/// Evaluates a polynomial, wrapping each step and recording whether any step
+/// overflowed.
+pub fn overflowing_eval<T: Widen>(coeffs: &[T], x: T) -> Overflowing<T> {
+	coeffs
+		.iter()
+		.fold(Overflowing::new(T::ZERO), |acc, &coeff| {
+			let wide = step(acc.value, x, coeff);
+			let mut out = acc;
+			out.value = T::truncate(wide);
+			out.has_overflowed |= T::narrow(wide).is_none();
+			out
+		})
+}
+
+/// Evaluates a polynomial, clamping each step to the range of the integer.
+pub fn saturating_eval<T: Widen>(coeffs: &[T], x: T) -> Saturating<T> {
+	let (min, max) = (T::MIN.widen(), T::MAX.widen());
+	coeffs
+		.iter()
+		.fold(T::ZERO, |acc, &coeff| {
+			T::truncate(step(acc, x, coeff).clamp(min, max))
+		})
+		.into()
+}
+
+/// Evaluates a polynomial, wrapping each step into the range of the integer.
+pub fn wrapping_eval<T: Widen>(coeffs: &[T], x: T) -> Wrapping<T> {
+	coeffs
+		.iter()
+		.fold(T::ZERO, |acc, &coeff| T::truncate(step(acc, x, coeff)))
+		.into()
+}
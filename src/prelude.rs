@@ -0,0 +1,50 @@
+/*! Commonly used names.
+
+This module re-exports the wrapper types, their constructor macros, and the
+extension traits, so that the ergonomic surface of the crate can be brought
+into scope with a single import.
+
+```rust
+use surety::prelude::*;
+
+let sum = 250u8.checked() + 10;
+assert!(sum.is_none());
+```
+!*/
+
+#[cfg(feature = "rayon")]
+pub use crate::par::ParallelAccumulate;
+pub use crate::{
+	checked,
+	overflowing,
+	saturating,
+	surety,
+	wrapping,
+	Accumulator,
+	Align,
+	Checked,
+	CheckedCast,
+	CheckedFloat,
+	CheckedNonZero,
+	Ensure,
+	EnsureOption,
+	Fixed,
+	MixedCmp,
+	Modular,
+	Overflowing,
+	PowMod,
+	Promote,
+	Ranged,
+	Saturating,
+	SaturatingCast,
+	SaturatingFloat,
+	SaturatingNonZero,
+	ToFloat,
+	ToSigned,
+	ToUnsigned,
+	TotalDiv,
+	TotalOps,
+	Widen,
+	Wrapping,
+	WrappingCast,
+};
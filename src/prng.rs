@@ -0,0 +1,88 @@
+/*! Step functions for deterministic pseudo-random generators.
+
+Linear congruential generators advance their state with a multiplication and an
+addition which are meant to wrap, and the PCG family adds a permutation of the
+old state as its output. These are the usual random sources in `no_std` and
+embedded code, and are easy to get subtly wrong: a missed wrap, an even
+increment, or output taken from the new state instead of the old one all still
+produce plausible-looking numbers.
+
+Each function takes the state as a [`Wrapping`] integer and returns the next
+state, along with the output where the generator has one. The caller keeps the
+state, so these can be built into any generator type without this crate
+imposing one.
+
+# Examples
+
+```rust
+use surety::{prng, Wrapping};
+
+//  The reference PCG32 sequence, for seed 42 on stream 54.
+let (mut state, increment) = prng::pcg32_seed(42, 54);
+let mut outputs = [0u32; 3];
+for out in &mut outputs {
+    let (next, value) = prng::pcg32_step(state, increment);
+    state = next;
+    *out = value;
+}
+assert_eq!(outputs, [0xa15c_02b7, 0x7b47_f409, 0xba1d_3330]);
+
+//  The Numerical Recipes 32-bit LCG.
+let state = prng::lcg_step(Wrapping::new(0u32), 1_664_525, 1_013_904_223);
+assert_eq!(state, 1_013_904_223);
+let state = prng::lcg_step(state, 1_664_525, 1_013_904_223);
+assert_eq!(state, 1_196_435_762);
+```
+
+[`Wrapping`]: crate::Wrapping
+!*/
+
+use funty::IsUnsigned;
+
+use crate::Wrapping;
+
+/// The multiplier of the 64-bit PCG state, from Knuth’s MMIX.
+pub const PCG_MULTIPLIER: u64 = 6_364_136_223_846_793_005;
+
+/// The increment of the default 64-bit PCG stream.
+pub const PCG_INCREMENT: u64 = 1_442_695_040_888_963_407;
+
+/// Advances a linear congruential generator, computing
+/// `state * multiplier + increment` modulo 2<sup>N</sup>.
+///
+/// The generator has the full period of 2<sup>N</sup> only when the increment
+/// is odd and `multiplier - 1` is a multiple of 4.
+pub fn lcg_step<T: IsUnsigned>(
+	state: Wrapping<T>,
+	multiplier: T,
+	increment: T,
+) -> Wrapping<T>
+{
+	state * multiplier + increment
+}
+
+/// Advances a PCG32 generator, returning the next state and a 32-bit output.
+///
+/// This is the PCG-XSH-RR generator: the state advances as a 64-bit LCG with
+/// [`PCG_MULTIPLIER`], and the output is the old state, xorshifted and rotated
+/// by its own top five bits. The increment selects the stream, and must be odd;
+/// its lowest bit is set if it is not.
+pub fn pcg32_step(state: Wrapping<u64>, increment: u64) -> (Wrapping<u64>, u32) {
+	let old = state.value;
+	let next = lcg_step(state, PCG_MULTIPLIER, increment | 1);
+	let xorshifted = (((old >> 18) ^ old) >> 27) as u32;
+	let rotation = (old >> 59) as u32;
+	(next, xorshifted.rotate_right(rotation))
+}
+
+/// Seeds a PCG32 generator, returning its initial state and the increment of
+/// the chosen stream.
+///
+/// This matches the reference `pcg32_srandom_r`, so that the same seed and
+/// stream produce the same sequence as every other PCG32 implementation.
+pub fn pcg32_seed(seed: u64, stream: u64) -> (Wrapping<u64>, u64) {
+	let increment = (stream << 1) | 1;
+	let (state, _) = pcg32_step(Wrapping::new(0), increment);
+	let (state, _) = pcg32_step(state + seed, increment);
+	(state, increment)
+}
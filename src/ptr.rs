@@ -0,0 +1,67 @@
+/*! Checked address arithmetic.
+
+Pointer offsets, buffer lengths in bytes, and end addresses are computed in
+`usize`, where an overflow silently wraps in release builds and produces a short
+buffer or an address below its base. The functions in this module perform the
+arithmetic in `Checked<usize>` instead, and additionally poison any byte count
+greater than `isize::MAX`, which [`pointer::add`] and
+[`slice::from_raw_parts`] forbid. A value which is not poisoned is therefore
+safe to pass on to those functions, as far as its size is concerned.
+
+# Examples
+
+```rust
+use surety::ptr;
+
+let buf = [0u32; 16];
+let bytes = ptr::bytes::<u32>(buf.len());
+assert_eq!(bytes, Some(64));
+assert_eq!(ptr::count::<u32>(64), Some(16));
+
+//  a count which would overflow `usize` when scaled is refused
+assert!(ptr::bytes::<u64>(usize::MAX / 4).is_none());
+
+let base = buf.as_ptr();
+let end = ptr::end(base, buf.len()).value().unwrap();
+assert_eq!(end, base as usize + 64);
+```
+
+[`pointer::add`]: https://doc.rust-lang.org/core/primitive.pointer.html#method.add
+[`slice::from_raw_parts`]: core::slice::from_raw_parts
+!*/
+
+use core::mem;
+
+use crate::Checked;
+
+/// The largest byte count that may be used as a pointer offset or slice size.
+const LIMIT: usize = isize::MAX as usize;
+
+/// Computes the size in bytes of `count` elements of `T`, which is also the
+/// byte offset of the element at index `count`.
+///
+/// The result is poisoned if it overflows `usize` or exceeds `isize::MAX`.
+pub fn bytes<T>(count: usize) -> Checked<usize> {
+	(Checked::new(count) * mem::size_of::<T>()).filter(|&bytes| bytes <= LIMIT)
+}
+
+/// Computes how many elements of `T` fill `bytes` bytes.
+///
+/// The result is poisoned if `T` is zero-sized, or if `bytes` is not a multiple
+/// of its size.
+pub fn count<T>(bytes: usize) -> Checked<usize> {
+	let size = mem::size_of::<T>();
+	if size == 0 || !bytes.is_multiple_of(size) {
+		return Checked { value: None };
+	}
+	Checked::new(bytes / size)
+}
+
+/// Computes the address one past the end of `count` elements of `T` starting
+/// at `base`.
+///
+/// The result is poisoned if the size of the elements is out of range, or if
+/// the end address overflows `usize`.
+pub fn end<T>(base: *const T, count: usize) -> Checked<usize> {
+	bytes::<T>(count).and_then(|len| (base as usize).checked_add(len))
+}
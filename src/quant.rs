@@ -0,0 +1,137 @@
+/*! Requantization of fixed-point integers.
+
+Integer inference and DSP pipelines scale an accumulator by a real factor by
+representing the factor as a fixed-point multiplier `M / 2^(N-1)` in `[0.5, 1)`,
+taking the rounded high half of the doubled product with `M`, and then shifting
+right by the factor’s remaining power of two. The final value is saturated into
+the narrower output type.
+
+[`rounding_doubling_mul_high`] is the first step. It matches the ARM `SQRDMULH`
+instruction exactly, including its one overflow: `MIN * MIN` saturates to
+`MAX`. [`rounding_shr`] is the second step, and matches the ARM `SRSHR`
+instruction, rounding ties towards positive infinity. [`requantize`] performs
+both steps and the final saturating narrowing.
+
+On AArch64, the 16- and 32-bit multiplications use the `SQRDMULH` instruction
+directly. Every other target computes the same result in the double-width
+integer.
+
+# Examples
+
+```rust
+use surety::quant;
+
+//  0.75 in Q0.31
+let three_quarters = 3 << 29;
+assert_eq!(quant::rounding_doubling_mul_high(1000i32, three_quarters), 750);
+assert_eq!(quant::rounding_doubling_mul_high(i16::MIN, i16::MIN), i16::MAX);
+
+assert_eq!(quant::rounding_shr(5i32, 1), 3);
+assert_eq!(quant::rounding_shr(-5i32, 1), -2);
+
+//  scale an accumulator by 0.75 / 4, into `i8`
+assert_eq!(quant::requantize::<_, i8>(100i32, three_quarters, 2), 19);
+assert_eq!(quant::requantize::<_, i8>(-10_000i32, three_quarters, 2), i8::MIN);
+```
+!*/
+
+use funty::IsSigned;
+
+use crate::{
+	CastFrom,
+	SaturatingCast as _,
+	Saturating,
+};
+
+/** Signed integers which can be used as fixed-point multipliers.
+
+This trait is implemented for `i8`, `i16`, `i32`, and `i64`. The `no-128`
+feature removes the `i64` implementation, which computes in `i128`.
+**/
+pub trait Quantize: IsSigned {
+	/// Computes the rounded high half of the doubled product.
+	#[doc(hidden)]
+	fn rdmulh(self, rhs: Self) -> Self;
+
+	/// Shifts right, rounding ties towards positive infinity.
+	#[doc(hidden)]
+	fn rshr(self, shift: u32) -> Self;
+}
+
+macro_rules! quantize {
+	($($t:ident => $wide:ident $(, $fast:ident)?);+ $(;)?) => { $(
+		impl Quantize for $t {
+			#[allow(unreachable_code)]
+			fn rdmulh(self, rhs: Self) -> Self {
+				$(
+					//  SAFETY: NEON is part of the AArch64 baseline.
+					#[cfg(target_arch = "aarch64")]
+					return unsafe { core::arch::aarch64::$fast(self, rhs) };
+				)?
+				if self == Self::MIN && rhs == Self::MIN {
+					Self::MAX
+				}
+				else {
+					let nudge = 1 << (Self::BITS - 1);
+					let doubled = self as $wide * rhs as $wide * 2;
+					((doubled + nudge) >> Self::BITS) as Self
+				}
+			}
+
+			fn rshr(self, shift: u32) -> Self {
+				match shift {
+					0 => self,
+					//  Every magnitude is at most half of `2^shift`, which
+					//  rounds to zero.
+					s if s >= Self::BITS => 0,
+					s => ((self as $wide + (1 << (s - 1))) >> s) as Self,
+				}
+			}
+		}
+	)+ };
+}
+
+quantize! {
+	i8 => i16;
+	i16 => i32, vqrdmulhh_s16;
+	i32 => i64, vqrdmulhs_s32;
+}
+
+#[cfg(not(feature = "no-128"))]
+quantize! {
+	i64 => i128;
+}
+
+/// Multiplies two fixed-point numbers with `N - 1` fractional bits, rounding
+/// the result and saturating its only overflow, `MIN * MIN`, to `MAX`.
+///
+/// This computes `(2 * a * b + 2^(N-1)) >> N`, where `N` is the width of `T`,
+/// as the ARM `SQRDMULH` instruction does.
+pub fn rounding_doubling_mul_high<T: Quantize>(a: T, b: T) -> Saturating<T> {
+	a.rdmulh(b).into()
+}
+
+/// Shifts right by `shift` bits, rounding to the nearest integer, and rounding
+/// ties towards positive infinity.
+///
+/// This computes `(value + 2^(shift-1)) >> shift` without overflow, as the ARM
+/// `SRSHR` instruction does. A shift of at least the width of `T` produces
+/// zero.
+pub fn rounding_shr<T: Quantize>(value: T, shift: u32) -> T {
+	value.rshr(shift)
+}
+
+/// Scales `value` by the fixed-point `multiplier` and by `2^-shift`, rounding
+/// each step, and saturates the result to the range of `U`.
+///
+/// The multiplier has `N - 1` fractional bits, where `N` is the width of `T`,
+/// so a real factor `f` in `[0.5, 1)` is passed as `f * 2^(N-1)`, and a
+/// smaller factor as that multiplier with the remaining power of two in
+/// `shift`.
+pub fn requantize<T, U>(value: T, multiplier: T, shift: u32) -> Saturating<U>
+where
+	T: Quantize,
+	U: CastFrom<T>,
+{
+	value.rdmulh(multiplier).rshr(shift).saturating_cast()
+}
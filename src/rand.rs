@@ -0,0 +1,115 @@
+/*! Random test values, built on `rand`.
+
+This module is only present when the `rand` feature is enabled.
+
+Overflow bugs live at the edges of an integer’s range, and a uniformly random
+`u64` has almost no chance of landing on or next to one. The [`Boundary`]
+distribution draws most of its values from those edges instead, so that a
+randomized test or fuzzer reaches them within a few hundred samples.
+
+# Examples
+
+```rust
+use rand::{rngs::SmallRng, RngExt as _, SeedableRng as _};
+use surety::{rand::Boundary, Checked};
+
+let mut rng = SmallRng::seed_from_u64(1);
+let samples: Vec<Checked<u64>> = (&mut rng)
+    .sample_iter(Boundary::new())
+    .take(1000)
+    .collect();
+assert!(samples.contains(&Checked::new(u64::MAX)));
+assert!(samples.contains(&Checked::new(0)));
+assert!(samples.contains(&Checked::new(1 << 32)));
+assert!(samples.iter().any(|&num| (num + num).is_none()));
+```
+!*/
+
+use core::marker::PhantomData;
+
+use ::rand::{
+	distr::Distribution,
+	Rng,
+	RngExt as _,
+};
+use funty::IsInteger;
+
+use crate::{
+	bits,
+	CastFrom,
+	Checked,
+	Overflowing,
+	Saturating,
+	Wrapping,
+};
+
+/** A distribution of integers which favors the edges of their range.
+
+One sample in eight is uniformly random over the whole range. The rest start
+from `0`, `MIN`, `MAX`, or a power of two, which is negated half of the time for
+signed integers, and then move by `-1`, `0`, or `+1`, wrapping around the range.
+This produces `MIN`, `MAX`, `0`, `±1`, `±2^k`, and their neighbors many times
+more often than uniform sampling would.
+
+The distribution produces the bare integer and every wrapper around it. A
+`Checked` sample is never poisoned, and an `Overflowing` sample has its flag
+cleared.
+**/
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Boundary<T: IsInteger> {
+	/// Marks the produced integer type.
+	_ty: PhantomData<fn() -> T>,
+}
+
+impl<T: IsInteger> Boundary<T> {
+	/// Creates the distribution.
+	pub const fn new() -> Self {
+		Self { _ty: PhantomData }
+	}
+}
+
+/// The widest integer that the uniform samples are drawn from.
+#[cfg(not(feature = "no-128"))]
+type Bits = u128;
+#[cfg(feature = "no-128")]
+type Bits = u64;
+
+impl<T: CastFrom<Bits>> Distribution<T> for Boundary<T> {
+	fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> T {
+		if rng.random_range(0 .. 8u32) == 0 {
+			return T::wrapping_from(rng.random::<Bits>());
+		}
+		let one = bits::one::<T>();
+		let base = match rng.random_range(0 .. 6u32) {
+			0 => T::ZERO,
+			1 => T::MIN,
+			2 => T::MAX,
+			_ => {
+				let power = one << rng.random_range(0 .. bits::width::<T>());
+				if T::MIN < T::ZERO && rng.random() {
+					T::ZERO.wrapping_sub(power)
+				}
+				else {
+					power
+				}
+			},
+		};
+		match rng.random_range(0 .. 3u32) {
+			0 => base.wrapping_sub(one),
+			1 => base,
+			_ => base.wrapping_add(one),
+		}
+	}
+}
+
+macro_rules! boundary {
+	($($w:ident),+ $(,)?) => { $(
+		impl<T: CastFrom<Bits>> Distribution<$w<T>> for Boundary<T> {
+			fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $w<T> {
+				$w::new(Distribution::<T>::sample(self, rng))
+			}
+		}
+	)+ };
+}
+
+boundary!(Checked, Overflowing, Saturating, Wrapping);
@@ -0,0 +1,369 @@
+use core::ops::{
+	Add,
+	AddAssign,
+	Mul,
+	MulAssign,
+	Sub,
+	SubAssign,
+};
+
+use crate::{
+	Checked,
+	Saturating,
+	Widen,
+	Wrapping,
+};
+
+/** Restricts an integer to the inclusive range `[MIN, MAX]`.
+
+The first parameter is one of the wrapper types, `Checked`, `Saturating`, or
+`Wrapping`, around the integer to be restricted, and it selects what happens
+when arithmetic leaves the range:
+
+- `Checked` poisons the number, as it does at the edges of the integer type.
+- `Saturating` clamps the result to the nearest bound.
+- `Wrapping` wraps the result around the range, so that `MAX + 1` is `MIN`.
+
+Values given to the constructor are subject to the same policy.
+
+The bounds are written as `i128` so that a single type parameter can describe
+any of the integers that implement [`Widen`]. They must lie within the range of
+the wrapped integer, and `MIN` must not exceed `MAX`.
+
+# Examples
+
+```rust
+use surety::{Checked, Ranged, Saturating, Wrapping};
+
+type Percent = Ranged<Saturating<u8>, 0, 100>;
+let pct = Percent::new(90) + 20;
+assert_eq!(pct.value(), 100);
+
+type Die = Ranged<Wrapping<u8>, 1, 6>;
+let roll = Die::new(5) + 3;
+assert_eq!(roll.value(), 2);
+
+type Month = Ranged<Checked<u8>, 1, 12>;
+assert!((Month::new(12) + 1).value().is_none());
+```
+
+[`Widen`]: crate::Widen
+**/
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+pub struct Ranged<W: RangePolicy, const MIN: i128, const MAX: i128> {
+	/// The contained wrapper, which is always within the range.
+	value: W,
+}
+
+impl<W: RangePolicy, const MIN: i128, const MAX: i128> Ranged<W, MIN, MAX> {
+	/// Rejects bounds which are out of order, or outside the range of the
+	/// integer. This is evaluated when the constructor is instantiated, so the
+	/// error is reported at build time.
+	const BOUNDS_FIT: () = {
+		let (min, max) = <W::Int as Widen>::BOUNDS;
+		assert!(
+			MIN <= MAX && MIN >= min && MAX <= max,
+			"The range bounds must be ordered and fit in the integer type",
+		);
+	};
+
+	/// Restricts an integer to the range, applying the overflow policy if it
+	/// lies outside.
+	///
+	/// Bounds which are out of order, or outside the range of the integer, fail
+	/// to compile:
+	///
+	/// ```rust,compile_fail
+	/// use surety::{Ranged, Saturating};
+	///
+	/// let _ = Ranged::<Saturating<u8>, 0, 256>::new(1);
+	/// ```
+	pub fn new(value: W::Int) -> Self {
+		let () = Self::BOUNDS_FIT;
+		let value = wide(value);
+		Self {
+			value: W::bound(value, || wrap(value, MIN, MAX), MIN, MAX),
+		}
+	}
+
+	/// Gets the contained wrapper.
+	pub fn value(&self) -> W {
+		self.value
+	}
+
+	/// Removes the contained wrapper.
+	pub fn into_inner(self) -> W {
+		self.value
+	}
+
+	/// Applies an arithmetic operation, and restricts its result to the range.
+	fn combine(self, rhs: Result<W::Int, W>, op: Op) -> Self {
+		let (lhs, rhs) = match (self.value.get(), rhs) {
+			(Ok(lhs), Ok(rhs)) => (wide(lhs), wide(rhs)),
+			(Err(value), _) | (_, Err(value)) => return Self { value },
+		};
+		let exact = match op {
+			Op::Add => lhs.saturating_add(rhs),
+			Op::Sub => lhs.saturating_sub(rhs),
+			Op::Mul => lhs.saturating_mul(rhs),
+		};
+		Self {
+			value: W::bound(
+				exact,
+				|| {
+					let span = span(MIN, MAX);
+					let (lhs, rhs) =
+						(wide_mod(lhs, span), wide_mod(rhs, span));
+					let rem = match op {
+						Op::Add => (lhs + rhs) % span,
+						Op::Sub => (lhs + span - rhs) % span,
+						Op::Mul => lhs * rhs % span,
+					};
+					wrap_mod(rem, MIN, span)
+				},
+				MIN,
+				MAX,
+			),
+		}
+	}
+}
+
+/** An overflow policy which can be applied to a [`Ranged`] integer.
+
+This trait is implemented by `Checked`, `Saturating`, and `Wrapping`, over the
+integers that implement [`Widen`], and cannot be implemented outside this
+crate.
+
+[`Ranged`]: crate::Ranged
+[`Widen`]: crate::Widen
+**/
+pub trait RangePolicy: Copy + sealed::Sealed {
+	/// The integer being restricted.
+	type Int: Widen;
+
+	/// Gets the integer, or `Err(self)` if it has been poisoned.
+	#[doc(hidden)]
+	fn get(self) -> Result<Self::Int, Self>;
+
+	/// Restricts an exact result to `[min, max]`. The `wrapped` function
+	/// produces the result wrapped around the range, if it is needed.
+	#[doc(hidden)]
+	fn bound(
+		exact: i128,
+		wrapped: impl FnOnce() -> i128,
+		min: i128,
+		max: i128,
+	) -> Self;
+}
+
+impl<T: Widen> RangePolicy for Checked<T> {
+	type Int = T;
+
+	fn get(self) -> Result<T, Self> {
+		self.value.ok_or(self)
+	}
+
+	fn bound(
+		exact: i128,
+		_: impl FnOnce() -> i128,
+		min: i128,
+		max: i128,
+	) -> Self
+	{
+		let value = if (min ..= max).contains(&exact) {
+			Some(narrow(exact))
+		}
+		else {
+			None
+		};
+		Self { value }
+	}
+}
+
+impl<T: Widen> RangePolicy for Saturating<T> {
+	type Int = T;
+
+	fn get(self) -> Result<T, Self> {
+		Ok(self.value)
+	}
+
+	fn bound(
+		exact: i128,
+		_: impl FnOnce() -> i128,
+		min: i128,
+		max: i128,
+	) -> Self
+	{
+		Self::new(narrow(exact.max(min).min(max)))
+	}
+}
+
+impl<T: Widen> RangePolicy for Wrapping<T> {
+	type Int = T;
+
+	fn get(self) -> Result<T, Self> {
+		Ok(self.value)
+	}
+
+	fn bound(
+		exact: i128,
+		wrapped: impl FnOnce() -> i128,
+		min: i128,
+		max: i128,
+	) -> Self
+	{
+		let value = if (min ..= max).contains(&exact) {
+			exact
+		}
+		else {
+			wrapped()
+		};
+		Self::new(narrow(value))
+	}
+}
+
+mod sealed {
+	pub trait Sealed {}
+
+	impl<T: crate::Widen> Sealed for crate::Checked<T> {
+	}
+
+	impl<T: crate::Widen> Sealed for crate::Saturating<T> {
+	}
+
+	impl<T: crate::Widen> Sealed for crate::Wrapping<T> {
+	}
+}
+
+/// The operations that `Ranged` implements.
+#[derive(Clone, Copy)]
+enum Op {
+	Add,
+	Sub,
+	Mul,
+}
+
+/// Converts an integer to `i128`. This is lossless for every `Widen` type.
+fn wide<T: Widen>(value: T) -> i128 {
+	match value.try_into() {
+		Ok(value) => value,
+		Err(_) => unreachable!("`Widen` integers fit in `i128`"),
+	}
+}
+
+/// Converts an in-range `i128` back to the integer type.
+fn narrow<T: Widen>(value: i128) -> T {
+	match T::try_from(value) {
+		Ok(value) => value,
+		Err(_) => unreachable!("The range bounds fit in the integer type"),
+	}
+}
+
+/// Counts the values in `[min, max]`. This is at most `2^64`.
+fn span(min: i128, max: i128) -> u128 {
+	(max - min) as u128 + 1
+}
+
+/// Reduces a number modulo the range span.
+fn wide_mod(value: i128, span: u128) -> u128 {
+	value.rem_euclid(span as i128) as u128
+}
+
+/// Finds the number in `[min, min + span)` that is congruent to a residue.
+fn wrap_mod(rem: u128, min: i128, span: u128) -> i128 {
+	min + ((rem + span - wide_mod(min, span)) % span) as i128
+}
+
+/// Wraps a number into `[min, max]`.
+fn wrap(value: i128, min: i128, max: i128) -> i128 {
+	let span = span(min, max);
+	wrap_mod(wide_mod(value, span), min, span)
+}
+
+macro_rules! op {
+	($($wrapper:ident),+ => $args:tt) => { $(
+		op!(@ $wrapper $args);
+	)+ };
+	(@ $wrapper:ident {
+		$($trait:ident $func:ident $assign:ident $assign_func:ident => $op:ident;)+
+	}) => { $(
+		impl<T: Widen, const MIN: i128, const MAX: i128> $trait<Self>
+		for Ranged<$wrapper<T>, MIN, MAX>
+		{
+			type Output = Self;
+
+			fn $func(self, rhs: Self) -> Self::Output {
+				self.combine(rhs.value.get(), Op::$op)
+			}
+		}
+
+		impl<T: Widen, const MIN: i128, const MAX: i128> $trait<&Self>
+		for Ranged<$wrapper<T>, MIN, MAX>
+		{
+			type Output = Self;
+
+			fn $func(self, rhs: &Self) -> Self::Output {
+				self.combine(rhs.value.get(), Op::$op)
+			}
+		}
+
+		impl<T: Widen, const MIN: i128, const MAX: i128> $trait<T>
+		for Ranged<$wrapper<T>, MIN, MAX>
+		{
+			type Output = Self;
+
+			fn $func(self, rhs: T) -> Self::Output {
+				self.combine(Ok(rhs), Op::$op)
+			}
+		}
+
+		impl<T: Widen, const MIN: i128, const MAX: i128> $trait<&T>
+		for Ranged<$wrapper<T>, MIN, MAX>
+		{
+			type Output = Self;
+
+			fn $func(self, rhs: &T) -> Self::Output {
+				self.combine(Ok(*rhs), Op::$op)
+			}
+		}
+
+		impl<T: Widen, const MIN: i128, const MAX: i128> $assign<Self>
+		for Ranged<$wrapper<T>, MIN, MAX>
+		{
+			fn $assign_func(&mut self, rhs: Self) {
+				*self = $trait::$func(*self, rhs)
+			}
+		}
+
+		impl<T: Widen, const MIN: i128, const MAX: i128> $assign<&Self>
+		for Ranged<$wrapper<T>, MIN, MAX>
+		{
+			fn $assign_func(&mut self, rhs: &Self) {
+				*self = $trait::$func(*self, rhs)
+			}
+		}
+
+		impl<T: Widen, const MIN: i128, const MAX: i128> $assign<T>
+		for Ranged<$wrapper<T>, MIN, MAX>
+		{
+			fn $assign_func(&mut self, rhs: T) {
+				*self = $trait::$func(*self, rhs)
+			}
+		}
+
+		impl<T: Widen, const MIN: i128, const MAX: i128> $assign<&T>
+		for Ranged<$wrapper<T>, MIN, MAX>
+		{
+			fn $assign_func(&mut self, rhs: &T) {
+				*self = $trait::$func(*self, rhs)
+			}
+		}
+	)+ };
+}
+
+op!(Checked, Saturating, Wrapping => {
+	Add add AddAssign add_assign => Add;
+	Sub sub SubAssign sub_assign => Sub;
+	Mul mul MulAssign mul_assign => Mul;
+});
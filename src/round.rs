@@ -0,0 +1,178 @@
+//! Right shifts which round to the nearest integer instead of truncating.
+
+use funty::IsInteger;
+
+use crate::{
+	bits,
+	shift::{
+		self,
+		Magnitude,
+	},
+	Checked,
+	Overflowing,
+	Saturating,
+	ShiftAmount,
+	Wrapping,
+};
+
+/** Selects how a rounding operation resolves a result exactly halfway between
+two integers.
+
+# Examples
+
+```rust
+use surety::{Ties, Wrapping};
+
+let num = Wrapping::new(-6i8);
+assert_eq!(num.rounding_shr(2u32, Ties::Away), -2);
+assert_eq!(num.rounding_shr(2u32, Ties::Even), -2);
+assert_eq!((num + 4).rounding_shr(2u32, Ties::Away), -1);
+assert_eq!((num + 4).rounding_shr(2u32, Ties::Even), 0);
+```
+**/
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Ties {
+	/// Rounds halfway results away from zero, so that `2.5` becomes `3` and
+	/// `-2.5` becomes `-3`.
+	Away,
+	/// Rounds halfway results to the even neighbor, so that `2.5` becomes `2`
+	/// and `3.5` becomes `4`. This does not bias a series of roundings.
+	Even,
+}
+
+/// Divides `value` by `2^n`, rounding to the nearest integer. `n` must be less
+/// than the width of `T`.
+fn shr<T: IsInteger>(value: T, n: u32, ties: Ties) -> T {
+	if n == 0 {
+		return value;
+	}
+	let one = bits::one::<T>();
+	//  The floor of the quotient is at most half of `T::MAX`, so adding one to
+	//  it cannot overflow.
+	let floor = value >> n;
+	let rest = value & !(!T::ZERO).wrapping_shl(n);
+	let half = one << (n - 1);
+	let up = if rest != half {
+		rest > half
+	}
+	else {
+		match ties {
+			//  The floor of a negative quotient is already away from zero.
+			Ties::Away => value >= T::ZERO,
+			Ties::Even => floor & one != T::ZERO,
+		}
+	};
+	if up { floor + one } else { floor }
+}
+
+/// Divides `value` by `2^n`, rounding to the nearest integer, for any `n`.
+fn shr_exact<T: IsInteger>(value: T, n: Magnitude, ties: Ties) -> T {
+	let width = bits::width::<T>();
+	if n < width as Magnitude {
+		return shr(value, n as u32, ties);
+	}
+	//  The quotient is in `(-1, 1)`. Only a shift by exactly the width can
+	//  reach one half, from `2^(N-1)` or from a signed `MIN`.
+	if n > width as Magnitude {
+		return T::ZERO;
+	}
+	if T::MIN < T::ZERO {
+		return match (value == T::MIN, ties) {
+			(true, Ties::Away) => !T::ZERO,
+			_ => T::ZERO,
+		};
+	}
+	let half = bits::one::<T>() << (width - 1);
+	let up = value > half || (value == half && ties == Ties::Away);
+	if up { bits::one() } else { T::ZERO }
+}
+
+impl<T: IsInteger> Checked<T> {
+	/// Shifts right, rounding the result to the nearest integer.
+	///
+	/// The amount is treated as it is by `>>`: a negative amount shifts left,
+	/// and the result is poisoned if the magnitude of the amount is not less
+	/// than the bit width of the integer.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::{Checked, Ties};
+	///
+	/// let num = Checked::new(10u8);
+	/// assert_eq!(num.rounding_shr(2u32, Ties::Away), Some(3));
+	/// assert_eq!(num.rounding_shr(2u32, Ties::Even), Some(2));
+	/// assert_eq!(num.rounding_shr(-1i32, Ties::Even), Some(20));
+	/// assert!(num.rounding_shr(8u32, Ties::Even).is_none());
+	/// ```
+	pub fn rounding_shr(self, amount: impl ShiftAmount, ties: Ties) -> Self {
+		match amount.shift_magnitude() {
+			(false, n) if n < bits::width::<T>() as Magnitude => {
+				self.map(|val| shr(val, n as u32, ties))
+			},
+			amount => self.and_then(|val| shift::checked(val, amount, false)),
+		}
+	}
+}
+
+impl<T: IsInteger> Overflowing<T> {
+	/// Shifts right, rounding the result to the nearest integer.
+	///
+	/// The amount is treated as it is by `>>`: a negative amount shifts left,
+	/// and an amount whose magnitude is not less than the bit width of the
+	/// integer is reduced modulo the width and sets the overflow flag.
+	pub fn rounding_shr(self, amount: impl ShiftAmount, ties: Ties) -> Self {
+		let (neg, n) = amount.shift_magnitude();
+		let width = bits::width::<T>() as Magnitude;
+		let (value, reduced) = if neg {
+			shift::shift(self.value, (neg, n), false)
+		}
+		else {
+			(shr(self.value, (n % width) as u32, ties), n >= width)
+		};
+		Self::from_parts(value, self.has_overflowed | reduced)
+	}
+}
+
+impl<T: IsInteger> Saturating<T> {
+	/// Shifts right, rounding the result to the nearest integer.
+	///
+	/// The amount is never reduced, so shifting by at least the bit width of
+	/// the integer rounds the exact quotient, which is zero unless a half
+	/// rounds away from it. A negative amount shifts left, clamping the result
+	/// if any significant bit is lost.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::{Saturating, Ties};
+	///
+	/// let num = Saturating::new(200u8);
+	/// assert_eq!(num.rounding_shr(8u32, Ties::Away), 1);
+	/// assert_eq!(num.rounding_shr(9u32, Ties::Away), 0);
+	/// assert_eq!(num.rounding_shr(-1i32, Ties::Away), 255);
+	/// ```
+	pub fn rounding_shr(self, amount: impl ShiftAmount, ties: Ties) -> Self {
+		match amount.shift_magnitude() {
+			(false, n) => shr_exact(self.value, n, ties),
+			(true, n) => shift::saturating(self.value, (true, n), false),
+		}
+		.into()
+	}
+}
+
+impl<T: IsInteger> Wrapping<T> {
+	/// Shifts right, rounding the result to the nearest integer.
+	///
+	/// The amount is treated as it is by `>>`: a negative amount shifts left,
+	/// and the magnitude of the amount is reduced modulo the bit width of the
+	/// integer.
+	pub fn rounding_shr(self, amount: impl ShiftAmount, ties: Ties) -> Self {
+		let (neg, n) = amount.shift_magnitude();
+		if neg {
+			return shift::shift(self.value, (neg, n), false).0.into();
+		}
+		let width = bits::width::<T>() as Magnitude;
+		shr(self.value, (n % width) as u32, ties).into()
+	}
+}
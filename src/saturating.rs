@@ -1,16 +1,38 @@
 use core::{
+	borrow::Borrow,
 	cmp::Ordering,
+	hash::{
+		Hash,
+		Hasher,
+	},
+	iter::Sum,
 	ops::{
 		Add,
 		AddAssign,
+		Deref,
+		DerefMut,
 		Mul,
 		MulAssign,
+		Neg,
+		RangeInclusive,
 		Sub,
 		SubAssign,
 	},
 };
 
-use funty::IsInteger;
+use funty::{
+	IsInteger,
+	IsSigned,
+	IsUnsigned,
+};
+
+use crate::{
+	bits,
+	Checked,
+	Overflowing,
+	ToUnsigned,
+	Wrapping,
+};
 
 /** Marks a type for saturating-overflow arithmetic.
 
@@ -25,20 +47,293 @@ This can lead to unexpected results, as unlike the `Wrapping` behavior,
 arithmetic stops at the value boundary until an operation reverses direction.
 Resumed arithmetic always begins from the boundary value, so all information
 about intermediate results is lost.
+
+As a `Saturating` integer is always valid, it dereferences to its contained
+integer, and the integer’s inherent methods can be called on it directly.
+
+It hashes, compares, and borrows as its contained integer, so a map or set keyed
+by `Saturating<T>` can be queried with a bare `T`.
 **/
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
 pub struct Saturating<T: IsInteger> {
 	/// The contained integer.
-	pub value: T,
+	pub(crate) value: T,
 }
 
 impl<T: IsInteger> Saturating<T> {
+	/// Wraps an integer for saturating-overflow arithmetic.
+	pub const fn new(value: T) -> Self {
+		Self { value }
+	}
+
+	/// Gets the contained integer.
+	pub const fn value(&self) -> T {
+		self.value
+	}
+
+	/// Unwraps the contained integer.
+	pub const fn into_inner(self) -> T {
+		self.value
+	}
+
+	/// Replaces the contained integer.
+	pub fn set(&mut self, value: T) {
+		self.value = value;
+	}
+
+	/// Switches to checked arithmetic. The result is not poisoned.
+	pub const fn to_checked(self) -> Checked<T> {
+		Checked::new(self.value)
+	}
+
+	/// Switches to wrapping arithmetic, from the current value.
+	pub const fn to_wrapping(self) -> Wrapping<T> {
+		Wrapping { value: self.value }
+	}
+
+	/// Returns the value unchanged. This exists so that generic code can call
+	/// `to_saturating` on any of the wrappers.
+	pub const fn to_saturating(self) -> Self {
+		self
+	}
+
+	/// Switches to overflow-detecting arithmetic, with the overflow flag
+	/// cleared.
+	pub const fn to_overflowing(self) -> Overflowing<T> {
+		Overflowing::new(self.value)
+	}
+
+	/// Saturating Euclidean division. Computes
+	/// `self.value.div_euclid(rhs.value)`, saturating at the numeric bounds
+	/// instead of overflowing.
+	///
+	/// # Signed Types
+	///
+	/// Saturation will only occur in `MIN / -1` on a signed type (where `MIN`
+	/// is the negative minimal value for the type). This is equivalent to
+	/// `-MIN`, a positive value that is too large to represent in the type. In
+	/// this case, this method returns `MAX`.
+	///
+	/// # Unsigned Types
+	///
+	/// Saturating division on unsigned types is just normal division, and is
+	/// exactly equal to `self / rhs`.
+	///
+	/// # Panics
+	///
+	/// This function will panic if `rhs` is 0.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Saturating;
+	///
+	/// let min = Saturating::new(i8::MIN);
+	/// assert_eq!(min.div_euclid(Saturating::new(-1)), i8::MAX);
+	/// assert_eq!(Saturating::new(-7i8).div_euclid(Saturating::new(2)), -4);
+	/// ```
+	pub fn div_euclid(self, rhs: Self) -> Self {
+		match self.value.overflowing_div_euclid(rhs.value) {
+			(_, true) => T::MAX,
+			(value, false) => value,
+		}
+		.into()
+	}
+
+	/// Saturating Euclidean remainder. Computes
+	/// `self.value.rem_euclid(rhs.value)`.
+	///
+	/// The remainder never overflows; `MIN % -1` is 0.
+	///
+	/// # Panics
+	///
+	/// This function will panic if `rhs` is 0.
+	pub fn rem_euclid(self, rhs: Self) -> Self {
+		self.value.wrapping_rem_euclid(rhs.value).into()
+	}
+
+	/// Saturating absolute value. Computes `self.value.abs()`, producing `MAX`
+	/// instead of overflowing if `self.value == T::MIN`.
+	pub fn abs(self) -> Self
+	where T: IsSigned {
+		self.value.checked_abs().unwrap_or(T::MAX).into()
+	}
+
 	/// Saturating integer exponentiation. Computes `self.value.pow(exp)`,
 	/// saturating at the numeric bounds instead of overflowing.
-	pub fn saturating_pow(self, exp: u32) -> Self {
+	pub fn pow(self, exp: u32) -> Self {
 		self.value.saturating_pow(exp).into()
 	}
+
+	/// Saturating division which does not panic. Computes
+	/// `self.value / rhs.value`, or a poisoned `Checked` if `rhs` is 0.
+	///
+	/// On a signed type, `MIN / -1` saturates to `MAX`, and is not poisoned.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Saturating;
+	///
+	/// let min = Saturating::new(i8::MIN);
+	/// assert_eq!(min.checked_div(Saturating::new(-1)), Some(i8::MAX));
+	/// assert!(min.checked_div(Saturating::new(0)).is_none());
+	/// ```
+	pub fn checked_div(self, rhs: Self) -> Checked<T> {
+		if rhs.value == T::ZERO {
+			return Checked { value: None };
+		}
+		//  Division by a nonzero integer only overflows at `MIN / -1`.
+		self.value.checked_div(rhs.value).unwrap_or(T::MAX).into()
+	}
+
+	/// Saturating remainder which does not panic. Computes
+	/// `self.value % rhs.value`, or a poisoned `Checked` if `rhs` is 0.
+	///
+	/// The remainder never overflows; `MIN % -1` is 0.
+	pub fn checked_rem(self, rhs: Self) -> Checked<T> {
+		if rhs.value == T::ZERO {
+			return Checked { value: None };
+		}
+		self.value.wrapping_rem(rhs.value).into()
+	}
+
+	/// Adds every integer in a sequence, saturating after each addition.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Saturating;
+	///
+	/// assert_eq!(Saturating::new(0i8).add_all(vec![100, 100, -50]), 77);
+	/// ```
+	pub fn add_all(self, iter: impl IntoIterator<Item = T>) -> Self {
+		iter.into_iter().fold(self.value, T::saturating_add).into()
+	}
+
+	/// Adds every integer in a slice, saturating after each addition.
+	pub fn add_slice(self, addends: &[T]) -> Self {
+		self.add_all(addends.iter().copied())
+	}
+
+	/// Clamps the integer to an inclusive range.
+	///
+	/// # Panics
+	///
+	/// This panics if the start of the range is greater than its end.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Saturating;
+	///
+	/// let volume = Saturating::new(90u8) + 20;
+	/// assert_eq!(volume.clamp_to(0 ..= 100), 100);
+	/// ```
+	pub fn clamp_to(self, range: RangeInclusive<T>) -> Self {
+		let (min, max) = range.into_inner();
+		self.value.clamp(min, max).into()
+	}
+
+	/// Replaces the value in place with the result of a function applied to
+	/// it.
+	///
+	/// This allows values stored in fields or behind references to be modified
+	/// without moving them out into a temporary.
+	pub fn update(&mut self, func: impl FnOnce(Self) -> Self) {
+		*self = func(*self);
+	}
+}
+
+impl<T: ToUnsigned> Saturating<T> {
+	/// Tests whether the value is pinned at `T::MAX`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Saturating;
+	///
+	/// let sum = Saturating::new(250u8) + 10;
+	/// assert!(sum.at_max());
+	/// assert_eq!(sum.headroom(), 0);
+	/// ```
+	pub fn at_max(&self) -> bool {
+		self.value == T::MAX
+	}
+
+	/// Tests whether the value is pinned at `T::MIN`.
+	pub fn at_min(&self) -> bool {
+		self.value == T::MIN
+	}
+
+	/// Computes how much can be added to the value before it saturates at
+	/// `T::MAX`.
+	///
+	/// This is reported in the unsigned integer of the same width, which can
+	/// hold the full range of a signed integer.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Saturating;
+	///
+	/// assert_eq!(Saturating::new(-128i8).headroom(), 255u8);
+	/// assert_eq!(Saturating::new(100u8).headroom(), 155);
+	/// ```
+	pub fn headroom(&self) -> T::Unsigned {
+		T::MAX.distance_above(self.value)
+	}
+}
+
+impl<T: IsUnsigned> Saturating<T> {
+	/// Increments the counter by one, and reports whether it is now at
+	/// `T::MAX`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Saturating;
+	///
+	/// let mut retries = Saturating::new(253u8);
+	/// assert!(!retries.bump());
+	/// assert!(retries.bump());
+	/// assert!(retries.bump());
+	/// assert_eq!(retries, 255);
+	/// ```
+	pub fn bump(&mut self) -> bool {
+		self.bump_by(bits::one())
+	}
+
+	/// Increments the counter by `amount`, and reports whether it is now at
+	/// `T::MAX`.
+	pub fn bump_by(&mut self, amount: T) -> bool {
+		*self += amount;
+		self.value == T::MAX
+	}
+
+	/// Decrements the counter by one, and reports whether it is now zero.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Saturating;
+	///
+	/// let mut tokens = Saturating::new(3u32);
+	/// assert!(!tokens.decay_by(2));
+	/// assert!(tokens.decay());
+	/// assert!(tokens.decay());
+	/// assert_eq!(tokens, 0);
+	/// ```
+	pub fn decay(&mut self) -> bool {
+		self.decay_by(bits::one())
+	}
+
+	/// Decrements the counter by `amount`, and reports whether it is now zero.
+	pub fn decay_by(&mut self, amount: T) -> bool {
+		*self -= amount;
+		self.value == T::ZERO
+	}
 }
 
 impl<T: IsInteger> PartialEq<T> for Saturating<T> {
@@ -65,12 +360,58 @@ impl<T: IsInteger> AsMut<T> for Saturating<T> {
 	}
 }
 
+impl<T: IsInteger> Borrow<T> for Saturating<T> {
+	fn borrow(&self) -> &T {
+		&self.value
+	}
+}
+
+/// Hashes exactly as the contained integer does, as `Borrow` requires.
+impl<T: IsInteger> Hash for Saturating<T> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.value.hash(state);
+	}
+}
+
+impl<T: IsInteger> Deref for Saturating<T> {
+	type Target = T;
+
+	fn deref(&self) -> &T {
+		&self.value
+	}
+}
+
+impl<T: IsInteger> DerefMut for Saturating<T> {
+	fn deref_mut(&mut self) -> &mut T {
+		&mut self.value
+	}
+}
+
 impl<T: IsInteger> From<T> for Saturating<T> {
 	fn from(value: T) -> Self {
 		Self { value }
 	}
 }
 
+/// Starts saturating arithmetic from the current value.
+impl<T: IsInteger> From<Wrapping<T>> for Saturating<T> {
+	fn from(value: Wrapping<T>) -> Self {
+		value.to_saturating()
+	}
+}
+
+impl<T: IsInteger> Sum<Self> for Saturating<T> {
+	fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+		iter.fold(T::default().into(), |acc, next| acc + next)
+	}
+}
+
+impl<'a, T: 'a + IsInteger> Sum<&'a Self> for Saturating<T> {
+	fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+		iter.copied().sum()
+	}
+}
+
 impl<T: IsInteger> Add<Self> for Saturating<T> {
 	type Output = Self;
 
@@ -183,6 +524,15 @@ impl<T: IsInteger> SubAssign<&T> for Saturating<T> {
 	}
 }
 
+/// Saturating negation. `-MIN` on a signed type produces `MAX`.
+impl<T: IsSigned> Neg for Saturating<T> {
+	type Output = Self;
+
+	fn neg(self) -> Self::Output {
+		self.value.checked_neg().unwrap_or(T::MAX).into()
+	}
+}
+
 impl<T: IsInteger> Mul<Self> for Saturating<T> {
 	type Output = Self;
 
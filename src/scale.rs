@@ -0,0 +1,236 @@
+/*! Overflow-safe interpolation and rescaling.
+
+Interpolating between two integers, or mapping a value from one range onto
+another, multiplies a difference by a ratio. Done naïvely in the integer’s own
+type, the difference or the product overflows long before the result does: a
+sensor reading mapped from `0 ..= 4095` onto `0 ..= 65535` overflows `u16` in
+the product even though every result fits.
+
+These functions compute the result exactly in a 256-bit intermediate, and only
+apply an overflow policy when it is brought back down into the integer’s type.
+The scaled offset is divided with truncation, so results round toward the start
+of the target range.
+
+# Examples
+
+```rust
+use surety::scale;
+
+//  three quarters of the way from 100 to 200
+assert_eq!(scale::lerp_checked(100u8, 200, 3, 4), Some(175));
+//  extrapolating beyond the end is poisoned, or clamped
+assert!(scale::lerp_checked(100u8, 200, 5, 2).is_none());
+assert_eq!(scale::lerp_saturating(100u8, 200, 5, 2), 255);
+
+assert_eq!(
+    scale::rescale_checked(2048u16, 0 ..= 4095, 0 ..= 65535),
+    Some(32775),
+);
+assert_eq!(scale::rescale_checked(-1i8, -128 ..= 127, 0 ..= 100), Some(49));
+```
+!*/
+
+use core::ops::RangeInclusive;
+
+use funty::IsInteger;
+
+use crate::{
+	i256,
+	Checked,
+	Overflowing,
+	Saturating,
+	Wrapping,
+};
+
+/** Integers which can be interpolated and rescaled without overflow.
+
+The integral part of every interpolation between, and rescaling onto, the
+integers up to 64 bits wide fits in 256 bits, so the final policy is always
+applied to the exact result. This trait is implemented for all Rust integers
+except `i128` and `u128`.
+**/
+pub trait Scale: IsInteger {
+	/// Converts the integer into the 256-bit intermediate.
+	#[doc(hidden)]
+	fn to_wide(self) -> i256;
+
+	/// Converts the intermediate back into the integer, if it is in range.
+	#[doc(hidden)]
+	fn from_wide(wide: i256) -> Option<Self>;
+
+	/// Converts the intermediate back into the integer, keeping only the bits
+	/// that fit.
+	#[doc(hidden)]
+	fn truncate_wide(wide: i256) -> Self;
+
+	/// Converts the intermediate back into the integer, clamping it to the
+	/// integer’s range.
+	#[doc(hidden)]
+	fn saturate_wide(wide: i256) -> Self {
+		match Self::from_wide(wide) {
+			Some(value) => value,
+			None if wide < Self::MIN.to_wide() => Self::MIN,
+			None => Self::MAX,
+		}
+	}
+}
+
+macro_rules! scale {
+	($($t:ty),+ $(,)?) => { $(
+		impl Scale for $t {
+			fn to_wide(self) -> i256 {
+				i256::from(self)
+			}
+
+			fn from_wide(wide: i256) -> Option<Self> {
+				use core::convert::TryFrom as _;
+				Self::try_from(wide).ok()
+			}
+
+			fn truncate_wide(wide: i256) -> Self {
+				wide.to_limbs()[0] as Self
+			}
+		}
+	)+ };
+}
+
+scale!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// Computes `a + (b - a) * num / den` exactly, or `None` if `den` is zero.
+fn lerp<T: Scale>(a: T, b: T, num: i256, den: i256) -> Option<i256> {
+	let zero = i256::from(0u8);
+	if den == zero {
+		return None;
+	}
+	let (a, b) = (a.to_wide(), b.to_wide());
+	Some(a + (b - a) * num / den)
+}
+
+/// Computes the position of `value` in `to` that corresponds to its position
+/// in `from`, or `None` if `from` has only one element.
+fn rescale<T: Scale>(
+	value: T,
+	from: RangeInclusive<T>,
+	to: RangeInclusive<T>,
+) -> Option<i256>
+{
+	let (lo, hi) = from.into_inner();
+	let (start, end) = to.into_inner();
+	let num = value.to_wide() - lo.to_wide();
+	let den = hi.to_wide() - lo.to_wide();
+	lerp(start, end, num, den)
+}
+
+/// Interpolates `num / den` of the way from `a` to `b`, poisoning the result
+/// if it is out of range or `den` is zero.
+pub fn lerp_checked<T: Scale>(a: T, b: T, num: T, den: T) -> Checked<T> {
+	lerp(a, b, num.to_wide(), den.to_wide())
+		.and_then(T::from_wide)
+		.into()
+}
+
+/// Interpolates `num / den` of the way from `a` to `b`, wrapping the result
+/// into range and recording whether this occurred.
+///
+/// # Panics
+///
+/// This panics if `den` is zero.
+pub fn lerp_overflowing<T: Scale>(a: T, b: T, num: T, den: T) -> Overflowing<T> {
+	overflowing(lerp(a, b, num.to_wide(), den.to_wide()))
+}
+
+/// Interpolates `num / den` of the way from `a` to `b`, clamping the result
+/// to the integer’s range.
+///
+/// # Panics
+///
+/// This panics if `den` is zero.
+pub fn lerp_saturating<T: Scale>(a: T, b: T, num: T, den: T) -> Saturating<T> {
+	T::saturate_wide(divided(lerp(a, b, num.to_wide(), den.to_wide()))).into()
+}
+
+/// Interpolates `num / den` of the way from `a` to `b`, wrapping the result
+/// into range.
+///
+/// # Panics
+///
+/// This panics if `den` is zero.
+pub fn lerp_wrapping<T: Scale>(a: T, b: T, num: T, den: T) -> Wrapping<T> {
+	T::truncate_wide(divided(lerp(a, b, num.to_wide(), den.to_wide()))).into()
+}
+
+/// Maps `value` from the range `from` onto the range `to`, poisoning the
+/// result if it is out of range or `from` has only one element.
+///
+/// A value outside `from` is extrapolated beyond `to`.
+pub fn rescale_checked<T: Scale>(
+	value: T,
+	from: RangeInclusive<T>,
+	to: RangeInclusive<T>,
+) -> Checked<T>
+{
+	rescale(value, from, to).and_then(T::from_wide).into()
+}
+
+/// Maps `value` from the range `from` onto the range `to`, wrapping the result
+/// into range and recording whether this occurred.
+///
+/// # Panics
+///
+/// This panics if `from` has only one element.
+pub fn rescale_overflowing<T: Scale>(
+	value: T,
+	from: RangeInclusive<T>,
+	to: RangeInclusive<T>,
+) -> Overflowing<T>
+{
+	overflowing(rescale(value, from, to))
+}
+
+/// Maps `value` from the range `from` onto the range `to`, clamping the result
+/// to the integer’s range.
+///
+/// # Panics
+///
+/// This panics if `from` has only one element.
+pub fn rescale_saturating<T: Scale>(
+	value: T,
+	from: RangeInclusive<T>,
+	to: RangeInclusive<T>,
+) -> Saturating<T>
+{
+	T::saturate_wide(divided(rescale(value, from, to))).into()
+}
+
+/// Maps `value` from the range `from` onto the range `to`, wrapping the result
+/// into range.
+///
+/// # Panics
+///
+/// This panics if `from` has only one element.
+pub fn rescale_wrapping<T: Scale>(
+	value: T,
+	from: RangeInclusive<T>,
+	to: RangeInclusive<T>,
+) -> Wrapping<T>
+{
+	T::truncate_wide(divided(rescale(value, from, to))).into()
+}
+
+/// Unwraps an exact result, panicking if its divisor was zero.
+#[track_caller]
+fn divided(exact: Option<i256>) -> i256 {
+	match exact {
+		Some(exact) => exact,
+		None => panic!("attempt to divide by zero"),
+	}
+}
+
+/// Wraps an exact result into range, and flags whether this changed it.
+#[track_caller]
+fn overflowing<T: Scale>(exact: Option<i256>) -> Overflowing<T> {
+	let exact = divided(exact);
+	let mut out = Overflowing::new(T::truncate_wide(exact));
+	out.has_overflowed = T::from_wide(exact).is_none();
+	out
+}
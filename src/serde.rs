@@ -0,0 +1,178 @@
+/*! Serialization helpers, built on `serde`.
+
+This module is only present when the `serde` feature is enabled.
+
+The wrappers do not implement `Serialize` or `Deserialize` themselves. Instead,
+each module here serializes one wrapper in one representation, and is selected
+on a field with `#[serde(with = "...")]`.
+!*/
+
+/** Representations of [`Overflowing`], which differ in what they keep of the
+overflow flag.
+
+- [`bare`] writes only the integer, discarding the flag, and reads an integer as
+  a clean value.
+- [`record`] writes a `{ value, overflowed }` structure.
+- [`tuple`] writes a `(value, overflowed)` pair.
+
+# Examples
+
+```rust
+use serde::{Deserialize, Serialize};
+use surety::Overflowing;
+
+#[derive(Deserialize, Serialize)]
+struct Reading {
+    #[serde(with = "surety::serde::overflowing::bare")]
+    bare: Overflowing<u8>,
+    #[serde(with = "surety::serde::overflowing::record")]
+    record: Overflowing<u8>,
+    #[serde(with = "surety::serde::overflowing::tuple")]
+    tuple: Overflowing<u8>,
+}
+
+let over = Overflowing::new(250u8) + 10;
+let reading = Reading { bare: over, record: over, tuple: over };
+let json = serde_json::to_string(&reading).unwrap();
+assert_eq!(
+    json,
+    r#"{"bare":4,"record":{"value":4,"overflowed":true},"tuple":[4,true]}"#,
+);
+
+let back: Reading = serde_json::from_str(&json).unwrap();
+assert!(!back.bare.has_overflowed());
+assert_eq!(back.record, over);
+assert_eq!(back.tuple, over);
+```
+
+[`Overflowing`]: crate::Overflowing
+**/
+pub mod overflowing {
+	/// Serializes only the integer, discarding the overflow flag.
+	pub mod bare {
+		use ::serde::{
+			Deserialize,
+			Deserializer,
+			Serialize,
+			Serializer,
+		};
+		use funty::IsInteger;
+
+		use crate::Overflowing;
+
+		/// Serializes the integer alone.
+		pub fn serialize<T, S>(
+			value: &Overflowing<T>,
+			serializer: S,
+		) -> Result<S::Ok, S::Error>
+		where
+			T: IsInteger + Serialize,
+			S: Serializer,
+		{
+			value.value.serialize(serializer)
+		}
+
+		/// Deserializes an integer, with the overflow flag cleared.
+		pub fn deserialize<'de, T, D>(
+			deserializer: D,
+		) -> Result<Overflowing<T>, D::Error>
+		where
+			T: IsInteger + Deserialize<'de>,
+			D: Deserializer<'de>,
+		{
+			T::deserialize(deserializer).map(Overflowing::new)
+		}
+	}
+
+	/// Serializes a `{ value, overflowed }` structure.
+	pub mod record {
+		use ::serde::{
+			Deserialize,
+			Deserializer,
+			Serialize,
+			Serializer,
+		};
+		use funty::IsInteger;
+
+		use crate::Overflowing;
+
+		#[derive(Deserialize, Serialize)]
+		#[serde(rename = "Overflowing")]
+		struct Record<T> {
+			value: T,
+			overflowed: bool,
+		}
+
+		/// Serializes the integer and the overflow flag as named fields.
+		pub fn serialize<T, S>(
+			value: &Overflowing<T>,
+			serializer: S,
+		) -> Result<S::Ok, S::Error>
+		where
+			T: IsInteger + Serialize,
+			S: Serializer,
+		{
+			Record {
+				value: value.value,
+				overflowed: value.has_overflowed,
+			}
+			.serialize(serializer)
+		}
+
+		/// Deserializes the integer and the overflow flag from named fields.
+		pub fn deserialize<'de, T, D>(
+			deserializer: D,
+		) -> Result<Overflowing<T>, D::Error>
+		where
+			T: IsInteger + Deserialize<'de>,
+			D: Deserializer<'de>,
+		{
+			let Record { value, overflowed } =
+				Record::deserialize(deserializer)?;
+			Ok(Overflowing {
+				value,
+				has_overflowed: overflowed,
+			})
+		}
+	}
+
+	/// Serializes a `(value, overflowed)` pair.
+	pub mod tuple {
+		use ::serde::{
+			Deserialize,
+			Deserializer,
+			Serialize,
+			Serializer,
+		};
+		use funty::IsInteger;
+
+		use crate::Overflowing;
+
+		/// Serializes the integer and the overflow flag as a pair.
+		pub fn serialize<T, S>(
+			value: &Overflowing<T>,
+			serializer: S,
+		) -> Result<S::Ok, S::Error>
+		where
+			T: IsInteger + Serialize,
+			S: Serializer,
+		{
+			(value.value, value.has_overflowed).serialize(serializer)
+		}
+
+		/// Deserializes the integer and the overflow flag from a pair.
+		pub fn deserialize<'de, T, D>(
+			deserializer: D,
+		) -> Result<Overflowing<T>, D::Error>
+		where
+			T: IsInteger + Deserialize<'de>,
+			D: Deserializer<'de>,
+		{
+			let (value, has_overflowed) = <(T, bool)>::deserialize(deserializer)?;
+			Ok(Overflowing {
+				value,
+				has_overflowed,
+			})
+		}
+	}
+}
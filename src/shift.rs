@@ -0,0 +1,162 @@
+//! Shift-amount handling shared by the wrappers.
+//!
+//! A negative shift amount shifts in the opposite direction, by its magnitude.
+//! The magnitude is then reduced modulo the bit width of the shifted integer,
+//! and the reduction is reported so that each wrapper can apply its policy.
+
+use funty::IsInteger;
+
+use crate::bits;
+
+/// The magnitude of a shift amount, wide enough for every amount type.
+#[cfg(not(feature = "no-128"))]
+pub(crate) type Magnitude = u128;
+#[cfg(feature = "no-128")]
+pub(crate) type Magnitude = u64;
+
+/// A primitive integer used as a shift amount.
+pub(crate) trait Amount: Copy {
+	/// Splits the amount into whether it is negative and its magnitude.
+	fn magnitude(self) -> (bool, Magnitude);
+}
+
+macro_rules! amount {
+	($($s:ty),+ ; $($u:ty),+ $(;)?) => {
+		$(
+			impl Amount for $s {
+				fn magnitude(self) -> (bool, Magnitude) {
+					(self < 0, self.unsigned_abs() as Magnitude)
+				}
+			}
+		)+
+		$(
+			impl Amount for $u {
+				fn magnitude(self) -> (bool, Magnitude) {
+					(false, self as Magnitude)
+				}
+			}
+		)+
+	};
+}
+
+amount!(i8, i16, i32, i64, isize; u8, u16, u32, u64, usize);
+#[cfg(not(feature = "no-128"))]
+amount!(i128; u128);
+
+/** An integer which can be the right-hand side of a wrapper’s shift operators.
+
+This trait is implemented for all of the fundamental integers, and for
+references to them, and cannot be implemented outside this crate. A negative
+amount shifts in the opposite direction.
+**/
+pub trait ShiftAmount: Copy + sealed::Sealed {
+	/// Splits the amount into whether it is negative and its magnitude.
+	#[doc(hidden)]
+	fn shift_magnitude(self) -> (bool, Magnitude);
+}
+
+macro_rules! shift_amount {
+	($($t:ty),+ $(,)?) => { $(
+		impl ShiftAmount for $t {
+			fn shift_magnitude(self) -> (bool, Magnitude) {
+				self.magnitude()
+			}
+		}
+
+		impl ShiftAmount for &$t {
+			fn shift_magnitude(self) -> (bool, Magnitude) {
+				self.magnitude()
+			}
+		}
+
+		impl sealed::Sealed for $t {
+		}
+
+		impl sealed::Sealed for &$t {
+		}
+	)+ };
+}
+
+shift_amount!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+#[cfg(not(feature = "no-128"))]
+shift_amount!(i128, u128);
+
+/// An amount which has already been split into its sign and magnitude.
+impl Amount for (bool, Magnitude) {
+	fn magnitude(self) -> (bool, Magnitude) {
+		self
+	}
+}
+
+/// Shifts `value` left if `left` is set, or right if not, reversing the
+/// direction for a negative amount.
+///
+/// The magnitude of the amount is reduced modulo the bit width of `T`, and the
+/// flag reports whether the reduction changed it.
+pub(crate) fn shift<T: IsInteger>(
+	value: T,
+	amount: impl Amount,
+	left: bool,
+) -> (T, bool)
+{
+	let (neg, magnitude) = amount.magnitude();
+	let width = bits::width::<T>() as Magnitude;
+	let masked = (magnitude % width) as u32;
+	let value = if left != neg {
+		value.wrapping_shl(masked)
+	}
+	else {
+		value.wrapping_shr(masked)
+	};
+	(value, magnitude >= width)
+}
+
+/// Shifts `value` as [`shift`] does, but clamps instead of discarding bits.
+///
+/// A left shift which loses any significant bit, including a change of sign,
+/// saturates towards the sign of `value`. A right shift by at least the bit
+/// width produces the sign fill.
+pub(crate) fn saturating<T: IsInteger>(
+	value: T,
+	amount: impl Amount,
+	left: bool,
+) -> T
+{
+	let (neg, magnitude) = amount.magnitude();
+	let width = bits::width::<T>() as Magnitude;
+	let zero = T::ZERO;
+	if left != neg {
+		if value == zero {
+			return zero;
+		}
+		let clamp = if value < zero { T::MIN } else { T::MAX };
+		if magnitude >= width {
+			return clamp;
+		}
+		let out = value << magnitude as u32;
+		if out >> magnitude as u32 == value { out } else { clamp }
+	}
+	else if magnitude >= width {
+		if value < zero { !zero } else { zero }
+	}
+	else {
+		value >> magnitude as u32
+	}
+}
+
+/// Shifts as [`shift`] does, producing `None` if the amount had to be reduced.
+pub(crate) fn checked<T: IsInteger>(
+	value: T,
+	amount: impl Amount,
+	left: bool,
+) -> Option<T>
+{
+	match shift(value, amount, left) {
+		(value, false) => Some(value),
+		(_, true) => None,
+	}
+}
+
+mod sealed {
+	pub trait Sealed {}
+}
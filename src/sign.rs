@@ -0,0 +1,93 @@
+use funty::{
+	IsInteger,
+	IsSigned,
+	IsUnsigned,
+};
+
+/** Associates an integer with the unsigned integer of the same width.
+
+The unsigned counterpart can hold the distance between any two values of the
+integer, so it is the type in which capacity and difference queries report.
+**/
+pub trait ToUnsigned: IsInteger {
+	/// The unsigned integer of the same width.
+	type Unsigned: IsUnsigned;
+
+	/// Reinterprets the bits of the integer as its unsigned counterpart.
+	fn to_unsigned_bits(self) -> Self::Unsigned;
+
+	/// Computes `self - other` as an unsigned quantity, where `self` is not less
+	/// than `other`.
+	fn distance_above(self, other: Self) -> Self::Unsigned {
+		self.wrapping_sub(other).to_unsigned_bits()
+	}
+}
+
+macro_rules! unsigned {
+	($($t:ty => $u:ty),* $(,)?) => { $(
+		impl ToUnsigned for $t {
+			type Unsigned = $u;
+
+			fn to_unsigned_bits(self) -> Self::Unsigned {
+				self as $u
+			}
+		}
+	)* };
+}
+
+unsigned!(
+	i8 => u8,
+	i16 => u16,
+	i32 => u32,
+	i64 => u64,
+	isize => usize,
+	u8 => u8,
+	u16 => u16,
+	u32 => u32,
+	u64 => u64,
+	usize => usize,
+);
+
+#[cfg(not(feature = "no-128"))]
+unsigned!(i128 => u128, u128 => u128);
+
+/** Associates an integer with the signed integer of the same width.
+
+The signed counterpart of an unsigned integer is the type in which the shortest
+difference between two points on a wrapping number line is reported.
+**/
+pub trait ToSigned: IsInteger {
+	/// The signed integer of the same width.
+	type Signed: IsSigned;
+
+	/// Reinterprets the bits of the integer as its signed counterpart.
+	fn to_signed_bits(self) -> Self::Signed;
+}
+
+macro_rules! signed {
+	($($t:ty => $s:ty),* $(,)?) => { $(
+		impl ToSigned for $t {
+			type Signed = $s;
+
+			fn to_signed_bits(self) -> Self::Signed {
+				self as $s
+			}
+		}
+	)* };
+}
+
+signed!(
+	i8 => i8,
+	i16 => i16,
+	i32 => i32,
+	i64 => i64,
+	isize => isize,
+	u8 => i8,
+	u16 => i16,
+	u32 => i32,
+	u64 => i64,
+	usize => isize,
+);
+
+#[cfg(not(feature = "no-128"))]
+signed!(i128 => i128, u128 => i128);
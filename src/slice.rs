@@ -0,0 +1,362 @@
+/*! Policy-aware kernels over slices of integers.
+
+The products of two `N`-bit integers always fit in `2N` bits, so these kernels
+multiply in the [`Widen::Wide`] type and only apply an overflow policy to the
+accumulation of the products.
+
+As with [`Iterator::zip`], the kernels which take two slices stop at the end of
+the shorter slice.
+
+# Examples
+
+```rust
+use surety::slice;
+
+let lhs = [i16::max_value(); 3];
+let rhs = [i16::max_value(); 3];
+
+//  each product fits in `i32`, and so does the sum of two,
+assert_eq!(slice::dot_checked(&lhs[.. 2], &rhs), Some(2 * 32767 * 32767));
+//  but not the sum of three.
+assert!(slice::dot_checked(&lhs, &rhs).is_none());
+assert_eq!(slice::dot_saturating(&lhs, &rhs), i32::max_value());
+```
+
+`Saturating` and `Wrapping` are `#[repr(transparent)]`, so a buffer of integers
+can also be viewed in place as a buffer of either wrapper, and back, without
+copying it.
+
+```rust
+use surety::slice;
+
+let mut samples = [250u8, 5, 128];
+for sample in slice::as_saturating_mut(&mut samples) {
+    *sample += 10;
+}
+assert_eq!(samples, [255, 15, 138]);
+```
+
+[`Widen::Wide`]: crate::Widen::Wide
+[`Iterator::zip`]: core::iter::Iterator::zip
+!*/
+
+use core::slice;
+
+use crate::{
+	Checked,
+	Overflowing,
+	Saturating,
+	Widen,
+	Wrapping,
+};
+
+use funty::IsInteger;
+
+/// Computes the products of corresponding elements in the wide type.
+fn products<'a, T: 'a + Widen>(
+	lhs: &'a [T],
+	rhs: &'a [T],
+) -> impl 'a + Iterator<Item = T::Wide>
+{
+	lhs.iter()
+		.zip(rhs.iter())
+		.map(|(&a, &b)| a.widen() * b.widen())
+}
+
+/// Computes the dot product of two slices, producing `None` if the sum of
+/// products overflows the wide type.
+pub fn dot_checked<T: Widen>(lhs: &[T], rhs: &[T]) -> Checked<T::Wide> {
+	products(lhs, rhs)
+		.try_fold(T::Wide::default(), |acc, prod| acc.checked_add(prod))
+		.into()
+}
+
+/// Computes the dot product of two slices, wrapping the sum of products at the
+/// boundary of the wide type and recording whether this occurred.
+pub fn dot_overflowing<T: Widen>(
+	lhs: &[T],
+	rhs: &[T],
+) -> Overflowing<T::Wide>
+{
+	products(lhs, rhs).fold(T::Wide::default().into(), |acc, prod| acc + prod)
+}
+
+/// Computes the dot product of two slices, saturating the sum of products at
+/// the boundary of the wide type.
+pub fn dot_saturating<T: Widen>(lhs: &[T], rhs: &[T]) -> Saturating<T::Wide> {
+	products(lhs, rhs).fold(T::Wide::default().into(), |acc, prod| acc + prod)
+}
+
+/// Computes the dot product of two slices, wrapping the sum of products at the
+/// boundary of the wide type.
+pub fn dot_wrapping<T: Widen>(lhs: &[T], rhs: &[T]) -> Wrapping<T::Wide> {
+	products(lhs, rhs).fold(T::Wide::default().into(), |acc, prod| acc + prod)
+}
+
+macro_rules! kernel {
+	($($name:ident $t:ty, $op:tt $doc:literal $(=> $dsp:path)?;)+) => { $(
+		#[doc = $doc]
+		pub fn $name(dst: &mut [Saturating<$t>], src: &[$t]) {
+			$(
+				#[cfg(all(feature = "arm-dsp", target_arch = "arm"))]
+				return $dsp(from_saturating_mut(dst), src);
+			)?
+			#[allow(unreachable_code)]
+			for (d, &s) in dst.iter_mut().zip(src) {
+				*d $op s;
+			}
+		}
+	)+ };
+}
+
+kernel! {
+	add_saturating_i16 i16, += "Adds each element of `src` to the corresponding
+element of `dst`, saturating each sum.
+
+With the `arm-dsp` feature on 32-bit ARM, this adds two elements per
+instruction." => crate::dsp::add_i16;
+	sub_saturating_i16 i16, -= "Subtracts each element of `src` from the
+corresponding element of `dst`, saturating each difference.
+
+With the `arm-dsp` feature on 32-bit ARM, this subtracts two elements per
+instruction." => crate::dsp::sub_i16;
+	add_saturating_i32 i32, += "Adds each element of `src` to the corresponding
+element of `dst`, saturating each sum.";
+	sub_saturating_i32 i32, -= "Subtracts each element of `src` from the
+corresponding element of `dst`, saturating each difference.";
+}
+
+macro_rules! view {
+	($(
+		$w:ident:
+		$as:ident $as_mut:ident
+		$from:ident $from_mut:ident
+		$to_array:ident $from_array:ident
+	);+ $(;)?) => { $(
+		/// Views a slice of integers as a slice of the wrapper, without copying.
+		pub fn $as<T: IsInteger>(values: &[T]) -> &[$w<T>] {
+			//  SAFETY: the wrapper is `#[repr(transparent)]` over `T`, so the
+			//  two slices have the same layout, and every `T` is a valid
+			//  wrapper.
+			unsafe { slice::from_raw_parts(values.as_ptr().cast(), values.len()) }
+		}
+
+		/// Views a mutable slice of integers as a mutable slice of the wrapper,
+		/// without copying.
+		pub fn $as_mut<T: IsInteger>(values: &mut [T]) -> &mut [$w<T>] {
+			//  SAFETY: as above, and the wrapper has no invariant beyond `T`'s,
+			//  so writes through the view leave valid integers.
+			unsafe {
+				slice::from_raw_parts_mut(values.as_mut_ptr().cast(), values.len())
+			}
+		}
+
+		/// Views a slice of the wrapper as a slice of its integers, without
+		/// copying.
+		pub fn $from<T: IsInteger>(values: &[$w<T>]) -> &[T] {
+			//  SAFETY: the wrapper is `#[repr(transparent)]` over `T`.
+			unsafe { slice::from_raw_parts(values.as_ptr().cast(), values.len()) }
+		}
+
+		/// Views a mutable slice of the wrapper as a mutable slice of its
+		/// integers, without copying.
+		pub fn $from_mut<T: IsInteger>(values: &mut [$w<T>]) -> &mut [T] {
+			//  SAFETY: the wrapper is `#[repr(transparent)]` over `T`.
+			unsafe {
+				slice::from_raw_parts_mut(values.as_mut_ptr().cast(), values.len())
+			}
+		}
+
+		/// Wraps every integer in an array.
+		pub fn $to_array<T: IsInteger, const N: usize>(
+			values: [T; N],
+		) -> [$w<T>; N]
+		{
+			values.map($w::new)
+		}
+
+		/// Unwraps every integer in an array.
+		pub fn $from_array<T: IsInteger, const N: usize>(
+			values: [$w<T>; N],
+		) -> [T; N]
+		{
+			values.map($w::into_inner)
+		}
+	)+ };
+}
+
+view! {
+	Saturating:
+		as_saturating as_saturating_mut
+		from_saturating from_saturating_mut
+		saturating_array from_saturating_array;
+	Wrapping:
+		as_wrapping as_wrapping_mut
+		from_wrapping from_wrapping_mut
+		wrapping_array from_wrapping_array;
+}
+
+/// Counts the `u64` words of flag bitmap needed for `len` elements of an
+/// [`OverflowingSlice`].
+pub const fn flag_words(len: usize) -> usize {
+	len.div_ceil(64)
+}
+
+/** A slice of integers with an overflow flag for each element, stored apart.
+
+A `[Overflowing<T>]` interleaves a `bool` with every integer, which at least
+doubles the size of narrow integers and keeps the compiler from processing
+several elements per instruction. This type instead borrows the integers as one
+slice and their flags as a bitmap, one bit per element packed into `u64` words,
+so the arithmetic runs over a dense buffer and each chunk of 64 results is
+merged into the bitmap at once.
+
+As with `Overflowing`, the arithmetic wraps, and each flag is sticky until it is
+cleared. The element-wise operations stop at the end of the shorter slice.
+
+# Examples
+
+```rust
+use surety::slice::{self, OverflowingSlice};
+
+let mut values = [100u8, 200, 250, 5];
+let mut flags = [0u64; slice::flag_words(4)];
+let mut data = OverflowingSlice::new(&mut values, &mut flags);
+
+data.add(&[10, 10, 10, 10]);
+assert_eq!(data.values(), &[110, 210, 4, 15]);
+assert!(!data.has_overflowed(1));
+assert!(data.has_overflowed(2));
+
+data.mul(&[1, 2]);
+assert_eq!(data.count_overflowed(), 2);
+assert_eq!(data.get(1).map(|num| num.into_parts()), Some((164, true)));
+
+data.clear_overflow();
+assert!(!data.any_overflowed());
+```
+**/
+#[derive(Debug)]
+pub struct OverflowingSlice<'a, T: IsInteger> {
+	/// The integers.
+	values: &'a mut [T],
+	/// The overflow flag of `values[i]` is bit `i % 64` of `flags[i / 64]`.
+	flags: &'a mut [u64],
+}
+
+impl<'a, T: IsInteger> OverflowingSlice<'a, T> {
+	/// Borrows a slice of integers and a bitmap for their flags, clearing
+	/// every flag.
+	///
+	/// # Panics
+	///
+	/// This panics if `flags` is shorter than [`flag_words`] of the length of
+	/// `values`.
+	pub fn new(values: &'a mut [T], flags: &'a mut [u64]) -> Self {
+		let mut this = Self::from_parts(values, flags);
+		this.clear_overflow();
+		this
+	}
+
+	/// Borrows a slice of integers and a bitmap for their flags, keeping any
+	/// flags that are already set.
+	///
+	/// # Panics
+	///
+	/// This panics if `flags` is shorter than [`flag_words`] of the length of
+	/// `values`.
+	pub fn from_parts(values: &'a mut [T], flags: &'a mut [u64]) -> Self {
+		let words = flag_words(values.len());
+		assert!(flags.len() >= words, "the flag bitmap is too short");
+		let flags = &mut flags[.. words];
+		Self { values, flags }
+	}
+
+	/// Releases the integers and the flag bitmap.
+	pub fn into_parts(self) -> (&'a mut [T], &'a mut [u64]) {
+		(self.values, self.flags)
+	}
+
+	/// Counts the elements.
+	pub fn len(&self) -> usize {
+		self.values.len()
+	}
+
+	/// Tests whether there are no elements.
+	pub fn is_empty(&self) -> bool {
+		self.values.is_empty()
+	}
+
+	/// Views the integers, without their flags.
+	pub fn values(&self) -> &[T] {
+		self.values
+	}
+
+	/// Gets one element with its flag, or `None` if `idx` is out of bounds.
+	pub fn get(&self, idx: usize) -> Option<Overflowing<T>> {
+		let value = *self.values.get(idx)?;
+		Some(Overflowing::from_parts(value, self.has_overflowed(idx)))
+	}
+
+	/// Iterates over the elements with their flags.
+	pub fn iter(&self) -> impl '_ + Iterator<Item = Overflowing<T>> {
+		(0 .. self.len()).filter_map(move |idx| self.get(idx))
+	}
+
+	/// Tests the flag of one element. This is `false` if `idx` is out of
+	/// bounds.
+	pub fn has_overflowed(&self, idx: usize) -> bool {
+		self.flags
+			.get(idx / 64)
+			.is_some_and(|word| word & (1 << (idx % 64)) != 0)
+	}
+
+	/// Tests whether any element has overflowed.
+	pub fn any_overflowed(&self) -> bool {
+		self.flags.iter().any(|&word| word != 0)
+	}
+
+	/// Counts the elements which have overflowed.
+	pub fn count_overflowed(&self) -> usize {
+		self.flags.iter().map(|word| word.count_ones() as usize).sum()
+	}
+
+	/// Clears every flag, keeping the integers.
+	pub fn clear_overflow(&mut self) {
+		self.flags.iter_mut().for_each(|word| *word = 0);
+	}
+
+	/// Adds each element of `rhs` to the corresponding element, wrapping each
+	/// sum and setting its flag if it overflows.
+	pub fn add(&mut self, rhs: &[T]) {
+		self.apply(rhs, T::overflowing_add);
+	}
+
+	/// Subtracts each element of `rhs` from the corresponding element, wrapping
+	/// each difference and setting its flag if it overflows.
+	pub fn sub(&mut self, rhs: &[T]) {
+		self.apply(rhs, T::overflowing_sub);
+	}
+
+	/// Multiplies each element by the corresponding element of `rhs`, wrapping
+	/// each product and setting its flag if it overflows.
+	pub fn mul(&mut self, rhs: &[T]) {
+		self.apply(rhs, T::overflowing_mul);
+	}
+
+	/// Applies an overflowing operation element-wise, merging each chunk of 64
+	/// flags into the bitmap at once.
+	fn apply(&mut self, rhs: &[T], op: impl Fn(T, T) -> (T, bool)) {
+		let len = self.values.len().min(rhs.len());
+		let chunks = self.values[.. len].chunks_mut(64).zip(rhs.chunks(64));
+		for ((values, rhs), word) in chunks.zip(self.flags.iter_mut()) {
+			let mut bits = 0u64;
+			for (idx, (value, &rhs)) in values.iter_mut().zip(rhs).enumerate() {
+				let (out, ovf) = op(*value, rhs);
+				*value = out;
+				bits |= (ovf as u64) << idx;
+			}
+			*word |= bits;
+		}
+	}
+}
@@ -0,0 +1,113 @@
+/*! Overflow-safe statistics over slices of integers.
+
+These functions summarize a slice with a chosen overflow policy. Sums are
+computed in the wide type through an [`Accumulator`], so the policy is applied
+to the true sum rather than to each intermediate addition, and the mean is
+computed in a type wide enough that it cannot overflow at all.
+
+Functions which have no meaningful result for an empty slice return a
+`Checked` integer which is `None` when the slice is empty.
+
+# Examples
+
+```rust
+use surety::stats;
+
+let data = [250u8, 240, 230];
+assert!(stats::checked_sum(&data).is_none());
+assert_eq!(stats::saturating_sum(&data), 255);
+assert_eq!(stats::mean(&data), Some(240));
+assert_eq!(stats::min(&data), Some(230));
+assert!(stats::max::<u8>(&[]).is_none());
+```
+
+[`Accumulator`]: crate::Accumulator
+!*/
+
+use crate::{
+	Accumulator,
+	Checked,
+	Overflowing,
+	Saturating,
+	Widen,
+	Wrapping,
+};
+
+use funty::IsInteger;
+
+/// Sums a slice, producing `None` if the sum does not fit in `T`.
+pub fn checked_sum<T: Widen>(values: &[T]) -> Checked<T> {
+	values.iter().collect::<Accumulator<T>>().finish_checked()
+}
+
+/// Sums a slice, marking the wrapped sum as overflowed if it does not fit in
+/// `T`.
+pub fn overflowing_sum<T: Widen>(values: &[T]) -> Overflowing<T> {
+	values.iter().collect::<Accumulator<T>>().finish_overflowing()
+}
+
+/// Sums a slice, clamping the sum to the range of `T`.
+pub fn saturating_sum<T: Widen>(values: &[T]) -> Saturating<T> {
+	values.iter().collect::<Accumulator<T>>().finish_saturating()
+}
+
+/// Sums a slice, discarding all bits of the sum that do not fit in `T`.
+pub fn wrapping_sum<T: Widen>(values: &[T]) -> Wrapping<T> {
+	values.iter().collect::<Accumulator<T>>().finish_wrapping()
+}
+
+/// Computes the arithmetic mean of a slice, rounded toward zero.
+///
+/// The mean of a set of integers always lies within their range, so this only
+/// produces `None` when the slice is empty.
+pub fn mean<T: Mean>(values: &[T]) -> Checked<T> {
+	T::mean(values)
+}
+
+/// Finds the least value in a slice.
+pub fn min<T: IsInteger>(values: &[T]) -> Checked<T> {
+	values.iter().copied().min().into()
+}
+
+/// Finds the greatest value in a slice.
+pub fn max<T: IsInteger>(values: &[T]) -> Checked<T> {
+	values.iter().copied().max().into()
+}
+
+/** Integers which can be averaged without overflow.
+
+The sum of a slice is computed in the 128-bit integer of the same signedness.
+No slice that fits in memory can hold enough 64-bit integers to overflow this
+sum, so the mean is always exact before its final rounding.
+**/
+pub trait Mean: IsInteger {
+	/// Computes the arithmetic mean of a slice, rounded toward zero.
+	fn mean(values: &[Self]) -> Checked<Self>;
+}
+
+macro_rules! mean {
+	($($t:ty => $sum:ty),* $(,)?) => { $(
+		impl Mean for $t {
+			fn mean(values: &[Self]) -> Checked<Self> {
+				if values.is_empty() {
+					return None.into();
+				}
+				let sum = values.iter().map(|&v| v as $sum).sum::<$sum>();
+				Some((sum / values.len() as $sum) as $t).into()
+			}
+		}
+	)* };
+}
+
+mean!(
+	i8 => i128,
+	i16 => i128,
+	i32 => i128,
+	i64 => i128,
+	isize => i128,
+	u8 => u128,
+	u16 => u128,
+	u32 => u128,
+	u64 => u128,
+	usize => u128,
+);
@@ -0,0 +1,73 @@
+//! Arithmetic which must never overflow, on every wrapper.
+
+use funty::IsInteger;
+
+use crate::{
+	Checked,
+	Overflowing,
+	Saturating,
+	Wrapping,
+};
+
+/// Applies a checked operation, panicking with the operation and both operands
+/// if it fails.
+#[track_caller]
+fn strict<T: IsInteger>(
+	lhs: T,
+	op: &str,
+	rhs: T,
+	func: impl FnOnce(T, T) -> Option<T>,
+) -> T
+{
+	match func(lhs, rhs) {
+		Some(value) => value,
+		None => panic!("strict arithmetic overflowed: `{} {} {}`", lhs, op, rhs),
+	}
+}
+
+macro_rules! strict {
+	($($w:ident),+ $(,)?) => { $(
+		impl<T: IsInteger> $w<T> {
+			strict!(@ $w
+				strict_add "+" checked_add "Adds";
+				strict_sub "-" checked_sub "Subtracts";
+				strict_mul "*" checked_mul "Multiplies";
+				strict_div "/" checked_div "Divides";
+				strict_rem "%" checked_rem "Takes the remainder of";
+			);
+		}
+	)+ };
+
+	(@ Checked $($name:ident $op:literal $func:ident $verb:literal;)+) => { $(
+		#[doc = $verb]
+		#[doc = " the integer, panicking with both operands if the result"]
+		#[doc = " overflows or the divisor is zero, or if the value is already"]
+		#[doc = " poisoned."]
+		#[track_caller]
+		pub fn $name(self, rhs: T) -> Self {
+			let lhs = match self.value {
+				Some(value) => value,
+				None => panic!(
+					"strict arithmetic on a poisoned value: `_ {} {}`",
+					$op,
+					rhs,
+				),
+			};
+			strict(lhs, $op, rhs, T::$func).into()
+		}
+	)+ };
+
+	(@ $w:ident $($name:ident $op:literal $func:ident $verb:literal;)+) => { $(
+		#[doc = $verb]
+		#[doc = " the integer, panicking with both operands if the result"]
+		#[doc = " overflows or the divisor is zero."]
+		#[track_caller]
+		pub fn $name(self, rhs: T) -> Self {
+			let mut out = self;
+			out.value = strict(self.value, $op, rhs, T::$func);
+			out
+		}
+	)+ };
+}
+
+strict!(Checked, Overflowing, Saturating, Wrapping);
@@ -0,0 +1,184 @@
+//! Formatting into caller-provided byte buffers, without `core::fmt`.
+//!
+//! Each wrapper can write its value as text into a byte buffer, in any radix
+//! from 2 to 36, and produce the written text as a `&str` borrowed from the
+//! buffer. The text matches the wrapper’s `Display` output: a poisoned
+//! `Checked` is written as `overflow`, and an `Overflowing` whose flag is set
+//! is followed by ` (overflowed)`.
+
+use core::str;
+
+use crate::{
+	shift::Magnitude,
+	Checked,
+	MixedCmp,
+	Overflowing,
+	Saturating,
+	Wrapping,
+};
+
+/// The digits of every radix up to 36.
+const DIGITS: &[u8; 36] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// Writes an integer, followed by `suffix`, into the start of `buf`.
+///
+/// # Panics
+///
+/// This panics if `radix` is not in `2 ..= 36`, or if `buf` is too short.
+fn write<'a, T: MixedCmp>(
+	value: T,
+	radix: u32,
+	suffix: &str,
+	buf: &'a mut [u8],
+) -> &'a str
+{
+	assert!((2 ..= 36).contains(&radix), "radix must be in 2 ..= 36");
+	let (neg, mut magnitude) = value.sign_magnitude();
+	let radix = radix as Magnitude;
+	let mut digits = 1;
+	let mut rest = magnitude / radix;
+	while rest != 0 {
+		digits += 1;
+		rest /= radix;
+	}
+	let len = neg as usize + digits + suffix.len();
+	assert!(buf.len() >= len, "buffer too short for the formatted integer");
+	let out = &mut buf[.. len];
+
+	out[len - suffix.len() ..].copy_from_slice(suffix.as_bytes());
+	for slot in out[neg as usize .. neg as usize + digits].iter_mut().rev() {
+		*slot = DIGITS[(magnitude % radix) as usize];
+		magnitude /= radix;
+	}
+	if neg {
+		out[0] = b'-';
+	}
+	//  SAFETY: every byte written is ASCII, or is copied from a `str`.
+	unsafe { str::from_utf8_unchecked(out) }
+}
+
+/// Writes `text` into the start of `buf`.
+fn write_str<'a>(text: &str, buf: &'a mut [u8]) -> &'a str {
+	assert!(buf.len() >= text.len(), "buffer too short for the text");
+	let out = &mut buf[.. text.len()];
+	out.copy_from_slice(text.as_bytes());
+	//  SAFETY: the bytes are copied from a `str`.
+	unsafe { str::from_utf8_unchecked(out) }
+}
+
+impl<T: MixedCmp> Checked<T> {
+	/// Writes the value in decimal into the start of `buf`, or `overflow` if
+	/// it is poisoned, and produces the written text.
+	///
+	/// The buffer must be long enough for the text. Forty bytes holds any
+	/// decimal integer up to 128 bits.
+	///
+	/// # Panics
+	///
+	/// This panics if `buf` is too short.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Checked;
+	///
+	/// let mut buf = [0u8; 40];
+	/// assert_eq!(Checked::new(-1234i32).to_str_buf(&mut buf), "-1234");
+	/// assert_eq!((Checked::new(255u8) + 1).to_str_buf(&mut buf), "overflow");
+	/// ```
+	pub fn to_str_buf<'a>(&self, buf: &'a mut [u8]) -> &'a str {
+		self.to_str_radix_buf(buf, 10)
+	}
+
+	/// Writes the value in `radix`, with lowercase digits, into the start of
+	/// `buf`, or `overflow` if it is poisoned, and produces the written text.
+	///
+	/// # Panics
+	///
+	/// This panics if `radix` is not in `2 ..= 36`, or if `buf` is too short.
+	pub fn to_str_radix_buf<'a>(
+		&self,
+		buf: &'a mut [u8],
+		radix: u32,
+	) -> &'a str
+	{
+		match self.value {
+			Some(value) => write(value, radix, "", buf),
+			None => write_str("overflow", buf),
+		}
+	}
+}
+
+impl<T: MixedCmp> Overflowing<T> {
+	/// Writes the value in decimal into the start of `buf`, followed by
+	/// ` (overflowed)` if the overflow flag is set, and produces the written
+	/// text.
+	///
+	/// # Panics
+	///
+	/// This panics if `buf` is too short.
+	pub fn to_str_buf<'a>(&self, buf: &'a mut [u8]) -> &'a str {
+		self.to_str_radix_buf(buf, 10)
+	}
+
+	/// Writes the value in `radix`, with lowercase digits, into the start of
+	/// `buf`, followed by ` (overflowed)` if the overflow flag is set, and
+	/// produces the written text.
+	///
+	/// # Panics
+	///
+	/// This panics if `radix` is not in `2 ..= 36`, or if `buf` is too short.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Overflowing;
+	///
+	/// let mut buf = [0u8; 32];
+	/// let over = Overflowing::new(0xF0u8) + 0x20;
+	/// assert_eq!(over.to_str_radix_buf(&mut buf, 16), "10 (overflowed)");
+	/// ```
+	pub fn to_str_radix_buf<'a>(
+		&self,
+		buf: &'a mut [u8],
+		radix: u32,
+	) -> &'a str
+	{
+		let suffix = if self.has_overflowed { " (overflowed)" } else { "" };
+		write(self.value, radix, suffix, buf)
+	}
+}
+
+macro_rules! text {
+	($($w:ident),+ $(,)?) => { $(
+		impl<T: MixedCmp> $w<T> {
+			/// Writes the value in decimal into the start of `buf`, and
+			/// produces the written text.
+			///
+			/// # Panics
+			///
+			/// This panics if `buf` is too short.
+			pub fn to_str_buf<'a>(&self, buf: &'a mut [u8]) -> &'a str {
+				self.to_str_radix_buf(buf, 10)
+			}
+
+			/// Writes the value in `radix`, with lowercase digits, into the
+			/// start of `buf`, and produces the written text.
+			///
+			/// # Panics
+			///
+			/// This panics if `radix` is not in `2 ..= 36`, or if `buf` is too
+			/// short.
+			pub fn to_str_radix_buf<'a>(
+				&self,
+				buf: &'a mut [u8],
+				radix: u32,
+			) -> &'a str
+			{
+				write(self.value, radix, "", buf)
+			}
+		}
+	)+ };
+}
+
+text!(Saturating, Wrapping);
@@ -0,0 +1,102 @@
+use core::ops::{
+	Add,
+	AddAssign,
+	Div,
+	DivAssign,
+	Mul,
+	MulAssign,
+	Rem,
+	RemAssign,
+	Sub,
+	SubAssign,
+};
+
+use funty::IsInteger;
+
+use crate::{
+	Checked,
+	Overflowing,
+	Saturating,
+	Wrapping,
+};
+
+/** Marks a type whose addition, subtraction, and multiplication never panic.
+
+Every wrapper in this crate implements this trait, against both itself and its
+integer, because each of its policies has a defined result for every operand:
+the checked operators poison, the saturating operators clamp, and the wrapping
+and overflowing operators wrap. The trait is sealed, so that a bound on it is a
+promise made by this crate and cannot be extended to a type that might panic.
+
+Generic code which must not panic can bound on this trait to be sure that it
+only uses these operators. Division is not included, as the wrapping and
+overflowing policies still panic on a zero divisor; see [`TotalDiv`].
+
+# Examples
+
+```rust
+use surety::{Saturating, TotalOps, Wrapping};
+
+fn mix<N: TotalOps + Copy>(a: N, b: N) -> N {
+    a * b + a - b
+}
+
+assert_eq!(mix(Wrapping::new(200u8), Wrapping::new(3)), 29);
+assert_eq!(mix(Saturating::new(200u8), Saturating::new(3)), 252);
+```
+
+[`TotalDiv`]: crate::TotalDiv
+**/
+pub trait TotalOps<Rhs = Self>:
+	sealed::Sealed
+	+ Sized
+	+ Add<Rhs, Output = Self>
+	+ Sub<Rhs, Output = Self>
+	+ Mul<Rhs, Output = Self>
+	+ AddAssign<Rhs>
+	+ SubAssign<Rhs>
+	+ MulAssign<Rhs>
+{
+}
+
+/** Marks a type whose division and remainder, as well as its [`TotalOps`], never
+panic.
+
+Only `Checked` implements this trait, as it poisons on a zero divisor instead of
+panicking.
+
+[`TotalOps`]: crate::TotalOps
+**/
+pub trait TotalDiv<Rhs = Self>:
+	TotalOps<Rhs>
+	+ Div<Rhs, Output = Self>
+	+ Rem<Rhs, Output = Self>
+	+ DivAssign<Rhs>
+	+ RemAssign<Rhs>
+{
+}
+
+macro_rules! total {
+	($($w:ident),+ $(,)?) => { $(
+		impl<T: IsInteger> sealed::Sealed for $w<T> {
+		}
+
+		impl<T: IsInteger> TotalOps for $w<T> {
+		}
+
+		impl<T: IsInteger> TotalOps<T> for $w<T> {
+		}
+	)+ };
+}
+
+total!(Checked, Overflowing, Saturating, Wrapping);
+
+impl<T: IsInteger> TotalDiv for Checked<T> {
+}
+
+impl<T: IsInteger> TotalDiv<T> for Checked<T> {
+}
+
+mod sealed {
+	pub trait Sealed {}
+}
@@ -0,0 +1,215 @@
+/*! Exhaustive verification of arithmetic, for tests.
+
+This module is only present when the `verify` feature is enabled.
+
+[`unary`] and [`binary`] run an operation on every value, or every pair of
+values, of an 8- or 16-bit integer, and compare each result against a reference
+model which computes the intended answer in plain arithmetic. They panic on the
+first disagreement, naming the operands. This lets a downstream crate prove
+that, for example, a custom fixed-point operation saturates exactly where it
+should, without writing its own nested loops.
+
+The operation receives its operands as any type which can be made from the
+integer, so it can take the wrappers directly, and its result only needs to be
+comparable with the model’s. `binary` over a 16-bit integer makes 2<sup>32</sup>
+calls, which is practical only in an optimized build.
+
+# Examples
+
+```rust
+use surety::{verify, Checked, Saturating};
+
+verify::binary(
+    |a: Saturating<u8>, b: Saturating<u8>| a + b,
+    |a: u8, b: u8| (a as u16 + b as u16).min(255) as u8,
+);
+verify::unary(
+    |a: Checked<i16>| -a,
+    |a: i16| if a == i16::MIN { None } else { Some(-a) },
+);
+```
+
+# Proofs
+
+When the crate is built by the [Kani] model checker, this module also contains
+proof harnesses which state the contract of each wrapper’s arithmetic operators,
+and Kani proves them for every possible pair of operands:
+
+```sh
+cargo kani --features verify
+```
+
+Each harness computes the exact result of an operation in `i128`, which holds
+every sum, difference, and product of the integers up to 32 bits wide, and
+compares the wrapper’s result against it:
+
+- `Checked` produces the exact result when it is in range, and is poisoned when
+  it is not. It never holds a wrapped result.
+- `Overflowing` holds the wrapped result, and sets its flag exactly when the
+  exact result is out of range.
+- `Saturating` holds the exact result clamped to the range of the integer, and
+  so is always within its bounds.
+- `Wrapping` holds the exact result reduced modulo 2<sup>N</sup>.
+
+[Kani]: https://model-checking.github.io/kani/
+!*/
+
+use core::{
+	fmt::Debug,
+	iter,
+};
+
+use funty::IsInteger;
+
+use crate::bits;
+
+/** Integers narrow enough to check every value.
+
+This trait is implemented for `i8`, `i16`, `u8`, and `u16`, and cannot be
+implemented outside this crate.
+**/
+pub trait Exhaustive: IsInteger + sealed::Sealed {}
+
+impl<T: IsInteger + sealed::Sealed> Exhaustive for T {
+}
+
+/// Checks an operation of one operand against a reference model, for every
+/// value of the integer `T`.
+///
+/// # Panics
+///
+/// This panics at the first value for which the operation’s result is not
+/// equal to the model’s.
+#[track_caller]
+pub fn unary<T, W, R, M>(
+	mut op: impl FnMut(W) -> R,
+	mut model: impl FnMut(T) -> M,
+) where
+	T: Exhaustive,
+	W: From<T>,
+	R: Debug + PartialEq<M>,
+	M: Debug,
+{
+	for a in values::<T>() {
+		let (actual, expected) = (op(a.into()), model(a));
+		if actual != expected {
+			panic!(
+				"operation disagrees with the model at {:?}: got {:?}, expected \
+				 {:?}",
+				a, actual, expected,
+			);
+		}
+	}
+}
+
+/// Checks an operation of two operands against a reference model, for every
+/// pair of values of the integer `T`.
+///
+/// # Panics
+///
+/// This panics at the first pair for which the operation’s result is not
+/// equal to the model’s.
+#[track_caller]
+pub fn binary<T, W, R, M>(
+	mut op: impl FnMut(W, W) -> R,
+	mut model: impl FnMut(T, T) -> M,
+) where
+	T: Exhaustive,
+	W: From<T>,
+	R: Debug + PartialEq<M>,
+	M: Debug,
+{
+	for a in values::<T>() {
+		for b in values::<T>() {
+			let (actual, expected) = (op(a.into(), b.into()), model(a, b));
+			if actual != expected {
+				panic!(
+					"operation disagrees with the model at ({:?}, {:?}): got \
+					 {:?}, expected {:?}",
+					a, b, actual, expected,
+				);
+			}
+		}
+	}
+}
+
+/// Produces every value of `T`, from `MIN` to `MAX`.
+fn values<T: Exhaustive>() -> impl Iterator<Item = T> {
+	iter::successors(Some(T::MIN), |&value| {
+		if value == T::MAX {
+			None
+		}
+		else {
+			Some(value + bits::one::<T>())
+		}
+	})
+}
+
+mod sealed {
+	pub trait Sealed {}
+
+	macro_rules! sealed {
+		($($t:ty),+) => { $(
+			impl Sealed for $t {
+			}
+		)+ };
+	}
+
+	sealed!(i8, i16, u8, u16);
+}
+
+
+#[cfg(kani)]
+use crate::{
+	Checked,
+	Overflowing,
+	Saturating,
+	Wrapping,
+};
+
+#[cfg(kani)]
+macro_rules! verify {
+	($($t:ident),+ $(,)?) => { $(
+		mod $t {
+			use super::*;
+
+			verify!(@ $t
+				add + |a: i128, b: i128| a + b;
+				sub - |a: i128, b: i128| a - b;
+				mul * |a: i128, b: i128| a * b;
+			);
+		}
+	)+ };
+
+	(@ $t:ident $($name:ident $op:tt $exact:expr;)+) => { $(
+		#[kani::proof]
+		fn $name() {
+			let a: $t = kani::any();
+			let b: $t = kani::any();
+			let exact = ($exact)(a as i128, b as i128);
+			let fits = exact >= $t::MIN as i128 && exact <= $t::MAX as i128;
+			let modulus = 1i128 << (core::mem::size_of::<$t>() * 8);
+			let wrapped = exact.rem_euclid(modulus);
+
+			let checked = Checked::new(a) $op b;
+			match checked.value {
+				Some(value) => assert!(fits && value as i128 == exact),
+				None => assert!(!fits),
+			}
+
+			let overflowing = Overflowing::new(a) $op b;
+			assert_eq!(overflowing.has_overflowed, !fits);
+			assert_eq!((overflowing.value as i128).rem_euclid(modulus), wrapped);
+
+			let saturating = Saturating::new(a) $op b;
+			let clamped = exact.clamp($t::MIN as i128, $t::MAX as i128);
+			assert_eq!(saturating.value as i128, clamped);
+
+			let wrapping = Wrapping::new(a) $op b;
+			assert_eq!((wrapping.value as i128).rem_euclid(modulus), wrapped);
+		}
+	)+ };
+}
+
+#[cfg(kani)]
+verify!(i8, i16, i32, u8, u16, u32);
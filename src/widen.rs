@@ -0,0 +1,165 @@
+use funty::IsInteger;
+
+use crate::{
+	Checked,
+	Overflowing,
+	Saturating,
+	Wrapping,
+};
+
+/** Associates an integer with the integer of the same signedness and the next
+larger width.
+
+This trait is implemented for all Rust integers except `i128` and `u128`, which
+have no larger fundamental to widen into. The pointer-sized integers widen into
+the 128-bit integers.
+
+The `no-128` feature removes the implementations whose wide type is 128 bits,
+so that the 64-bit integers do not widen at all. The pointer-sized integers
+then widen into the 64-bit integers on targets where they are narrower.
+
+This relationship is used to perform arithmetic in a type which cannot overflow
+for the first several operations, and only apply an overflow policy when the
+result is brought back down into the narrow type.
+**/
+pub trait Widen: IsInteger {
+	/// The integer of the same signedness and the next larger width.
+	type Wide: IsInteger;
+
+	/// The least and greatest values of the integer, as `i128`, for checks
+	/// which run at compile time.
+	#[doc(hidden)]
+	const BOUNDS: (i128, i128);
+
+	/// Losslessly converts a narrow integer into the wide integer.
+	fn widen(self) -> Self::Wide;
+
+	/// Converts a wide integer into the narrow integer by discarding the high
+	/// bits that do not fit.
+	fn truncate(wide: Self::Wide) -> Self;
+
+	/// Converts a wide integer into the narrow integer, if it is within the
+	/// narrow integer’s range.
+	fn narrow(wide: Self::Wide) -> Option<Self>;
+}
+
+/** Moves an integer, or a wrapper around one, to the next larger width.
+
+Each wrapper promotes into the same wrapper around its integer’s [`Widen`]
+type, keeping its state: a poisoned `Checked` stays poisoned, and an
+`Overflowing` keeps its flag. A computation can promote its operands to a width
+in which its intermediate results cannot overflow, and then narrow the result
+back with one of the policy casts.
+
+# Examples
+
+```rust
+use surety::{Checked, Promote as _, Promoted};
+
+let side = Checked::new(200u8);
+let area: Promoted<Checked<u8>> = side.promote() * side.promote();
+assert_eq!(area, Some(40_000u16));
+assert!(area.cast::<u8>().is_none());
+assert_eq!((area / 200).cast::<u8>(), Some(200));
+```
+**/
+pub trait Promote {
+	/// The same kind of value, at the next larger width.
+	type Promoted;
+
+	/// Losslessly converts into the next larger width.
+	fn promote(self) -> Self::Promoted;
+}
+
+/// The result of promoting an integer, or a wrapper around one, to the next
+/// larger width.
+pub type Promoted<T> = <T as Promote>::Promoted;
+
+impl<T: Widen> Promote for Checked<T> {
+	type Promoted = Checked<T::Wide>;
+
+	fn promote(self) -> Self::Promoted {
+		self.map(Widen::widen)
+	}
+}
+
+impl<T: Widen> Promote for Overflowing<T> {
+	type Promoted = Overflowing<T::Wide>;
+
+	fn promote(self) -> Self::Promoted {
+		self.map(Widen::widen)
+	}
+}
+
+impl<T: Widen> Promote for Saturating<T> {
+	type Promoted = Saturating<T::Wide>;
+
+	fn promote(self) -> Self::Promoted {
+		Saturating::new(self.value.widen())
+	}
+}
+
+impl<T: Widen> Promote for Wrapping<T> {
+	type Promoted = Wrapping<T::Wide>;
+
+	fn promote(self) -> Self::Promoted {
+		Wrapping::new(self.value.widen())
+	}
+}
+
+macro_rules! widen {
+	($($narrow:ty => $wide:ty),* $(,)?) => { $(
+		impl Widen for $narrow {
+			type Wide = $wide;
+
+			const BOUNDS: (i128, i128) =
+				(<$narrow>::MIN as i128, <$narrow>::MAX as i128);
+
+			fn widen(self) -> Self::Wide {
+				self as $wide
+			}
+
+			fn truncate(wide: Self::Wide) -> Self {
+				wide as $narrow
+			}
+
+			fn narrow(wide: Self::Wide) -> Option<Self> {
+				use core::convert::TryFrom as _;
+				Self::try_from(wide).ok()
+			}
+		}
+
+		impl Promote for $narrow {
+			type Promoted = $wide;
+
+			fn promote(self) -> Self::Promoted {
+				self.widen()
+			}
+		}
+	)* };
+}
+
+widen!(
+	i8 => i16,
+	i16 => i32,
+	i32 => i64,
+	u8 => u16,
+	u16 => u32,
+	u32 => u64,
+);
+
+#[cfg(not(feature = "no-128"))]
+widen!(
+	i64 => i128,
+	isize => i128,
+	u64 => u128,
+	usize => u128,
+);
+
+//  Without the 128-bit integers, the pointer-sized integers can still widen on
+//  targets where they are narrower than 64 bits.
+#[cfg(all(feature = "no-128", not(target_pointer_width = "64")))]
+widen!(
+	isize => i64,
+	usize => u64,
+);
@@ -1,14 +1,28 @@
 use core::{
+	borrow::Borrow,
 	cmp::Ordering,
-	convert::TryInto as _,
+	hash::{
+		Hash,
+		Hasher,
+	},
+	iter::Sum,
 	ops::{
 		Add,
 		AddAssign,
+		BitAnd,
+		BitAndAssign,
+		BitOr,
+		BitOrAssign,
+		BitXor,
+		BitXorAssign,
+		Deref,
+		DerefMut,
 		Div,
 		DivAssign,
 		Mul,
 		MulAssign,
 		Neg,
+		Not,
 		Rem,
 		RemAssign,
 		Shl,
@@ -23,6 +37,18 @@ use core::{
 use funty::{
 	IsInteger,
 	IsSigned,
+	IsUnsigned,
+};
+
+use crate::{
+	bits,
+	shift,
+	Checked,
+	Overflowing,
+	Saturating,
+	ShiftAmount,
+	ToSigned,
+	ToUnsigned,
 };
 
 /** Marks an integer for wrapping-overflow arithmetic.
@@ -34,15 +60,79 @@ This type is the fastest, as it has no branches and merely truncates results to
 fit, but is by the same token the least precise. It is useful for ring
 arithmetic, but not for any arithmetic where you need to observe boundary
 conditions.
+
+Shifts never panic. A negative shift amount shifts in the opposite direction,
+and the magnitude of the amount is reduced modulo the bit width of the integer,
+so that shifting a `u8` left by `9` shifts it left by `1`, and shifting it left
+by `-9` shifts it right by `1`.
+
+# Performance
+
+The arithmetic operators defer to the integer’s `wrapping_*` methods, which are
+the processor’s native arithmetic. There is no branch, no flag, and no call: the
+operators are generic, so they are compiled in the calling crate and inlined
+like the integer’s own methods, without needing `#[inline]`. In an optimized
+build, `Wrapping<u32>` addition, subtraction, and multiplication are each one
+instruction, `lea`, `sub`, and `imul` on x86-64, or `add`, `sub`, and `mul` on
+AArch64, and `acc + Wrapping::new(a) * b` is a single `madd` on AArch64.
+`benches/codegen.sh` checks this against the compiler’s output.
+
+As a `Wrapping` integer is always valid, it dereferences to its contained
+integer, and the integer’s inherent methods can be called on it directly.
+
+It hashes, compares, and borrows as its contained integer, so a map or set keyed
+by `Wrapping<T>` can be queried with a bare `T`.
 **/
 #[repr(transparent)]
-#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
 pub struct Wrapping<T: IsInteger> {
 	/// The contained integer.
-	pub value: T,
+	pub(crate) value: T,
 }
 
 impl<T: IsInteger> Wrapping<T> {
+	/// Wraps an integer for wrapping-overflow arithmetic.
+	pub const fn new(value: T) -> Self {
+		Self { value }
+	}
+
+	/// Gets the contained integer.
+	pub const fn value(&self) -> T {
+		self.value
+	}
+
+	/// Unwraps the contained integer.
+	pub const fn into_inner(self) -> T {
+		self.value
+	}
+
+	/// Replaces the contained integer.
+	pub fn set(&mut self, value: T) {
+		self.value = value;
+	}
+
+	/// Switches to checked arithmetic. The result is not poisoned.
+	pub const fn to_checked(self) -> Checked<T> {
+		Checked::new(self.value)
+	}
+
+	/// Returns the value unchanged. This exists so that generic code can call
+	/// `to_wrapping` on any of the wrappers.
+	pub const fn to_wrapping(self) -> Self {
+		self
+	}
+
+	/// Switches to saturating arithmetic, from the current value.
+	pub const fn to_saturating(self) -> Saturating<T> {
+		Saturating { value: self.value }
+	}
+
+	/// Switches to overflow-detecting arithmetic, with the overflow flag
+	/// cleared.
+	pub const fn to_overflowing(self) -> Overflowing<T> {
+		Overflowing::new(self.value)
+	}
+
 	/// Wrapping Eulidean division. Computes `self.value.div_euclid(rhs.value)`,
 	/// wrapping around at the boundary of the type.
 	///
@@ -94,6 +184,39 @@ impl<T: IsInteger> Wrapping<T> {
 		self.value.wrapping_rem_euclid(rhs.value).into()
 	}
 
+	/// Wrapping division which does not panic. Computes
+	/// `self.value.wrapping_div(rhs.value)`, or a poisoned `Checked` if `rhs`
+	/// is 0.
+	///
+	/// As with the `/` operator, `MIN / -1` on a signed type wraps to `MIN`,
+	/// and is not poisoned.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Wrapping;
+	///
+	/// let min = Wrapping::new(i8::MIN);
+	/// assert_eq!(min.checked_div(Wrapping::new(-1)), Some(i8::MIN));
+	/// assert!(min.checked_div(Wrapping::new(0)).is_none());
+	/// ```
+	pub fn checked_div(self, rhs: Self) -> Checked<T> {
+		if rhs.value == T::ZERO {
+			return Checked { value: None };
+		}
+		self.value.wrapping_div(rhs.value).into()
+	}
+
+	/// Wrapping remainder which does not panic. Computes
+	/// `self.value.wrapping_rem(rhs.value)`, or a poisoned `Checked` if `rhs`
+	/// is 0.
+	pub fn checked_rem(self, rhs: Self) -> Checked<T> {
+		if rhs.value == T::ZERO {
+			return Checked { value: None };
+		}
+		self.value.wrapping_rem(rhs.value).into()
+	}
+
 	/// Wrapping (modular) absolute value. Computes `self.value.abs()`, wrapping
 	/// around at the boundary of the type.
 	///
@@ -111,6 +234,176 @@ impl<T: IsInteger> Wrapping<T> {
 	pub fn pow(self, exp: u32) -> Self {
 		self.value.wrapping_pow(exp).into()
 	}
+
+	/// Replaces the value in place with the result of a function applied to
+	/// it.
+	///
+	/// This allows values stored in fields or behind references to be modified
+	/// without moving them out into a temporary.
+	pub fn update(&mut self, func: impl FnOnce(Self) -> Self) {
+		*self = func(*self);
+	}
+
+	/// Adds in place, wrapping, and returns whether the addition wrapped.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Wrapping;
+	///
+	/// let mut num = Wrapping::new(250u8);
+	/// assert!(num.add_assign_check(10));
+	/// assert_eq!(num, 4);
+	/// assert!(!num.sub_assign_check(4));
+	/// assert!(num.sub_assign_check(1));
+	/// assert_eq!(num, 255);
+	/// ```
+	pub fn add_assign_check(&mut self, rhs: T) -> bool {
+		let (value, ovf) = self.value.overflowing_add(rhs);
+		self.value = value;
+		ovf
+	}
+
+	/// Subtracts in place, wrapping, and returns whether the subtraction
+	/// wrapped.
+	pub fn sub_assign_check(&mut self, rhs: T) -> bool {
+		let (value, ovf) = self.value.overflowing_sub(rhs);
+		self.value = value;
+		ovf
+	}
+
+	/// Multiplies in place, wrapping, and returns whether the multiplication
+	/// wrapped.
+	pub fn mul_assign_check(&mut self, rhs: T) -> bool {
+		let (value, ovf) = self.value.overflowing_mul(rhs);
+		self.value = value;
+		ovf
+	}
+
+	/// Steps forward by one, wrapping from `T::MAX` to `T::MIN`.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Wrapping;
+	///
+	/// assert_eq!(Wrapping::new(255u8).next_cyclic(), 0);
+	/// assert_eq!(Wrapping::new(0u8).prev_cyclic(), 255);
+	/// assert_eq!(Wrapping::new(250u8).advance(10), 4);
+	/// ```
+	pub fn next_cyclic(self) -> Self {
+		self.advance(bits::one())
+	}
+
+	/// Steps backward by one, wrapping from `T::MIN` to `T::MAX`.
+	pub fn prev_cyclic(self) -> Self {
+		self.value.wrapping_sub(bits::one()).into()
+	}
+
+	/// Steps forward by `steps`, wrapping around the range of the integer.
+	pub fn advance(self, steps: T) -> Self {
+		self.value.wrapping_add(steps).into()
+	}
+}
+
+impl<T: IsUnsigned> Wrapping<T> {
+	/// Steps forward by one through `0 .. len`, wrapping from `len - 1` to 0.
+	///
+	/// This is the `(i + 1) % len` of a ring buffer or round-robin scheduler,
+	/// which cannot overflow even when `len` is `T::MAX`. A value which is not
+	/// already less than `len` is reduced first.
+	///
+	/// # Panics
+	///
+	/// This panics if `len` is zero.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Wrapping;
+	///
+	/// let slot = Wrapping::new(4usize);
+	/// assert_eq!(slot.next_cyclic_in(5), 0);
+	/// assert_eq!(Wrapping::new(0usize).prev_cyclic_in(5), 4);
+	/// assert_eq!(Wrapping::new(3u8).advance_in(254, 255), 2);
+	/// ```
+	#[track_caller]
+	pub fn next_cyclic_in(self, len: T) -> Self {
+		self.advance_in(bits::one(), len)
+	}
+
+	/// Steps backward by one through `0 .. len`, wrapping from 0 to `len - 1`.
+	///
+	/// # Panics
+	///
+	/// This panics if `len` is zero.
+	#[track_caller]
+	pub fn prev_cyclic_in(self, len: T) -> Self {
+		let value = self.value % len;
+		let value = if value == T::ZERO { len } else { value };
+		(value - bits::one::<T>()).into()
+	}
+
+	/// Steps forward by `steps` through `0 .. len`, wrapping around to 0 after
+	/// `len - 1`.
+	///
+	/// # Panics
+	///
+	/// This panics if `len` is zero.
+	#[track_caller]
+	pub fn advance_in(self, steps: T, len: T) -> Self {
+		let (value, steps) = (self.value % len, steps % len);
+		//  `value + steps` may overflow `T`, but `len - steps` cannot.
+		let room = len - steps;
+		let value = if value >= room { value - room } else { value + steps };
+		value.into()
+	}
+}
+
+impl<T: ToUnsigned> Wrapping<T> {
+	/// Computes the shorter distance between two points on the circular number
+	/// line, in either direction.
+	///
+	/// Plain subtraction gives the distance in one direction only, which is
+	/// nearly the full range for two points just either side of the wrap.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Wrapping;
+	///
+	/// let a = Wrapping::new(250u8);
+	/// let b = Wrapping::new(4u8);
+	/// assert_eq!(a.wrapping_distance(b), 10);
+	/// assert_eq!(b.wrapping_distance(a), 10);
+	/// ```
+	pub fn wrapping_distance(self, other: Self) -> T::Unsigned {
+		let forward = other.value.wrapping_sub(self.value).to_unsigned_bits();
+		forward.min(forward.wrapping_neg())
+	}
+}
+
+impl<T: ToSigned> Wrapping<T> {
+	/// Computes the shortest signed step from `self` to `other` on the circular
+	/// number line.
+	///
+	/// The result is positive if `other` is ahead of `self`, as in serial-number
+	/// arithmetic, even when `other` has wrapped around and is numerically
+	/// smaller. Two points exactly half the range apart are separated by the
+	/// signed minimum.
+	///
+	/// # Examples
+	///
+	/// ```rust
+	/// use surety::Wrapping;
+	///
+	/// let seq = Wrapping::new(65_530u16);
+	/// assert_eq!(seq.signed_delta(Wrapping::new(3)), 9);
+	/// assert_eq!(Wrapping::new(3u16).signed_delta(seq), -9);
+	/// ```
+	pub fn signed_delta(self, other: Self) -> T::Signed {
+		other.value.wrapping_sub(self.value).to_signed_bits()
+	}
 }
 
 impl<T: IsInteger> PartialEq<T> for Wrapping<T> {
@@ -137,12 +430,68 @@ impl<T: IsInteger> AsMut<T> for Wrapping<T> {
 	}
 }
 
+/// # Examples
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use surety::Wrapping;
+///
+/// let mut seen = HashMap::new();
+/// seen.insert(Wrapping::new(u32::MAX) + 1, "wrapped");
+/// assert_eq!(seen.get(&0), Some(&"wrapped"));
+/// ```
+impl<T: IsInteger> Borrow<T> for Wrapping<T> {
+	fn borrow(&self) -> &T {
+		&self.value
+	}
+}
+
+/// Hashes exactly as the contained integer does, as `Borrow` requires.
+impl<T: IsInteger> Hash for Wrapping<T> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.value.hash(state);
+	}
+}
+
+impl<T: IsInteger> Deref for Wrapping<T> {
+	type Target = T;
+
+	fn deref(&self) -> &T {
+		&self.value
+	}
+}
+
+impl<T: IsInteger> DerefMut for Wrapping<T> {
+	fn deref_mut(&mut self) -> &mut T {
+		&mut self.value
+	}
+}
+
 impl<T: IsInteger> From<T> for Wrapping<T> {
 	fn from(value: T) -> Self {
 		Self { value }
 	}
 }
 
+/// Starts wrapping arithmetic from the current value.
+impl<T: IsInteger> From<Saturating<T>> for Wrapping<T> {
+	fn from(value: Saturating<T>) -> Self {
+		value.to_wrapping()
+	}
+}
+
+impl<T: IsInteger> Sum<Self> for Wrapping<T> {
+	fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+		iter.fold(T::default().into(), |acc, next| acc + next)
+	}
+}
+
+impl<'a, T: 'a + IsInteger> Sum<&'a Self> for Wrapping<T> {
+	fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+		iter.copied().sum()
+	}
+}
+
 impl<T: IsInteger> Add<Self> for Wrapping<T> {
 	type Output = Self;
 
@@ -431,136 +780,194 @@ impl<T: IsInteger> RemAssign<&T> for Wrapping<T> {
 	}
 }
 
-macro_rules! shift {
-	($($t:ty),* $(,)?) => { $(
-		impl<T: IsInteger> Shl<Wrapping<$t>> for Wrapping<T> {
+impl<T: IsInteger> Not for Wrapping<T> {
+	type Output = Self;
+
+	fn not(self) -> Self::Output {
+		(!self.value).into()
+	}
+}
+
+/// Implements a bitwise operator, which cannot overflow, and its assigning
+/// form, for both wrapped and bare right operands.
+macro_rules! bitwise {
+	($($trait:ident $func:ident $assign:ident $assign_func:ident $op:tt);+ $(;)?) => { $(
+		impl<T: IsInteger> $trait<Self> for Wrapping<T> {
 			type Output = Self;
 
-			fn shl(self, rhs: Wrapping<$t>) -> Self::Output {
-				self.value.wrapping_shl(
-					rhs.value
-						.try_into()
-						.expect("Could not convert the shift amount to `u32`")
-				).into()
+			fn $func(self, rhs: Self) -> Self::Output {
+				(self.value $op rhs.value).into()
 			}
 		}
 
-		impl<T: IsInteger> Shl<&Wrapping<$t>> for Wrapping<T> {
+		impl<T: IsInteger> $trait<&Self> for Wrapping<T> {
 			type Output = Self;
 
-			fn shl(self, rhs: &Wrapping<$t>) -> Self::Output {
-				self << *rhs
+			fn $func(self, rhs: &Self) -> Self::Output {
+				(self.value $op rhs.value).into()
 			}
 		}
 
-		impl<T: IsInteger> Shl<$t> for Wrapping<T> {
+		impl<T: IsInteger> $trait<T> for Wrapping<T> {
 			type Output = Self;
 
-			fn shl(self, rhs: $t) -> Self::Output {
-				self.value.wrapping_shl(
-					rhs.try_into()
-						.expect("Could not convert the shift amount to `u32`")
-				).into()
+			fn $func(self, rhs: T) -> Self::Output {
+				(self.value $op rhs).into()
 			}
 		}
 
-		impl<T: IsInteger> Shl<&$t> for Wrapping<T> {
+		impl<T: IsInteger> $trait<&T> for Wrapping<T> {
 			type Output = Self;
 
-			fn shl(self, rhs: &$t) -> Self::Output {
-				self << *rhs
+			fn $func(self, rhs: &T) -> Self::Output {
+				(self.value $op *rhs).into()
 			}
 		}
 
-		impl<T: IsInteger> ShlAssign<Wrapping<$t>> for Wrapping<T> {
-			fn shl_assign(&mut self, rhs: Wrapping<$t>) {
-				*self = *self << rhs
+		impl<T: IsInteger> $assign<Self> for Wrapping<T> {
+			fn $assign_func(&mut self, rhs: Self) {
+				*self = $trait::$func(*self, rhs)
 			}
 		}
 
-		impl<T: IsInteger> ShlAssign<&Wrapping<$t>> for Wrapping<T> {
-			fn shl_assign(&mut self, rhs: &Wrapping<$t>) {
-				*self = *self << rhs
+		impl<T: IsInteger> $assign<&Self> for Wrapping<T> {
+			fn $assign_func(&mut self, rhs: &Self) {
+				*self = $trait::$func(*self, rhs)
 			}
 		}
 
-		impl<T: IsInteger> ShlAssign<$t> for Wrapping<T> {
-			fn shl_assign(&mut self, rhs: $t) {
-				*self = *self << rhs
+		impl<T: IsInteger> $assign<T> for Wrapping<T> {
+			fn $assign_func(&mut self, rhs: T) {
+				*self = $trait::$func(*self, rhs)
 			}
 		}
 
-		impl<T: IsInteger> ShlAssign<&$t> for Wrapping<T> {
-			fn shl_assign(&mut self, rhs: &$t) {
-				*self = *self << rhs
+		impl<T: IsInteger> $assign<&T> for Wrapping<T> {
+			fn $assign_func(&mut self, rhs: &T) {
+				*self = $trait::$func(*self, rhs)
 			}
 		}
+	)+ };
+}
 
-		impl<T: IsInteger> Shr<Wrapping<$t>> for Wrapping<T> {
-			type Output = Self;
+bitwise! {
+	BitAnd bitand BitAndAssign bitand_assign &;
+	BitOr bitor BitOrAssign bitor_assign |;
+	BitXor bitxor BitXorAssign bitxor_assign ^;
+}
 
-			fn shr(self, rhs: Wrapping<$t>) -> Self::Output {
-				self.value.wrapping_shr(
-					rhs.value
-						.try_into()
-						.expect("Could not convert the shift amount to `u32`")
-				).into()
-			}
-		}
+impl<T, U> Shl<Wrapping<U>> for Wrapping<T>
+where
+	T: IsInteger,
+	U: IsInteger + ShiftAmount,
+{
+	type Output = Self;
 
-		impl<T: IsInteger> Shr<&Wrapping<$t>> for Wrapping<T> {
-			type Output = Self;
+	fn shl(self, rhs: Wrapping<U>) -> Self::Output {
+		shift::shift(self.value, rhs.value.shift_magnitude(), true).0.into()
+	}
+}
 
-			fn shr(self, rhs: &Wrapping<$t>) -> Self::Output {
-				self >> *rhs
-			}
-		}
+impl<T, U> Shl<&Wrapping<U>> for Wrapping<T>
+where
+	T: IsInteger,
+	U: IsInteger + ShiftAmount,
+{
+	type Output = Self;
 
-		impl<T: IsInteger> Shr<$t> for Wrapping<T> {
-			type Output = Self;
+	fn shl(self, rhs: &Wrapping<U>) -> Self::Output {
+		self << *rhs
+	}
+}
 
-			fn shr(self, rhs: $t) -> Self::Output {
-				self.value.wrapping_shr(
-					rhs.try_into()
-						.expect("Could not convert the shift amount to `u32`")
-				).into()
-			}
-		}
+impl<T: IsInteger, U: ShiftAmount> Shl<U> for Wrapping<T> {
+	type Output = Self;
 
-		impl<T: IsInteger> Shr<&$t> for Wrapping<T> {
-			type Output = Self;
+	fn shl(self, rhs: U) -> Self::Output {
+		shift::shift(self.value, rhs.shift_magnitude(), true).0.into()
+	}
+}
 
-			fn shr(self, rhs: &$t) -> Self::Output {
-				self >> *rhs
-			}
-		}
+impl<T, U> ShlAssign<Wrapping<U>> for Wrapping<T>
+where
+	T: IsInteger,
+	U: IsInteger + ShiftAmount,
+{
+	fn shl_assign(&mut self, rhs: Wrapping<U>) {
+		*self = *self << rhs
+	}
+}
 
-		impl<T: IsInteger> ShrAssign<Wrapping<$t>> for Wrapping<T> {
-			fn shr_assign(&mut self, rhs: Wrapping<$t>) {
-				*self = *self >> rhs
-			}
-		}
+impl<T, U> ShlAssign<&Wrapping<U>> for Wrapping<T>
+where
+	T: IsInteger,
+	U: IsInteger + ShiftAmount,
+{
+	fn shl_assign(&mut self, rhs: &Wrapping<U>) {
+		*self = *self << rhs
+	}
+}
 
-		impl<T: IsInteger> ShrAssign<&Wrapping<$t>> for Wrapping<T> {
-			fn shr_assign(&mut self, rhs: &Wrapping<$t>) {
-				*self = *self >> rhs
-			}
-		}
+impl<T: IsInteger, U: ShiftAmount> ShlAssign<U> for Wrapping<T> {
+	fn shl_assign(&mut self, rhs: U) {
+		*self = *self << rhs
+	}
+}
 
-		impl<T: IsInteger> ShrAssign<$t> for Wrapping<T> {
-			fn shr_assign(&mut self, rhs: $t) {
-				*self = *self >> rhs
-			}
-		}
+impl<T, U> Shr<Wrapping<U>> for Wrapping<T>
+where
+	T: IsInteger,
+	U: IsInteger + ShiftAmount,
+{
+	type Output = Self;
 
-		impl<T: IsInteger> ShrAssign<&$t> for Wrapping<T> {
-			fn shr_assign(&mut self, rhs: &$t) {
-				*self = *self >> rhs
-			}
-		}
-	)* };
+	fn shr(self, rhs: Wrapping<U>) -> Self::Output {
+		shift::shift(self.value, rhs.value.shift_magnitude(), false).0.into()
+	}
 }
 
-shift!(
-	i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
-);
+impl<T, U> Shr<&Wrapping<U>> for Wrapping<T>
+where
+	T: IsInteger,
+	U: IsInteger + ShiftAmount,
+{
+	type Output = Self;
+
+	fn shr(self, rhs: &Wrapping<U>) -> Self::Output {
+		self >> *rhs
+	}
+}
+
+impl<T: IsInteger, U: ShiftAmount> Shr<U> for Wrapping<T> {
+	type Output = Self;
+
+	fn shr(self, rhs: U) -> Self::Output {
+		shift::shift(self.value, rhs.shift_magnitude(), false).0.into()
+	}
+}
+
+impl<T, U> ShrAssign<Wrapping<U>> for Wrapping<T>
+where
+	T: IsInteger,
+	U: IsInteger + ShiftAmount,
+{
+	fn shr_assign(&mut self, rhs: Wrapping<U>) {
+		*self = *self >> rhs
+	}
+}
+
+impl<T, U> ShrAssign<&Wrapping<U>> for Wrapping<T>
+where
+	T: IsInteger,
+	U: IsInteger + ShiftAmount,
+{
+	fn shr_assign(&mut self, rhs: &Wrapping<U>) {
+		*self = *self >> rhs
+	}
+}
+
+impl<T: IsInteger, U: ShiftAmount> ShrAssign<U> for Wrapping<T> {
+	fn shr_assign(&mut self, rhs: U) {
+		*self = *self >> rhs
+	}
+}